@@ -38,6 +38,32 @@ struct TrackData {
     engine_type: u32,
     params: HashMap<String, f32>,
     sequence: Vec<bool>,
+    /// Animate per-step locks, `ANIMATE_LOCK_PARAMS` entries per step.
+    #[serde(default)]
+    animate_step_lock_enabled: Vec<bool>,
+    #[serde(default)]
+    animate_step_lock_values: Vec<f32>,
+    /// Animate per-step note overrides, `ANIMATE_SEQ_STEPS` entries per lane.
+    #[serde(default)]
+    animate_step_note_override: Vec<bool>,
+    #[serde(default)]
+    animate_step_note: Vec<u32>,
+    /// SynDRM per-step voice overrides, `SYNDRM_STEPS` entries per voice.
+    #[serde(default)]
+    syndrm_voice_step_override: Vec<bool>,
+    /// SynDRM per-step voice params, `SYNDRM_VOICE_PARAMS` entries per step.
+    #[serde(default)]
+    syndrm_voice_step_params: Vec<f32>,
+    /// SynDRM per-step velocity, probability, micro-timing and ratchet,
+    /// `SYNDRM_STEPS` entries per lane.
+    #[serde(default)]
+    syndrm_step_velocity: Vec<f32>,
+    #[serde(default)]
+    syndrm_step_probability: Vec<f32>,
+    #[serde(default)]
+    syndrm_step_micro: Vec<f32>,
+    #[serde(default)]
+    syndrm_step_ratchet: Vec<u32>,
     sample_path: Option<String>,
 }
 
@@ -49,7 +75,90 @@ struct ProjectData {
     master_gain: f32,
     master_filter: f32,
     master_comp: f32,
+    #[serde(default = "default_project_swing")]
+    swing: f32,
     tracks: Vec<String>, // Paths to .trk files relative to project root
+    #[serde(default)]
+    midi_cc_mappings: Vec<MidiCcMapping>,
+}
+
+/// Projects saved before swing existed play straight.
+fn default_project_swing() -> f32 {
+    SWING_MIN
+}
+
+/// A hardware CC bound to one track parameter, keyed like the `.trk` params.
+#[derive(Serialize, Deserialize, Clone)]
+struct MidiCcMapping {
+    channel: u8,
+    cc: u8,
+    track: usize,
+    param: String,
+    min: f32,
+    max: f32,
+    #[serde(default)]
+    curve: u32,
+    /// Track fields `param` resolves to, filled in when the mapping is installed.
+    #[serde(skip)]
+    fields: Vec<TrackParamField>,
+}
+
+/// MIDI learn state and CC mappings, shared by the audio thread, UI, and background tasks.
+#[derive(Default)]
+struct MidiCcState {
+    mappings: Mutex<Vec<MidiCcMapping>>,
+    /// Lets the audio thread skip forwarding CCs when nothing is listening.
+    has_mappings: AtomicBool,
+    learn_armed: AtomicBool,
+    /// Track + param picked in learn mode by touching a control in the UI.
+    learn_target: Mutex<Option<(usize, String)>>,
+    /// Bumped whenever `mappings` changes so the UI can rebuild its list.
+    revision: AtomicU32,
+}
+
+impl MidiCcState {
+    fn mappings_changed(&self, mappings: &[MidiCcMapping]) {
+        self.has_mappings
+            .store(!mappings.is_empty(), Ordering::Relaxed);
+        self.revision.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn set_mappings(&self, mut new_mappings: Vec<MidiCcMapping>) {
+        for mapping in new_mappings.iter_mut() {
+            mapping.fields = resolve_track_param(&mapping.param);
+        }
+        let mut mappings = self.mappings.lock();
+        *mappings = new_mappings;
+        self.mappings_changed(&mappings);
+    }
+
+    /// Records `param` on `track` as the learn target while MIDI learn is armed, so the
+    /// last control touched in the UI is the one the next CC binds to.
+    fn touch(&self, track: usize, param: &str) {
+        if self.learn_armed.load(Ordering::Relaxed) {
+            *self.learn_target.lock() = Some((track, param.to_string()));
+        }
+    }
+}
+
+/// External MIDI clock sync for the standalone build, shared by the MIDI port
+/// threads, the audio thread, and the UI.
+#[derive(Default)]
+struct MidiClock {
+    /// Slave tempo and the master step clock to the selected clock input.
+    follow: AtomicBool,
+    /// Incoming clock is between Start/Continue and Stop.
+    running: AtomicBool,
+    /// Song position of the incoming clock in ticks (24 PPQN).
+    tick_position: AtomicI64,
+    /// Tempo measured from the incoming tick spacing (f32 bits, 0 until measured).
+    tempo: AtomicU32,
+    /// Transport state published by the audio thread for clock out.
+    out_playing: AtomicBool,
+    /// Ticks the audio thread has played since the transport started.
+    out_ticks: AtomicU64,
+    /// Bumped to retire the running clock out thread.
+    out_generation: AtomicU32,
 }
 
 #[derive(Clone, Copy, Default)]
@@ -57,29 +166,189 @@ struct PendingProjectParams {
     gain: f32,
     master_filter: f32,
     master_comp: f32,
+    swing: f32,
 }
 use std::process::Command as ProcessCommand;
 use std::sync::atomic::AtomicI32;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU64};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 use std::cell::RefCell;
 use std::sync::mpsc;
-use std::sync::{Arc, Once};
+use std::sync::{Arc, Once, OnceLock, Weak};
 use std::time::Instant;
 use std::f32::consts::PI;
 use fundsp::hacker32::{
-    AudioUnit, Tanh, bandpass, highpass, lowpass, moog, noise, shape, sine,
+    AudioUnit, Tanh, bandpass, highpass, lowpass, moog, noise, shape, sine, square,
 };
 
 pub const NUM_TRACKS: usize = 4;
 pub const SYNDRM_PAGE_SIZE: usize = 16;
 pub const SYNDRM_PAGES: usize = 8;
 pub const SYNDRM_STEPS: usize = SYNDRM_PAGE_SIZE * SYNDRM_PAGES;
-pub const SYNDRM_LANES: usize = 2;
+pub const SYNDRM_LANES: usize = 11;
+/// SynDRM lanes after kick and snare, which share the generic voice parameter set.
+pub const SYNDRM_VOICES: usize = SYNDRM_LANES - 2;
+/// Parameters per generic SynDRM voice: tune, decay, tone, drive, level.
+pub const SYNDRM_VOICE_PARAMS: usize = 5;
+/// Lane bitmask covering every SynDRM lane (bit 0 = kick, bit 1 = snare, then the voices).
+pub const SYNDRM_ALL_LANES: u16 = (1 << SYNDRM_LANES) - 1;
+const SYNDRM_VOICE_CLOSED_HAT: usize = 0;
+const SYNDRM_VOICE_OPEN_HAT: usize = 1;
+const SYNDRM_VOICE_CLAP: usize = 2;
+const SYNDRM_VOICE_LOW_TOM: usize = 3;
+const SYNDRM_VOICE_MID_TOM: usize = 4;
+const SYNDRM_VOICE_HIGH_TOM: usize = 5;
+const SYNDRM_VOICE_RIMSHOT: usize = 6;
+const SYNDRM_VOICE_COWBELL: usize = 7;
+const SYNDRM_VOICE_CYMBAL: usize = 8;
+/// Persistence key prefix for each generic SynDRM voice.
+const SYNDRM_VOICE_KEYS: [&str; SYNDRM_VOICES] = [
+    "closed_hat",
+    "open_hat",
+    "clap",
+    "low_tom",
+    "mid_tom",
+    "high_tom",
+    "rimshot",
+    "cowbell",
+    "cymbal",
+];
+/// Persistence key suffix for each generic SynDRM voice parameter.
+const SYNDRM_VOICE_PARAM_KEYS: [&str; SYNDRM_VOICE_PARAMS] =
+    ["tune", "decay", "tone", "drive", "level"];
+/// Default tune, decay, tone, drive and level for each generic SynDRM voice.
+const SYNDRM_VOICE_DEFAULTS: [[f32; SYNDRM_VOICE_PARAMS]; SYNDRM_VOICES] = [
+    [0.5, 0.3, 0.6, 0.0, 0.6],
+    [0.5, 0.4, 0.6, 0.0, 0.6],
+    [0.5, 0.4, 0.5, 0.0, 0.7],
+    [0.5, 0.5, 0.3, 0.0, 0.8],
+    [0.5, 0.5, 0.3, 0.0, 0.8],
+    [0.5, 0.5, 0.3, 0.0, 0.8],
+    [0.5, 0.3, 0.5, 0.0, 0.6],
+    [0.5, 0.4, 0.5, 0.0, 0.6],
+    [0.5, 0.5, 0.5, 0.0, 0.5],
+];
+/// Partial ratios of the six-square metallic source used by the hats and cymbal.
+const SYNDRM_METAL_RATIOS: [f32; 6] = [1.0, 1.4827, 1.8003, 2.546, 2.6303, 3.8968];
+/// Partial ratios of the two-square cowbell source.
+const SYNDRM_COWBELL_RATIOS: [f32; 2] = [1.0, 1.4815];
+const SYNDRM_SINGLE_RATIO: [f32; 1] = [1.0];
+/// Extra handclap bursts fired after the initial hit.
+const SYNDRM_CLAP_BURSTS: u32 = 3;
+/// Maximum ratchet (repeat) count for a SynDRM step.
+pub const SYNDRM_MAX_RATCHET: u32 = 4;
+/// Pending-hit slots per lane. A step's hits can start half a step early and end a step
+/// after that, and full swing makes neighbouring steps 3:1 in length, so up to four steps
+/// (two behind the playhead, the current one, and the next) can hold a full ratchet each.
+const SYNDRM_PENDING_HITS: usize = 4 * SYNDRM_MAX_RATCHET as usize;
 pub const SYNDRM_FILTER_TYPES: u32 = 4;
+pub const ANIMATE_SEQ_VOICES: usize = 10;
+pub const ANIMATE_SEQ_PAGE_SIZE: usize = 16;
+pub const ANIMATE_SEQ_PAGES: usize = 8;
+pub const ANIMATE_SEQ_STEPS: usize = ANIMATE_SEQ_PAGE_SIZE * ANIMATE_SEQ_PAGES;
+/// Animate parameters that can be locked per step, laid out as vector X/Y followed by
+/// the four slots' level, filter cutoff, filter resonance, and coarse pitch.
+pub const ANIMATE_LOCK_PARAMS: usize = 18;
+const ANIMATE_LOCK_VECTOR_X: usize = 0;
+const ANIMATE_LOCK_VECTOR_Y: usize = 1;
+const ANIMATE_LOCK_SLOT_LEVEL: usize = 2;
+const ANIMATE_LOCK_SLOT_CUTOFF: usize = 6;
+const ANIMATE_LOCK_SLOT_RESONANCE: usize = 10;
+const ANIMATE_LOCK_SLOT_COARSE: usize = 14;
+const ANIMATE_LOCK_NAMES: [&str; ANIMATE_LOCK_PARAMS] = [
+    "Vector X",
+    "Vector Y",
+    "A Level",
+    "B Level",
+    "C Level",
+    "D Level",
+    "A Cutoff",
+    "B Cutoff",
+    "C Cutoff",
+    "D Cutoff",
+    "A Resonance",
+    "B Resonance",
+    "C Resonance",
+    "D Resonance",
+    "A Coarse",
+    "B Coarse",
+    "C Coarse",
+    "D Coarse",
+];
+/// Sequencer root note (MIDI), C2.
+const ANIMATE_SEQ_DEFAULT_ROOT: u32 = 36;
+/// Scales the Animate sequencer maps its lanes onto, as semitone offsets from the root.
+const ANIMATE_SCALES: [(&str, &[u32]); 10] = [
+    ("Major Pentatonic", &[0, 2, 4, 7, 9]),
+    ("Minor Pentatonic", &[0, 3, 5, 7, 10]),
+    ("Major", &[0, 2, 4, 5, 7, 9, 11]),
+    ("Minor", &[0, 2, 3, 5, 7, 8, 10]),
+    ("Dorian", &[0, 2, 3, 5, 7, 9, 10]),
+    ("Phrygian", &[0, 1, 3, 5, 7, 8, 10]),
+    ("Lydian", &[0, 2, 4, 6, 7, 9, 11]),
+    ("Mixolydian", &[0, 2, 4, 5, 7, 9, 10]),
+    ("Harmonic Minor", &[0, 2, 3, 5, 7, 8, 11]),
+    ("Chromatic", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+];
+/// Per-slot envelope parameters: amp attack/decay/sustain/release, then filter envelope
+/// attack/decay/sustain/release and its bipolar amount.
+pub const ANIMATE_SLOT_ENV_PARAMS: usize = 9;
+const ANIMATE_SLOT_ENV_DEFAULTS: [f32; ANIMATE_SLOT_ENV_PARAMS] =
+    [0.01, 0.1, 0.8, 0.3, 0.01, 0.3, 0.0, 0.3, 0.0];
+const ANIMATE_SLOT_ENV_KEYS: [&str; ANIMATE_SLOT_ENV_PARAMS] = [
+    "amp_attack",
+    "amp_decay",
+    "amp_sustain",
+    "amp_release",
+    "filter_env_attack",
+    "filter_env_decay",
+    "filter_env_sustain",
+    "filter_env_release",
+    "filter_env_amount",
+];
+const ANIMATE_ENV_TIME_MAX: f32 = 10.0;
+/// Root key for Animate samples with no root in their `smpl` chunk or file name (C4).
+const ANIMATE_SAMPLE_DEFAULT_ROOT: f32 = 60.0;
+/// Root key projects saved before root detection assumed for every sample (A4).
+const ANIMATE_SAMPLE_LEGACY_ROOT: f32 = 69.0;
+/// Animate slot filter type for the four-pole ladder (after lp24, lp12, hp, bp).
+const ANIMATE_FILTER_MOOG: u32 = 4;
+/// Breakpoints in an Animate vector path.
+pub const ANIMATE_VECTOR_PATH_POINTS: usize = 32;
+/// Minimum time between recorded vector path points, in seconds.
+const ANIMATE_VECTOR_PATH_REC_INTERVAL: f32 = 0.05;
+/// Pad movement that counts as a new vector path point.
+const ANIMATE_VECTOR_PATH_REC_THRESHOLD: f32 = 0.01;
+/// Default Animate wavetable frame length, used when a file has no `clm ` chunk.
+pub const ANIMATE_WAVETABLE_FRAME: usize = 2048;
+/// Size of the Animate voice pool shared by the sequencer, external MIDI and the keybed.
+pub const ANIMATE_VOICES: usize = 16;
+/// Voice source ids after the sequencer lanes (sources 0..9).
+const ANIMATE_SOURCE_MIDI: u32 = ANIMATE_SEQ_VOICES as u32;
+const ANIMATE_SOURCE_KEYBED: u32 = ANIMATE_SEQ_VOICES as u32 + 1;
+pub const ANIMATE_VOICE_MODES: [&str; 4] = ["Poly", "Mono", "Legato", "Unison"];
+const ANIMATE_VOICE_MODE_MONO: u32 = 1;
+const ANIMATE_VOICE_MODE_LEGATO: u32 = 2;
+const ANIMATE_VOICE_MODE_UNISON: u32 = 3;
+pub const ANIMATE_STEAL_MODES: [&str; 2] = ["Oldest", "Quietest"];
+const ANIMATE_STEAL_QUIETEST: u32 = 1;
+/// Maximum voices stacked by one note in unison mode.
+pub const ANIMATE_UNISON_MAX: u32 = 8;
+/// Unison detune spread at full amount, in cents either side of the note.
+const ANIMATE_UNISON_DETUNE_CENTS: f32 = 50.0;
+/// Maximum Animate glide (portamento) time, in seconds.
+const ANIMATE_GLIDE_MAX: f32 = 2.0;
+pub const MIDI_EVENT_CAPACITY: usize = 512;
 pub const WAVEFORM_SUMMARY_SIZE: usize = 100;
-pub const RECORD_MAX_SECONDS: usize = 30;
+/// Seconds of tape a new recording pre-allocates before it starts growing.
+pub const RECORD_BUFFER_DEFAULT_SECONDS: u32 = 30;
+pub const RECORD_BUFFER_MIN_SECONDS: u32 = 5;
+pub const RECORD_BUFFER_MAX_SECONDS: u32 = 600;
+/// Samples per channel the audio thread copies into a grown recording buffer each block.
+const RECORD_MIGRATE_CHUNK: usize = 16_384;
+/// How often the recording helper thread checks for a grow request.
+const RECORD_GROW_POLL_MS: u64 = 5;
 pub const RECORD_MAX_SAMPLE_RATE: usize = 48_000;
-pub const RECORD_MAX_SAMPLES: usize = RECORD_MAX_SECONDS * RECORD_MAX_SAMPLE_RATE;
 pub const MOSAIC_BUFFER_SECONDS: usize = 4;
 pub const MOSAIC_BUFFER_SAMPLES: usize = MOSAIC_BUFFER_SECONDS * RECORD_MAX_SAMPLE_RATE;
 pub const MOSAIC_BUFFER_CHANNELS: usize = 2;
@@ -104,8 +373,34 @@ const ANIMATE_LFO_RATE_MAX_HZ: f32 = 20.0;
 const METRONOME_CLICK_MS: f32 = 12.0;
 const METRONOME_CLICK_GAIN: f32 = 0.25;
 const METRONOME_COUNT_IN_MAX_TICKS: u32 = 8;
+const RENDER_BLOCK_SIZE: usize = 512;
+const MASTER_RECORD_RING_FRAMES: usize = 1 << 19;
+const MASTER_RECORD_POLL_MS: u64 = 20;
+const MIDI_CLOCK_PPQN: u32 = 24;
+const MIDI_CLOCK_TICKS_PER_STEP: u64 = 6;
+const SWING_MIN: f32 = 50.0;
+const SWING_MAX: f32 = 75.0;
 const KEYLOCK_GRAIN_SIZE: usize = 256;
 const KEYLOCK_GRAIN_HOP: usize = KEYLOCK_GRAIN_SIZE / 2;
+const KEYLOCK_TONAL_HOP: usize = 1024;
+const KEYLOCK_RHYTHMIC_HOP: usize = 512;
+const KEYLOCK_ONSET_BLOCK: usize = 64;
+const KEYLOCK_ONSET_RATIO: f32 = 4.0;
+const KEYLOCK_ONSET_FLOOR: f32 = 1.0e-5;
+const KEYLOCK_ONSET_XFADE: f32 = 32.0;
+const KEYLOCK_ONSET_REFRACTORY: f32 = 2048.0;
+const TAPE_SLICE_MAX: usize = 64;
+/// Steps in the Tape slice row; master clock step N plays entry `N % 16`.
+const TAPE_SLICE_STEPS: usize = 16;
+/// Slice-row entry that silences its step.
+const TAPE_SLICE_REST: u32 = TAPE_SLICE_MAX as u32;
+/// Slice-row entry that lets the previous slice keep playing.
+const TAPE_SLICE_HOLD: u32 = TAPE_SLICE_MAX as u32 + 1;
+const TAPE_ONSET_FRAME: usize = 1024;
+const TAPE_ONSET_HOP: usize = 512;
+const TAPE_TEMPO_MIN: f32 = 80.0;
+const TAPE_TEMPO_MAX: f32 = 160.0;
+const TAPE_SLICE_MIDI_BASE: usize = 36;
 const OSCILLOSCOPE_SAMPLES: usize = 256;
 const SPECTRUM_BINS: usize = 48;
 const SPECTRUM_WINDOW: usize = 256;
@@ -173,7 +468,21 @@ struct Track {
     /// Count-in samples remaining before starting.
     count_in_remaining: AtomicU32,
     /// Recording head position in samples.
-    record_pos: AtomicU32,
+    record_pos: AtomicUsize,
+    /// The take started on an empty buffer and grows as it records (false for overdubs).
+    record_appending: AtomicBool,
+    /// Longer buffer prepared off the audio thread; the audio thread copies the take
+    /// into it a chunk per block, then swaps it with `samples`.
+    record_spare: Mutex<Vec<Vec<f32>>>,
+    /// Length the audio thread wants the spare buffer to have (0 = no request).
+    record_grow_len: AtomicUsize,
+    /// Samples per channel already copied into the spare buffer.
+    record_migrated: AtomicUsize,
+    /// Incremented on every record start so a stale helper thread stands down.
+    record_generation: AtomicU32,
+    /// Input samples per channel lost because the take filled its buffer before a
+    /// longer one was ready.
+    record_dropped: AtomicUsize,
     /// Whether the track is currently playing.
     is_playing: AtomicBool,
     /// Playback position in samples. Stored as u32 bits for f32.
@@ -188,6 +497,10 @@ struct Track {
     meter_right: AtomicU32,
     /// Track mute state.
     is_muted: AtomicBool,
+    /// Use this track's own swing instead of the global amount.
+    swing_override: AtomicBool,
+    /// Track swing in percent (50 = straight), used when overridden.
+    swing: AtomicU32,
     /// Tape speed multiplier.
     tape_speed: AtomicU32,
     /// Smoothed tape speed.
@@ -210,12 +523,45 @@ struct Track {
     tape_freeze: AtomicBool,
     /// Tape keylock toggle.
     tape_keylock: AtomicBool,
+    /// Tape pitch shift in semitones (-24..24).
+    tape_pitch: AtomicU32,
+    /// Tape fine pitch in cents (-100..100).
+    tape_fine: AtomicU32,
+    /// Whether the tape played through the time-stretch last block.
+    tape_stretch_active: AtomicBool,
     /// Keylock grain phase (0..KEYLOCK_GRAIN_HOP).
     keylock_phase: AtomicU32,
     /// Keylock grain A start position in samples.
     keylock_grain_a: AtomicU32,
     /// Keylock grain B start position in samples.
     keylock_grain_b: AtomicU32,
+    /// Keylock algorithm (0 = Classic, 1 = Tonal, 2 = Rhythmic).
+    tape_keylock_mode: AtomicU32,
+    /// Keylock crossfade length of the current grain in samples.
+    keylock_xfade: AtomicU32,
+    /// Next transient ahead of the playhead (-1 when none is pending).
+    keylock_onset: AtomicU32,
+    /// Last transient a Rhythmic grain was started on (-1 when none).
+    keylock_onset_last: AtomicU32,
+    /// Slice playback (0 = Off, 1 = Steps, 2 = MIDI).
+    tape_slice_mode: AtomicU32,
+    /// Number of detected slices.
+    tape_slice_count: AtomicU32,
+    /// Slice start positions in samples.
+    tape_slice_starts: [AtomicU32; TAPE_SLICE_MAX],
+    /// Slice each step of the 16-step row plays (taken modulo the slice count),
+    /// or `TAPE_SLICE_REST` / `TAPE_SLICE_HOLD`.
+    tape_slice_order: [AtomicU32; TAPE_SLICE_STEPS],
+    /// Slice currently playing.
+    tape_slice_current: AtomicU32,
+    /// Whether the current slice is still sounding.
+    tape_slice_playing: AtomicBool,
+    /// Master step that last triggered a slice (-1 before the first).
+    tape_slice_step: AtomicI64,
+    /// Detected tempo of the loaded sample in BPM (0 when none was found).
+    tape_detected_bpm: AtomicU32,
+    /// Detected first downbeat of the loaded sample in samples.
+    tape_downbeat: AtomicU32,
     /// Tape monitor toggle.
     tape_monitor: AtomicBool,
     /// Tape overdub toggle.
@@ -412,6 +758,8 @@ struct Track {
     animate_slot_pan: [AtomicU32; 4],
     /// Smoothed animate slot pan.
     animate_slot_pan_smooth: [AtomicU32; 4],
+    /// Animate wavetable position (0..1) across the table's frames.
+    animate_slot_wt_position: [AtomicU32; 4],
     /// Animate wavetable LFO amount.
     animate_slot_wt_lfo_amount: [AtomicU32; 4],
     /// Animate wavetable LFO shape.
@@ -432,20 +780,54 @@ struct Track {
     animate_slot_loop_start: [AtomicU32; 4],
     /// Animate sample loop end (normalized 0..1).
     animate_slot_loop_end: [AtomicU32; 4],
-    /// Animate slot filter type (0 = lp24, 1 = lp12, 2 = hp, 3 = bp).
+    /// Animate sample slot plays its sample's whole multisample set, zoned by root key and velocity.
+    animate_slot_multisample: [AtomicBool; 4],
+    /// Root key (MIDI note, f32 bits) for samples that don't name their own root.
+    animate_sample_default_root: AtomicU32,
+    /// Animate slot filter type (0 = lp24, 1 = lp12, 2 = hp, 3 = bp, 4 = Moog ladder).
     animate_slot_filter_type: [AtomicU32; 4],
     /// Animate slot filter cutoff (normalized 0..1).
     animate_slot_filter_cutoff: [AtomicU32; 4],
     /// Animate slot filter resonance (normalized 0..1).
     animate_slot_filter_resonance: [AtomicU32; 4],
+    /// Animate slot ladder filter drive (normalized 0..1).
+    animate_slot_filter_drive: [AtomicU32; 4],
+    /// Animate slot ladder filter stage states (per voice).
+    animate_slot_ladder_state: [[[AtomicU32; 4]; 4]; ANIMATE_VOICES],
+    /// Animate slot amp envelope attack (seconds).
+    animate_slot_amp_attack: [AtomicU32; 4],
+    /// Animate slot amp envelope decay (seconds).
+    animate_slot_amp_decay: [AtomicU32; 4],
+    /// Animate slot amp envelope sustain level (0..1).
+    animate_slot_amp_sustain: [AtomicU32; 4],
+    /// Animate slot amp envelope release (seconds).
+    animate_slot_amp_release: [AtomicU32; 4],
+    /// Animate slot filter envelope attack (seconds).
+    animate_slot_filter_env_attack: [AtomicU32; 4],
+    /// Animate slot filter envelope decay (seconds).
+    animate_slot_filter_env_decay: [AtomicU32; 4],
+    /// Animate slot filter envelope sustain level (0..1).
+    animate_slot_filter_env_sustain: [AtomicU32; 4],
+    /// Animate slot filter envelope release (seconds).
+    animate_slot_filter_env_release: [AtomicU32; 4],
+    /// Animate slot filter envelope amount added to cutoff (-1..1).
+    animate_slot_filter_env_amount: [AtomicU32; 4],
+    /// Animate slot amp envelope stage (per voice, same stages as `animate_amp_stage`).
+    animate_slot_amp_env_stage: [[AtomicU32; 4]; ANIMATE_VOICES],
+    /// Animate slot amp envelope level (per voice).
+    animate_slot_amp_env_level: [[AtomicU32; 4]; ANIMATE_VOICES],
+    /// Animate slot filter envelope stage (per voice).
+    animate_slot_filter_env_stage: [[AtomicU32; 4]; ANIMATE_VOICES],
+    /// Animate slot filter envelope level (per voice).
+    animate_slot_filter_env_level: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate slot filter state v1 (per voice).
-    animate_slot_filter_v1: [[AtomicU32; 4]; 10],
+    animate_slot_filter_v1: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate slot filter state v2 (per voice).
-    animate_slot_filter_v2: [[AtomicU32; 4]; 10],
+    animate_slot_filter_v2: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate slot filter state v1 stage 2 (per voice).
-    animate_slot_filter_v1_stage2: [[AtomicU32; 4]; 10],
+    animate_slot_filter_v1_stage2: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate slot filter state v2 stage 2 (per voice).
-    animate_slot_filter_v2_stage2: [[AtomicU32; 4]; 10],
+    animate_slot_filter_v2_stage2: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate vector position X (0..1).
     animate_vector_x: AtomicU32,
     /// Animate vector position Y (0..1).
@@ -454,6 +836,32 @@ struct Track {
     animate_vector_x_smooth: AtomicU32,
     /// Smoothed animate vector position Y.
     animate_vector_y_smooth: AtomicU32,
+    /// Animate vector path playback enabled.
+    animate_vector_path_enabled: AtomicBool,
+    /// Animate vector path follows tempo changes relative to its recording tempo.
+    animate_vector_path_sync: AtomicBool,
+    /// Animate vector path loops between its loop points.
+    animate_vector_path_loop: AtomicBool,
+    /// Animate vector path loop start point index.
+    animate_vector_path_loop_start: AtomicU32,
+    /// Animate vector path loop end point index.
+    animate_vector_path_loop_end: AtomicU32,
+    /// Number of points in the Animate vector path.
+    animate_vector_path_len: AtomicU32,
+    /// Animate vector path point X (0..1).
+    animate_vector_path_x: [AtomicU32; ANIMATE_VECTOR_PATH_POINTS],
+    /// Animate vector path point Y (0..1).
+    animate_vector_path_y: [AtomicU32; ANIMATE_VECTOR_PATH_POINTS],
+    /// Seconds from the previous vector path point to each point.
+    animate_vector_path_time: [AtomicU32; ANIMATE_VECTOR_PATH_POINTS],
+    /// Tempo (BPM) the Animate vector path was recorded at.
+    animate_vector_path_tempo: AtomicU32,
+    /// Animate vector path recording armed.
+    animate_vector_path_recording: AtomicBool,
+    /// Samples since the last recorded vector path point.
+    animate_vector_path_rec_elapsed: AtomicU32,
+    /// Animate vector path playback position (seconds) for each voice.
+    animate_vector_path_pos: [AtomicU32; ANIMATE_VOICES],
     /// Animate vector LFO X waveform (0 = sine, 1 = triangle, 2 = square, 3 = saw, 4 = sample&hold).
     animate_lfo_x_waveform: AtomicU32,
     /// Animate vector LFO X sync to BPM.
@@ -484,42 +892,78 @@ struct Track {
     animate_lfo_y_snh: AtomicU32,
     /// Animate LFO RNG state.
     animate_lfo_rng_state: AtomicU32,
-    /// Animate sequencer grid (10 rows * 16 steps).
-    animate_sequencer_grid: Arc<[AtomicBool; 160]>,
+    /// Animate sequencer grid (10 lanes * 128 steps).
+    animate_sequencer_grid: Arc<[[AtomicBool; ANIMATE_SEQ_STEPS]; ANIMATE_SEQ_VOICES]>,
+    /// Animate sequencer scale index into `ANIMATE_SCALES`.
+    animate_scale: AtomicU32,
+    /// Animate sequencer root note (MIDI) for lane 0.
+    animate_root_note: AtomicU32,
+    /// Animate per-step note override enabled.
+    animate_step_note_override: Arc<[[AtomicBool; ANIMATE_SEQ_STEPS]; ANIMATE_SEQ_VOICES]>,
+    /// Animate per-step override note (MIDI).
+    animate_step_note: Arc<[[AtomicU32; ANIMATE_SEQ_STEPS]; ANIMATE_SEQ_VOICES]>,
+    /// Animate per-step parameter lock enabled, indexed by step then `ANIMATE_LOCK_*` param.
+    animate_step_lock_enabled: Arc<[[AtomicBool; ANIMATE_LOCK_PARAMS]; ANIMATE_SEQ_STEPS]>,
+    /// Animate per-step parameter lock values.
+    animate_step_lock_values: Arc<[[AtomicU32; ANIMATE_LOCK_PARAMS]; ANIMATE_SEQ_STEPS]>,
+    /// Parameter shown in the Animate step lock editor.
+    animate_lock_param: AtomicU32,
+    /// Animate sequencer page (0..7).
+    animate_page: AtomicU32,
+    /// Animate step editor lane (0..9).
+    animate_edit_lane: AtomicU32,
+    /// Animate step editor step index (0..127).
+    animate_edit_step: AtomicU32,
     /// Animate sequencer current step.
     animate_sequencer_step: AtomicI32,
     /// Animate sequencer phase in samples.
     animate_sequencer_phase: AtomicU32,
     /// Animate slot oscillator phases (0..1) for each voice.
-    animate_slot_phases: [[AtomicU32; 4]; 10],
+    animate_slot_phases: [[AtomicU32; 4]; ANIMATE_VOICES],
     /// Animate slot sample playback positions (in samples) for each voice.
-    animate_slot_sample_pos: [[AtomicU32; 4]; 10],
-    /// Animate amp envelope stage (0 = idle, 1 = attack, 2 = decay, 3 = sustain, 4 = release) for each voice.
-    animate_amp_stage: [AtomicU32; 10],
-    /// Animate amp envelope level (0..1) for each voice.
-    animate_amp_level: [AtomicU32; 10],
+    animate_slot_sample_pos: [[AtomicU32; 4]; ANIMATE_VOICES],
+    /// Library sample each voice plays in each slot, picked from the zones at note-on.
+    animate_slot_voice_sample: [[AtomicU32; 4]; ANIMATE_VOICES],
+    /// Animate voice gate (0 = idle, 1 = triggered, 2 = held, 4 = released) for each voice.
+    animate_amp_stage: [AtomicU32; ANIMATE_VOICES],
+    /// Animate voice level (0..1), the loudest of its slot amp envelopes.
+    animate_amp_level: [AtomicU32; ANIMATE_VOICES],
+    /// Animate voice mode index into `ANIMATE_VOICE_MODES`.
+    animate_voice_mode: AtomicU32,
+    /// Animate voices available to the allocator (1..=16).
+    animate_polyphony: AtomicU32,
+    /// Animate voice stealing policy index into `ANIMATE_STEAL_MODES`.
+    animate_steal_mode: AtomicU32,
+    /// Voices stacked per note in unison mode (2..=8).
+    animate_unison_voices: AtomicU32,
+    /// Unison detune spread (0..1).
+    animate_unison_detune: AtomicU32,
+    /// Animate glide time in seconds.
+    animate_glide: AtomicU32,
+    /// Note held by each Animate voice (-1 = free).
+    animate_voice_note: [AtomicI32; ANIMATE_VOICES],
+    /// Source that triggered each Animate voice (sequencer lane, MIDI or keybed).
+    animate_voice_source: [AtomicU32; ANIMATE_VOICES],
+    /// Note-on velocity (0..1) of each Animate voice.
+    animate_voice_velocity: [AtomicU32; ANIMATE_VOICES],
+    /// Note-on order of each Animate voice, used to steal the oldest voice.
+    animate_voice_age: [AtomicU32; ANIMATE_VOICES],
+    /// Current (gliding) pitch of each Animate voice, in MIDI semitones.
+    animate_voice_pitch: [AtomicU32; ANIMATE_VOICES],
+    /// Unison detune offset of each Animate voice, in semitones.
+    animate_voice_detune: [AtomicU32; ANIMATE_VOICES],
+    /// Samples left before each Animate voice auto-releases (0 = held until note off).
+    animate_voice_gate: [AtomicU32; ANIMATE_VOICES],
+    /// Running note-on counter for the Animate voice allocator.
+    animate_voice_counter: AtomicU32,
+    /// Pitch of the last Animate note-on, where the next note glides from.
+    animate_last_pitch: AtomicU32,
     /// Animate keybed trigger note (MIDI note).
     animate_keybed_note: AtomicI32,
     /// Animate keybed trigger flag.
     animate_keybed_trigger: AtomicBool,
     /// Animate keybed hold (sustain).
     animate_keybed_hold: AtomicBool,
-    /// Animate keybed amp envelope stage.
-    animate_keybed_amp_stage: AtomicU32,
-    /// Animate keybed amp envelope level.
-    animate_keybed_amp_level: AtomicU32,
-    /// Animate keybed slot oscillator phases.
-    animate_keybed_slot_phases: [AtomicU32; 4],
-    /// Animate keybed slot sample playback positions.
-    animate_keybed_slot_sample_pos: [AtomicU32; 4],
-    /// Animate keybed filter state v1 per slot.
-    animate_keybed_filter_v1: [AtomicU32; 4],
-    /// Animate keybed filter state v2 per slot.
-    animate_keybed_filter_v2: [AtomicU32; 4],
-    /// Animate keybed filter state v1 stage 2 per slot.
-    animate_keybed_filter_v1_stage2: [AtomicU32; 4],
-    /// Animate keybed filter state v2 stage 2 per slot.
-    animate_keybed_filter_v2_stage2: [AtomicU32; 4],
     /// SynDRM kick pitch (normalized 0..1).
     kick_pitch: AtomicU32,
     /// SynDRM kick decay (normalized 0..1).
@@ -638,6 +1082,23 @@ struct Track {
     snare_step_filter_cutoff: Arc<[AtomicU32; SYNDRM_STEPS]>,
     /// SynDRM snare step filter resonance override.
     snare_step_filter_resonance: Arc<[AtomicU32; SYNDRM_STEPS]>,
+    /// SynDRM generic voice parameters, indexed [voice][param] (normalized 0..1).
+    syndrm_voice_params: [[AtomicU32; SYNDRM_VOICE_PARAMS]; SYNDRM_VOICES],
+    /// SynDRM generic voice sequencer grids (128 steps per voice).
+    syndrm_voice_grid: Arc<[[AtomicBool; SYNDRM_STEPS]; SYNDRM_VOICES]>,
+    /// SynDRM generic voice step override enabled.
+    syndrm_voice_step_override_enabled: Arc<[[AtomicBool; SYNDRM_STEPS]; SYNDRM_VOICES]>,
+    /// SynDRM generic voice step parameter overrides, indexed [voice][step][param].
+    syndrm_voice_step_params:
+        Arc<[[[AtomicU32; SYNDRM_VOICE_PARAMS]; SYNDRM_STEPS]; SYNDRM_VOICES]>,
+    /// SynDRM step velocity per lane (0..1).
+    syndrm_step_velocity: Arc<[[AtomicU32; SYNDRM_STEPS]; SYNDRM_LANES]>,
+    /// SynDRM step trigger probability per lane (0..1).
+    syndrm_step_probability: Arc<[[AtomicU32; SYNDRM_STEPS]; SYNDRM_LANES]>,
+    /// SynDRM step micro-timing offset per lane, in steps (-0.5..0.5).
+    syndrm_step_micro: Arc<[[AtomicU32; SYNDRM_STEPS]; SYNDRM_LANES]>,
+    /// SynDRM step ratchet count per lane (1..=4 hits spread across the step).
+    syndrm_step_ratchet: Arc<[[AtomicU32; SYNDRM_STEPS]; SYNDRM_LANES]>,
     /// Void Seed base frequency.
     void_base_freq: AtomicU32,
     /// Smoothed void base frequency.
@@ -686,6 +1147,8 @@ struct Track {
     void_delay_buffer: Arc<Mutex<[Vec<f32>; 2]>>,
     /// Void Seed delay write position.
     void_delay_write_pos: AtomicU32,
+    /// MIDI input channel for this track (0 = omni, 1..16 = channel).
+    midi_channel: AtomicU32,
     /// Engine type loaded for this track (0 = none, 1 = tape, 2 = animate, 3 = syndrm, 4 = voidseed).
     engine_type: AtomicU32,
     /// Logs one debug line per playback start to confirm audio thread output.
@@ -710,7 +1173,13 @@ impl Default for Track {
             pending_play: AtomicBool::new(false),
             pending_record: AtomicBool::new(false),
             count_in_remaining: AtomicU32::new(0),
-            record_pos: AtomicU32::new(0.0f32.to_bits()),
+            record_pos: AtomicUsize::new(0),
+            record_appending: AtomicBool::new(false),
+            record_spare: Mutex::new(Vec::new()),
+            record_grow_len: AtomicUsize::new(0),
+            record_migrated: AtomicUsize::new(0),
+            record_generation: AtomicU32::new(0),
+            record_dropped: AtomicUsize::new(0),
             is_playing: AtomicBool::new(false),
             play_pos: AtomicU32::new(0.0f32.to_bits()),
             level: AtomicU32::new(1.0f32.to_bits()),
@@ -718,6 +1187,8 @@ impl Default for Track {
             meter_left: AtomicU32::new(0.0f32.to_bits()),
             meter_right: AtomicU32::new(0.0f32.to_bits()),
             is_muted: AtomicBool::new(false),
+            swing_override: AtomicBool::new(false),
+            swing: AtomicU32::new(SWING_MIN.to_bits()),
             tape_speed: AtomicU32::new(1.0f32.to_bits()),
             tape_speed_smooth: AtomicU32::new(1.0f32.to_bits()),
             tape_tempo: AtomicU32::new(120.0f32.to_bits()),
@@ -729,9 +1200,25 @@ impl Default for Track {
             tape_reverse: AtomicBool::new(false),
             tape_freeze: AtomicBool::new(false),
             tape_keylock: AtomicBool::new(false),
+            tape_pitch: AtomicU32::new(0.0f32.to_bits()),
+            tape_fine: AtomicU32::new(0.0f32.to_bits()),
+            tape_stretch_active: AtomicBool::new(false),
             keylock_phase: AtomicU32::new(0.0f32.to_bits()),
             keylock_grain_a: AtomicU32::new(0.0f32.to_bits()),
             keylock_grain_b: AtomicU32::new(0.0f32.to_bits()),
+            tape_keylock_mode: AtomicU32::new(1),
+            keylock_xfade: AtomicU32::new(1.0f32.to_bits()),
+            keylock_onset: AtomicU32::new((-1.0f32).to_bits()),
+            keylock_onset_last: AtomicU32::new((-1.0f32).to_bits()),
+            tape_slice_mode: AtomicU32::new(0),
+            tape_slice_count: AtomicU32::new(0),
+            tape_slice_starts: std::array::from_fn(|_| AtomicU32::new(0)),
+            tape_slice_order: std::array::from_fn(|i| AtomicU32::new(i as u32)),
+            tape_slice_current: AtomicU32::new(0),
+            tape_slice_playing: AtomicBool::new(false),
+            tape_slice_step: AtomicI64::new(-1),
+            tape_detected_bpm: AtomicU32::new(0.0f32.to_bits()),
+            tape_downbeat: AtomicU32::new(0),
             tape_monitor: AtomicBool::new(false),
             tape_overdub: AtomicBool::new(false),
             loop_start: AtomicU32::new(0.0f32.to_bits()),
@@ -833,6 +1320,7 @@ impl Default for Track {
             animate_slot_level_smooth: std::array::from_fn(|_| AtomicU32::new(1.0f32.to_bits())),
             animate_slot_pan: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_slot_pan_smooth: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_slot_wt_position: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_slot_wt_lfo_amount: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_slot_wt_lfo_shape: std::array::from_fn(|_| AtomicU32::new(0)),
             animate_slot_wt_lfo_rate: std::array::from_fn(|_| AtomicU32::new(0.5f32.to_bits())),
@@ -843,9 +1331,56 @@ impl Default for Track {
             animate_slot_sample_start: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_slot_loop_start: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_slot_loop_end: std::array::from_fn(|_| AtomicU32::new(1.0f32.to_bits())),
+            animate_slot_multisample: std::array::from_fn(|_| AtomicBool::new(false)),
+            animate_sample_default_root: AtomicU32::new(ANIMATE_SAMPLE_DEFAULT_ROOT.to_bits()),
             animate_slot_filter_type: std::array::from_fn(|_| AtomicU32::new(0)),
             animate_slot_filter_cutoff: std::array::from_fn(|_| AtomicU32::new(0.5f32.to_bits())),
             animate_slot_filter_resonance: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_slot_filter_drive: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_slot_ladder_state: std::array::from_fn(|_| {
+                std::array::from_fn(|_| {
+                    std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
+                })
+            }),
+            animate_slot_amp_attack: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[0].to_bits())
+            }),
+            animate_slot_amp_decay: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[1].to_bits())
+            }),
+            animate_slot_amp_sustain: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[2].to_bits())
+            }),
+            animate_slot_amp_release: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[3].to_bits())
+            }),
+            animate_slot_filter_env_attack: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[4].to_bits())
+            }),
+            animate_slot_filter_env_decay: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[5].to_bits())
+            }),
+            animate_slot_filter_env_sustain: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[6].to_bits())
+            }),
+            animate_slot_filter_env_release: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[7].to_bits())
+            }),
+            animate_slot_filter_env_amount: std::array::from_fn(|_| {
+                AtomicU32::new(ANIMATE_SLOT_ENV_DEFAULTS[8].to_bits())
+            }),
+            animate_slot_amp_env_stage: std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0))
+            }),
+            animate_slot_amp_env_level: std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
+            }),
+            animate_slot_filter_env_stage: std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0))
+            }),
+            animate_slot_filter_env_level: std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
+            }),
             animate_slot_filter_v1: std::array::from_fn(|_| {
                 std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
             }),
@@ -862,6 +1397,19 @@ impl Default for Track {
             animate_vector_y: AtomicU32::new(0.5f32.to_bits()),
             animate_vector_x_smooth: AtomicU32::new(0.5f32.to_bits()),
             animate_vector_y_smooth: AtomicU32::new(0.5f32.to_bits()),
+            animate_vector_path_enabled: AtomicBool::new(false),
+            animate_vector_path_sync: AtomicBool::new(false),
+            animate_vector_path_loop: AtomicBool::new(false),
+            animate_vector_path_loop_start: AtomicU32::new(0),
+            animate_vector_path_loop_end: AtomicU32::new(0),
+            animate_vector_path_len: AtomicU32::new(0),
+            animate_vector_path_x: std::array::from_fn(|_| AtomicU32::new(0.5f32.to_bits())),
+            animate_vector_path_y: std::array::from_fn(|_| AtomicU32::new(0.5f32.to_bits())),
+            animate_vector_path_time: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_vector_path_tempo: AtomicU32::new(120.0f32.to_bits()),
+            animate_vector_path_recording: AtomicBool::new(false),
+            animate_vector_path_rec_elapsed: AtomicU32::new(0),
+            animate_vector_path_pos: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             animate_lfo_x_waveform: AtomicU32::new(0),
             animate_lfo_x_sync: AtomicBool::new(false),
             animate_lfo_x_division: AtomicU32::new(0),
@@ -877,24 +1425,52 @@ impl Default for Track {
             animate_lfo_y_phase: AtomicU32::new(0.0f32.to_bits()),
             animate_lfo_y_snh: AtomicU32::new(0.0f32.to_bits()),
             animate_lfo_rng_state: AtomicU32::new(0x2468_ace1),
-            animate_sequencer_grid: Arc::new(std::array::from_fn(|_| AtomicBool::new(false))),
+            animate_sequencer_grid: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicBool::new(false))
+            })),
+            animate_scale: AtomicU32::new(0),
+            animate_root_note: AtomicU32::new(ANIMATE_SEQ_DEFAULT_ROOT),
+            animate_step_note_override: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicBool::new(false))
+            })),
+            animate_step_note: Arc::new(std::array::from_fn(|lane| {
+                std::array::from_fn(|_| AtomicU32::new(animate_lane_note(0, ANIMATE_SEQ_DEFAULT_ROOT, lane)))
+            })),
+            animate_step_lock_enabled: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicBool::new(false))
+            })),
+            animate_step_lock_values: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
+            })),
+            animate_lock_param: AtomicU32::new(0),
+            animate_page: AtomicU32::new(0),
+            animate_edit_lane: AtomicU32::new(0),
+            animate_edit_step: AtomicU32::new(0),
             animate_sequencer_step: AtomicI32::new(-1),
             animate_sequencer_phase: AtomicU32::new(0),
             animate_slot_phases: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))),
             animate_slot_sample_pos: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))),
+            animate_slot_voice_sample: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU32::new(0))),
             animate_amp_stage: std::array::from_fn(|_| AtomicU32::new(0)),
             animate_amp_level: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_voice_mode: AtomicU32::new(0),
+            animate_polyphony: AtomicU32::new(ANIMATE_VOICES as u32),
+            animate_steal_mode: AtomicU32::new(0),
+            animate_unison_voices: AtomicU32::new(4),
+            animate_unison_detune: AtomicU32::new(0.2f32.to_bits()),
+            animate_glide: AtomicU32::new(0.0f32.to_bits()),
+            animate_voice_note: std::array::from_fn(|_| AtomicI32::new(-1)),
+            animate_voice_source: std::array::from_fn(|_| AtomicU32::new(0)),
+            animate_voice_velocity: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_voice_age: std::array::from_fn(|_| AtomicU32::new(0)),
+            animate_voice_pitch: std::array::from_fn(|_| AtomicU32::new(60.0f32.to_bits())),
+            animate_voice_detune: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
+            animate_voice_gate: std::array::from_fn(|_| AtomicU32::new(0)),
+            animate_voice_counter: AtomicU32::new(0),
+            animate_last_pitch: AtomicU32::new((-1.0f32).to_bits()),
             animate_keybed_note: AtomicI32::new(60),
             animate_keybed_trigger: AtomicBool::new(false),
             animate_keybed_hold: AtomicBool::new(false),
-            animate_keybed_amp_stage: AtomicU32::new(0),
-            animate_keybed_amp_level: AtomicU32::new(0.0f32.to_bits()),
-            animate_keybed_slot_phases: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
-            animate_keybed_slot_sample_pos: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
-            animate_keybed_filter_v1: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
-            animate_keybed_filter_v2: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
-            animate_keybed_filter_v1_stage2: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
-            animate_keybed_filter_v2_stage2: std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits())),
             kick_pitch: AtomicU32::new(0.5f32.to_bits()),
             kick_decay: AtomicU32::new(0.5f32.to_bits()),
             kick_attack: AtomicU32::new(0.0f32.to_bits()),
@@ -954,6 +1530,32 @@ impl Default for Track {
             snare_step_filter_type: Arc::new(std::array::from_fn(|_| AtomicU32::new(0))),
             snare_step_filter_cutoff: Arc::new(std::array::from_fn(|_| AtomicU32::new(0.6f32.to_bits()))),
             snare_step_filter_resonance: Arc::new(std::array::from_fn(|_| AtomicU32::new(0.2f32.to_bits()))),
+            syndrm_voice_params: std::array::from_fn(|v| {
+                std::array::from_fn(|p| AtomicU32::new(SYNDRM_VOICE_DEFAULTS[v][p].to_bits()))
+            }),
+            syndrm_voice_grid: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicBool::new(false))
+            })),
+            syndrm_voice_step_override_enabled: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicBool::new(false))
+            })),
+            syndrm_voice_step_params: Arc::new(std::array::from_fn(|v| {
+                std::array::from_fn(|_| {
+                    std::array::from_fn(|p| AtomicU32::new(SYNDRM_VOICE_DEFAULTS[v][p].to_bits()))
+                })
+            })),
+            syndrm_step_velocity: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(1.0f32.to_bits()))
+            })),
+            syndrm_step_probability: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(1.0f32.to_bits()))
+            })),
+            syndrm_step_micro: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(0.0f32.to_bits()))
+            })),
+            syndrm_step_ratchet: Arc::new(std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU32::new(1))
+            })),
             void_base_freq: AtomicU32::new(40.0f32.to_bits()),
             void_base_freq_smooth: AtomicU32::new(40.0f32.to_bits()),
             void_enabled: AtomicBool::new(false),
@@ -991,6 +1593,7 @@ impl Default for Track {
             void_internal_gain: AtomicU32::new(0.0f32.to_bits()),
             void_delay_buffer: Arc::new(Mutex::new([vec![0.0; 65536], vec![0.0; 65536]])),
             void_delay_write_pos: AtomicU32::new(0),
+            midi_channel: AtomicU32::new(0),
             engine_type: AtomicU32::new(0),
             debug_logged: AtomicBool::new(false),
             sample_rate: AtomicU32::new(44_100),
@@ -1016,10 +1619,355 @@ pub struct TLBX1 {
     master_step_count: i64,
     animate_library: Arc<AnimateLibrary>,
     master_fx: MasterFxState,
-    sample_rate: AtomicU32,
+    /// Host sample rate, shared with the editor so recordings are sized for it.
+    sample_rate: Arc<AtomicU32>,
     pending_project_params: Arc<Mutex<Option<PendingProjectParams>>>,
     track_buffer: Vec<Vec<f32>>,
+    midi_events: Vec<MidiNoteEvent>,
     syndrm_dsp: [SynDRMDspState; NUM_TRACKS],
+    master_recorder: Arc<MasterRecorder>,
+    midi_cc: Arc<MidiCcState>,
+    midi_clock: Arc<MidiClock>,
+    /// Host play state seen in the previous block, to catch play/stop edges.
+    host_was_playing: bool,
+    /// Animate slot sources each track played last block, to queue loads for new ones.
+    animate_sources_seen: [Option<AnimateSlotSources>; NUM_TRACKS],
+    /// Fixed master settings while rendering offline; `None` for live processing.
+    render_master: Option<PendingProjectParams>,
+    /// Per-track post-FX output captured during an offline stem render: [track][channel][sample].
+    render_stems: Vec<Vec<Vec<f32>>>,
+}
+
+/// Host transport state at the start of a block.
+#[derive(Clone, Copy, Default)]
+struct HostTransport {
+    playing: bool,
+    /// Song position in quarter notes.
+    pos_beats: Option<f64>,
+    /// Start of the current bar in quarter notes.
+    bar_start_beats: Option<f64>,
+    /// Zero-based index of the current bar.
+    bar_number: Option<i32>,
+}
+
+/// The master 16th grid with swing applied.
+///
+/// Swing is given MPC-style: 50% is straight, 66% is a triplet shuffle. Each pair of
+/// steps keeps its straight length, so every even step and every bar line stays on the
+/// straight grid and only the odd (off-beat) 16ths move later.
+#[derive(Clone, Copy)]
+struct SwingClock {
+    samples_per_step: f32,
+    /// How far odd steps start late, as a fraction of a straight step.
+    delay: f32,
+}
+
+impl SwingClock {
+    fn new(samples_per_step: f32, swing_percent: f32) -> Self {
+        let swing = if swing_percent.is_finite() {
+            swing_percent.clamp(SWING_MIN, SWING_MAX)
+        } else {
+            SWING_MIN
+        };
+        Self {
+            samples_per_step,
+            delay: swing / 50.0 - 1.0,
+        }
+    }
+
+    /// Length of `step` in samples; even steps stretch by what odd steps give up.
+    fn step_len(&self, step: i64) -> f32 {
+        if step.rem_euclid(2) == 0 {
+            self.samples_per_step * (1.0 + self.delay)
+        } else {
+            self.samples_per_step * (1.0 - self.delay)
+        }
+    }
+
+    /// Maps a straight step position onto the swung grid.
+    fn position(&self, straight_steps: f64) -> f64 {
+        let pair = (straight_steps * 0.5).floor();
+        let offset = straight_steps - pair * 2.0;
+        let split = 1.0 + self.delay as f64;
+        if offset < split {
+            pair * 2.0 + offset / split
+        } else {
+            pair * 2.0 + 1.0 + (offset - split) / (2.0 - split)
+        }
+    }
+
+    /// Converts a straight step count and phase into the swung step and the samples
+    /// elapsed within it.
+    fn locate(&self, step_count: i64, phase: f32) -> (i64, f32) {
+        let split = self.samples_per_step * (1.0 + self.delay);
+        let pair_phase = if step_count.rem_euclid(2) == 0 {
+            phase
+        } else {
+            phase + self.samples_per_step
+        };
+        let pair_start = step_count - step_count.rem_euclid(2);
+        if pair_phase < split {
+            (pair_start, pair_phase)
+        } else {
+            (pair_start + 1, pair_phase - split)
+        }
+    }
+}
+
+/// A note on/off received from the host, queued for sample-accurate dispatch.
+#[derive(Clone, Copy)]
+struct MidiNoteEvent {
+    timing: u32,
+    channel: u8,
+    note: u8,
+    velocity: f32,
+    is_on: bool,
+}
+
+/// A SynDRM hit waiting for its micro-timing or ratchet offset to elapse.
+#[derive(Clone, Copy, Default)]
+struct SynDRMPendingHit {
+    active: bool,
+    delay: u32,
+    velocity: f32,
+}
+
+/// Per-step coefficients for a generic SynDRM voice, derived from its parameters.
+#[derive(Clone, Copy, Default)]
+struct SynDRMVoiceShape {
+    freq: f32,
+    sweep: f32,
+    env_coeff: f32,
+    pitch_coeff: f32,
+    burst_coeff: f32,
+    burst_samples: u32,
+    cutoff_hz: f32,
+    q: f32,
+    noise_mix: f32,
+    drive: f32,
+    drive_gain: f32,
+    level: f32,
+}
+
+impl SynDRMVoiceShape {
+    fn new(voice: usize, params: &[f32; SYNDRM_VOICE_PARAMS], sr: f32, muted: bool) -> Self {
+        let tune = params[0].clamp(0.0, 1.0);
+        let decay = params[1].clamp(0.0, 1.0);
+        let tone = params[2].clamp(0.0, 1.0);
+        let drive = params[3].clamp(0.0, 1.0);
+        let level = if muted { 0.0 } else { params[4].clamp(0.0, 1.0) };
+        let tune_ratio = 2.0f32.powf((tune - 0.5) * 2.0);
+        let coeff = |secs: f32| (-1.0 / (secs * sr)).exp();
+        let mut shape = Self {
+            env_coeff: coeff(0.1),
+            pitch_coeff: coeff(0.04),
+            q: 0.7,
+            drive,
+            drive_gain: 1.0 + drive * 8.0,
+            level,
+            ..Self::default()
+        };
+        match voice {
+            SYNDRM_VOICE_CLOSED_HAT | SYNDRM_VOICE_OPEN_HAT => {
+                shape.freq = 205.3 * tune_ratio;
+                shape.noise_mix = 0.25;
+                shape.cutoff_hz = 4_000.0 + tone * 8_000.0;
+                shape.env_coeff = if voice == SYNDRM_VOICE_CLOSED_HAT {
+                    coeff(0.02 + decay * 0.18)
+                } else {
+                    coeff(0.15 + decay * 1.2)
+                };
+            }
+            SYNDRM_VOICE_CLAP => {
+                shape.noise_mix = 1.0;
+                shape.cutoff_hz = 1_000.0 * tune_ratio;
+                shape.q = 0.5 + (1.0 - tone) * 2.5;
+                shape.env_coeff = coeff(0.08 + decay * 0.5);
+                shape.burst_coeff = coeff(0.004);
+                shape.burst_samples = (0.01 * sr).round().max(1.0) as u32;
+            }
+            SYNDRM_VOICE_LOW_TOM | SYNDRM_VOICE_MID_TOM | SYNDRM_VOICE_HIGH_TOM => {
+                let base = match voice {
+                    SYNDRM_VOICE_LOW_TOM => 80.0,
+                    SYNDRM_VOICE_MID_TOM => 120.0,
+                    _ => 180.0,
+                };
+                shape.freq = base * tune_ratio;
+                shape.sweep = shape.freq * 0.6;
+                shape.noise_mix = tone * 0.3;
+                shape.cutoff_hz = 1_500.0 + tone * 6_000.0;
+                shape.q = 0.5;
+                shape.env_coeff = coeff(0.1 + decay * 0.9);
+            }
+            SYNDRM_VOICE_RIMSHOT => {
+                shape.freq = 1_700.0 * tune_ratio;
+                shape.noise_mix = 0.2 + tone * 0.4;
+                shape.cutoff_hz = 2_000.0 + tone * 3_000.0;
+                shape.q = 1.0;
+                shape.env_coeff = coeff(0.01 + decay * 0.05);
+            }
+            SYNDRM_VOICE_COWBELL => {
+                shape.freq = 540.0 * tune_ratio;
+                shape.cutoff_hz = 1_200.0 + tone * 2_400.0;
+                shape.q = 1.0;
+                shape.env_coeff = coeff(0.05 + decay * 0.6);
+            }
+            SYNDRM_VOICE_CYMBAL => {
+                shape.freq = 307.95 * tune_ratio;
+                shape.noise_mix = 0.35;
+                shape.cutoff_hz = 2_500.0 + tone * 6_000.0;
+                shape.env_coeff = coeff(0.4 + decay * 2.6);
+            }
+            _ => {}
+        }
+        shape
+    }
+}
+
+/// FunDSP chain and envelope state for one generic SynDRM voice.
+struct SynDRMVoiceDsp {
+    /// Partial ratios driving `oscs`; empty for the noise-only clap.
+    ratios: &'static [f32],
+    oscs: Vec<Box<dyn AudioUnit>>,
+    noise: Box<dyn AudioUnit>,
+    /// Highpass for metals, bandpass for clap/rimshot/cowbell, lowpass for toms.
+    filter: Box<dyn AudioUnit>,
+    drive: Box<dyn AudioUnit>,
+    env: f32,
+    pitch_env: f32,
+    /// Clap bursts still to fire and samples until the next one.
+    bursts_remaining: u32,
+    burst_countdown: u32,
+    /// Set when the voice is cut by its choke group; releases over `choke_coeff`.
+    choked: bool,
+    choke_coeff: f32,
+    /// Velocity of the last hit; the envelope (and each clap burst) restarts at this level.
+    velocity: f32,
+}
+
+impl SynDRMVoiceDsp {
+    fn new(voice: usize) -> Self {
+        let ratios: &'static [f32] = match voice {
+            SYNDRM_VOICE_CLOSED_HAT | SYNDRM_VOICE_OPEN_HAT | SYNDRM_VOICE_CYMBAL => {
+                &SYNDRM_METAL_RATIOS
+            }
+            SYNDRM_VOICE_COWBELL => &SYNDRM_COWBELL_RATIOS,
+            SYNDRM_VOICE_CLAP => &[],
+            _ => &SYNDRM_SINGLE_RATIO,
+        };
+        let oscs = ratios
+            .iter()
+            .map(|_| -> Box<dyn AudioUnit> {
+                if ratios.len() > 1 {
+                    Box::new(square())
+                } else {
+                    Box::new(sine())
+                }
+            })
+            .collect();
+        let filter: Box<dyn AudioUnit> = match voice {
+            SYNDRM_VOICE_CLOSED_HAT | SYNDRM_VOICE_OPEN_HAT | SYNDRM_VOICE_CYMBAL => {
+                Box::new(highpass())
+            }
+            SYNDRM_VOICE_LOW_TOM | SYNDRM_VOICE_MID_TOM | SYNDRM_VOICE_HIGH_TOM => {
+                Box::new(lowpass())
+            }
+            _ => Box::new(bandpass()),
+        };
+        Self {
+            ratios,
+            oscs,
+            noise: Box::new(noise()),
+            filter,
+            drive: Box::new(shape(Tanh(1.0))),
+            env: 0.0,
+            pitch_env: 0.0,
+            bursts_remaining: 0,
+            burst_countdown: 0,
+            choked: false,
+            choke_coeff: 0.0,
+            velocity: 1.0,
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f32) {
+        let sr = sample_rate as f64;
+        for osc in self.oscs.iter_mut() {
+            osc.set_sample_rate(sr);
+        }
+        self.noise.set_sample_rate(sr);
+        self.filter.set_sample_rate(sr);
+        self.drive.set_sample_rate(sr);
+        self.choke_coeff = (-1.0 / (0.003 * sample_rate.max(1.0))).exp();
+    }
+
+    fn trigger(&mut self, shape: &SynDRMVoiceShape, velocity: f32) {
+        self.velocity = velocity;
+        self.env = velocity;
+        self.pitch_env = 1.0;
+        self.choked = false;
+        if shape.burst_samples > 0 {
+            self.bursts_remaining = SYNDRM_CLAP_BURSTS;
+            self.burst_countdown = shape.burst_samples;
+        } else {
+            self.bursts_remaining = 0;
+        }
+    }
+
+    fn choke(&mut self) {
+        self.choked = true;
+        self.bursts_remaining = 0;
+    }
+
+    fn tick(&mut self, shape: &SynDRMVoiceShape) -> f32 {
+        if self.env < 1.0e-5 && self.bursts_remaining == 0 {
+            self.env = 0.0;
+            return 0.0;
+        }
+        if self.bursts_remaining > 0 {
+            self.burst_countdown = self.burst_countdown.saturating_sub(1);
+            if self.burst_countdown == 0 {
+                self.env = self.velocity;
+                self.bursts_remaining -= 1;
+                self.burst_countdown = shape.burst_samples;
+            }
+        }
+
+        let mut out = [0.0f32];
+        let mut tone = 0.0;
+        if !self.oscs.is_empty() {
+            let freq = shape.freq + self.pitch_env * shape.sweep;
+            for (osc, ratio) in self.oscs.iter_mut().zip(self.ratios.iter()) {
+                osc.tick(&[freq * ratio], &mut out);
+                tone += out[0];
+            }
+            tone /= self.oscs.len() as f32;
+        }
+        let mut noise_out = 0.0;
+        if shape.noise_mix > 0.0 {
+            self.noise.tick(&[], &mut out);
+            noise_out = out[0];
+        }
+        let mut sample = tone * (1.0 - shape.noise_mix) + noise_out * shape.noise_mix;
+        self.filter.tick(&[sample, shape.cutoff_hz, shape.q], &mut out);
+        sample = out[0];
+        if shape.drive > 0.0 {
+            self.drive.tick(&[sample * shape.drive_gain], &mut out);
+            sample = out[0];
+        }
+        sample *= self.env * shape.level;
+
+        let coeff = if self.choked {
+            self.choke_coeff
+        } else if self.bursts_remaining > 0 {
+            shape.burst_coeff
+        } else {
+            shape.env_coeff
+        };
+        self.env *= coeff;
+        self.pitch_env *= shape.pitch_coeff;
+        sample
+    }
 }
 
 struct SynDRMDspState {
@@ -1037,6 +1985,16 @@ struct SynDRMDspState {
     snare_filter_lp: Box<dyn AudioUnit>,
     snare_filter_hp: Box<dyn AudioUnit>,
     snare_filter_bp: Box<dyn AudioUnit>,
+    voices: [SynDRMVoiceDsp; SYNDRM_VOICES],
+    /// Scheduled hits per lane, fired sample-accurately by `process_syndrm`.
+    pending_hits: [[SynDRMPendingHit; SYNDRM_PENDING_HITS]; SYNDRM_LANES],
+    /// Levels the kick and snare attacks rise to, set by the velocity of the hit
+    /// that started them.
+    attack_peak: [f32; 2],
+    /// Whether the transport was running last block, to catch it starting.
+    was_running: bool,
+    /// RNG state for step probability rolls.
+    rng_state: u32,
 }
 
 impl SynDRMDspState {
@@ -1056,6 +2014,11 @@ impl SynDRMDspState {
             snare_filter_lp: Box::new(lowpass()),
             snare_filter_hp: Box::new(highpass()),
             snare_filter_bp: Box::new(bandpass()),
+            voices: std::array::from_fn(SynDRMVoiceDsp::new),
+            pending_hits: [[SynDRMPendingHit::default(); SYNDRM_PENDING_HITS]; SYNDRM_LANES],
+            attack_peak: [1.0; 2],
+            was_running: false,
+            rng_state: 0x2f6b_9d41,
         }
     }
 
@@ -1078,141 +2041,1114 @@ impl SynDRMDspState {
         self.snare_filter_lp.set_sample_rate(sr);
         self.snare_filter_hp.set_sample_rate(sr);
         self.snare_filter_bp.set_sample_rate(sr);
+        for voice in self.voices.iter_mut() {
+            voice.set_sample_rate(sample_rate);
+        }
     }
-}
-
-struct AnimateLibrary {
-    wavetable_paths: Vec<PathBuf>,
-    sample_paths: Vec<PathBuf>,
-    wavetables: Mutex<Vec<Option<Arc<Vec<f32>>>>>,
-    samples: Mutex<Vec<Option<Arc<Vec<Vec<f32>>>>>>,
-}
-
-#[derive(Params)]
-pub struct TLBX1Params {
-    #[id = "selected_track"]
-    pub selected_track: IntParam,
-
-    #[id = "gain"]
-    pub gain: FloatParam,
-
-    #[id = "master_filter"]
-    pub master_filter: FloatParam,
-
-    #[id = "master_comp"]
-    pub master_comp: FloatParam,
-}
 
-impl AnimateLibrary {
-    fn load() -> Self {
-        fn scan_dir(dir: &Path, paths: &mut Vec<PathBuf>) {
-            if let Ok(entries) = std::fs::read_dir(dir) {
-                let mut sorted_entries: Vec<_> = entries.flatten().collect();
-                sorted_entries.sort_by_key(|e| e.file_name());
-
-                for entry in sorted_entries {
-                    let path = entry.path();
-                    if path.is_dir() {
-                        scan_dir(&path, paths);
-                    } else if path.extension().map_or(false, |ext| ext == "wav" || ext == "mp3") {
-                        paths.push(path);
-                    }
+    /// Schedules the hits that land inside the step starting now: the current step's
+    /// on-time or late hits, and the next step's early (negative micro-timing) hits.
+    ///
+    /// `elapsed` is set when the transport starts `elapsed` samples into the step. The
+    /// current step's early hits then have no previous step to come from, so they play
+    /// at once.
+    fn schedule_step_hits(
+        &mut self,
+        track: &Track,
+        step_idx: usize,
+        next_idx: usize,
+        step_len: f32,
+        next_len: f32,
+        elapsed: Option<f32>,
+    ) {
+        for lane in 0..SYNDRM_LANES {
+            for (step, is_next) in [(step_idx, false), (next_idx, true)] {
+                if step >= SYNDRM_STEPS || !syndrm_lane_step_active(track, lane, step) {
+                    continue;
+                }
+                let micro = f32::from_bits(track.syndrm_step_micro[lane][step].load(Ordering::Relaxed))
+                    .clamp(-0.5, 0.5);
+                let early = micro < 0.0;
+                if is_next != early && !(elapsed.is_some() && early && !is_next) {
+                    continue;
+                }
+                let probability =
+                    f32::from_bits(track.syndrm_step_probability[lane][step].load(Ordering::Relaxed))
+                        .clamp(0.0, 1.0);
+                if probability < 1.0 && next_mosaic_rand_unit(&mut self.rng_state) >= probability {
+                    continue;
+                }
+                let velocity =
+                    f32::from_bits(track.syndrm_step_velocity[lane][step].load(Ordering::Relaxed))
+                        .clamp(0.0, 1.0);
+                let ratchet = track.syndrm_step_ratchet[lane][step]
+                    .load(Ordering::Relaxed)
+                    .clamp(1, SYNDRM_MAX_RATCHET);
+                // Swing gives neighbouring steps different lengths.
+                let (offset, len) = if is_next {
+                    (step_len, next_len)
+                } else {
+                    (0.0, step_len)
+                };
+                let start = offset + micro * len - elapsed.unwrap_or(0.0);
+                let spacing = len / ratchet as f32;
+                for hit in 0..ratchet {
+                    let delay = (start + hit as f32 * spacing).round().max(0.0) as u32;
+                    // Never expected to be full; if it is, the hit due soonest makes way.
+                    let slots = &mut self.pending_hits[lane];
+                    let slot = match slots.iter().position(|slot| !slot.active) {
+                        Some(free) => free,
+                        None => (0..slots.len()).min_by_key(|&i| slots[i].delay).unwrap_or(0),
+                    };
+                    slots[slot] = SynDRMPendingHit {
+                        active: true,
+                        delay,
+                        velocity,
+                    };
                 }
             }
         }
+    }
 
-        let mut wavetable_paths = Vec::new();
-        let mut sample_paths = Vec::new();
+    /// Advances pending hits by one sample, returning the velocity of any lane due now.
+    fn take_due_hits(&mut self) -> [Option<f32>; SYNDRM_LANES] {
+        let mut due = [None; SYNDRM_LANES];
+        for (lane, hits) in self.pending_hits.iter_mut().enumerate() {
+            for hit in hits.iter_mut().filter(|hit| hit.active) {
+                if hit.delay == 0 {
+                    hit.active = false;
+                    due[lane] = Some(hit.velocity);
+                } else {
+                    hit.delay -= 1;
+                }
+            }
+        }
+        due
+    }
 
-        scan_dir(Path::new("src/library/factory/wavetables"), &mut wavetable_paths);
-        scan_dir(Path::new("src/library/factory/samples"), &mut sample_paths);
+    fn clear_pending_hits(&mut self) {
+        for hits in self.pending_hits.iter_mut() {
+            for hit in hits.iter_mut() {
+                hit.active = false;
+            }
+        }
+    }
+}
 
-        let wavetables = vec![None; wavetable_paths.len()];
-        let samples = vec![None; sample_paths.len()];
+/// One band-limited copy of an Animate wavetable: every frame resynthesized with
+/// harmonics up to `harmonics`, `len` samples per frame.
+struct WavetableLevel {
+    len: usize,
+    harmonics: usize,
+    data: Vec<f32>,
+}
 
-        Self {
-            wavetable_paths,
-            sample_paths,
-            wavetables: Mutex::new(wavetables),
-            samples: Mutex::new(samples),
-        }
+impl WavetableLevel {
+    fn read(&self, frame: usize, phase: f32) -> f32 {
+        let pos = phase * self.len as f32;
+        let idx = (pos as usize).min(self.len - 1);
+        let frac = pos - idx as f32;
+        let base = frame * self.len;
+        let s1 = self.data[base + idx];
+        let s2 = self.data[base + (idx + 1) % self.len];
+        s1 + (s2 - s1) * frac
     }
+}
 
-    fn ensure_wavetable_loaded(&self, idx: usize) -> Option<Arc<Vec<f32>>> {
-        if idx >= self.wavetable_paths.len() {
+/// An Animate wavetable: `frames` single-cycle frames, stored as mip levels that halve
+/// the harmonic count each step. Level 0 is the file as loaded.
+struct Wavetable {
+    frames: usize,
+    levels: Vec<WavetableLevel>,
+}
+
+impl Wavetable {
+    /// Splits a decoded table into frames and precomputes its band-limited levels.
+    /// Without a frame length from the file, a table that divides evenly into
+    /// `ANIMATE_WAVETABLE_FRAME` frames is read that way and anything else is treated as
+    /// one single-cycle frame.
+    fn new(mut data: Vec<f32>, frame_len: Option<usize>) -> Option<Self> {
+        if data.is_empty() {
             return None;
         }
-        if let Some(cache) = self.wavetables.try_lock() {
-            if let Some(existing) = cache.get(idx).and_then(|entry| entry.clone()) {
-                return Some(existing);
+        let frame_len = match frame_len.filter(|len| *len > 1 && *len <= data.len()) {
+            Some(len) => len,
+            None if data.len() % ANIMATE_WAVETABLE_FRAME == 0 => ANIMATE_WAVETABLE_FRAME,
+            None => data.len(),
+        };
+        let frames = data.len() / frame_len;
+        data.truncate(frames * frame_len);
+        let mut levels = vec![WavetableLevel {
+            len: frame_len,
+            harmonics: (frame_len / 2).max(1),
+            data,
+        }];
+        wavetable_build_mips(&mut levels, frames);
+        Some(Self { frames, levels })
+    }
+
+    /// Reads the table at `phase` (0..1) for a note at `freq` Hz, morphing between the
+    /// two frames around `position` (0..1). The two mip levels whose harmonics all stay
+    /// below Nyquist are crossfaded by pitch, so sweeps don't step between levels.
+    fn read(&self, position: f32, phase: f32, freq: f32, sr: f32) -> f32 {
+        let frame_pos = position.clamp(0.0, 1.0) * (self.frames - 1) as f32;
+        let frame = (frame_pos as usize).min(self.frames - 1);
+        let next = (frame + 1).min(self.frames - 1);
+        let blend = frame_pos - frame as f32;
+        let phase = phase.clamp(0.0, 1.0);
+
+        // Octaves the top harmonic sits above Nyquist; level `lo` and above are clean.
+        let max_harmonics = (0.5 * sr / freq.max(1.0)).max(1.0);
+        let octaves = (self.levels[0].harmonics as f32 / max_harmonics).log2();
+        let last = self.levels.len() - 1;
+        let lo = (octaves.ceil().max(0.0) as usize).min(last);
+        let hi = (lo + 1).min(last);
+        let mix = (octaves - lo as f32 + 1.0).clamp(0.0, 1.0);
+
+        let read_level = |level: &WavetableLevel| {
+            let a = level.read(frame, phase);
+            if blend > 0.0 && next != frame {
+                a + (level.read(next, phase) - a) * blend
+            } else {
+                a
             }
+        };
+        let a = read_level(&self.levels[lo]);
+        if mix > 0.0 && hi != lo {
+            a + (read_level(&self.levels[hi]) - a) * mix
+        } else {
+            a
         }
-        let path = self.wavetable_paths.get(idx)?.clone();
-        let data = load_audio_file(&path).ok();
-        let wavetable = data.and_then(|(data, _)| data.get(0).cloned());
-        if let Some(wt) = wavetable {
-            let arc = Arc::new(wt);
-            if let Some(mut cache) = self.wavetables.try_lock() {
-                if let Some(entry) = cache.get_mut(idx) {
-                    *entry = Some(Arc::clone(&arc));
-                }
+    }
+}
+
+/// Shortest frame kept for the upper mip levels, so linear interpolation stays clean
+/// for the few harmonics left.
+const ANIMATE_WAVETABLE_MIP_MIN_LEN: usize = 64;
+
+/// Adds band-limited levels after `levels[0]` until one harmonic is left. Each frame is
+/// resampled to a power of two and transformed once; every level is then resynthesized
+/// from its lowest bins at four samples per cycle of its top harmonic.
+fn wavetable_build_mips(levels: &mut Vec<WavetableLevel>, frames: usize) {
+    let frame_len = levels[0].len;
+    let size = frame_len.next_power_of_two().max(2);
+    let mut harmonics = levels[0].harmonics.min(size / 2);
+    let mut plan = Vec::new();
+    while harmonics > 1 {
+        harmonics /= 2;
+        let len = (4 * harmonics)
+            .next_power_of_two()
+            .max(ANIMATE_WAVETABLE_MIP_MIN_LEN);
+        plan.push((len, harmonics));
+    }
+    let mut mips: Vec<WavetableLevel> = plan
+        .iter()
+        .map(|&(len, harmonics)| WavetableLevel {
+            len,
+            harmonics,
+            data: Vec::with_capacity(len * frames),
+        })
+        .collect();
+
+    let mut spectrum_re = vec![0.0f32; size];
+    let mut spectrum_im = vec![0.0f32; size];
+    for frame in 0..frames {
+        let source = &levels[0].data[frame * frame_len..(frame + 1) * frame_len];
+        for (i, (re, im)) in spectrum_re.iter_mut().zip(spectrum_im.iter_mut()).enumerate() {
+            let pos = i as f32 * frame_len as f32 / size as f32;
+            let idx = pos as usize;
+            let frac = pos - idx as f32;
+            let s1 = source[idx % frame_len];
+            let s2 = source[(idx + 1) % frame_len];
+            *re = s1 + (s2 - s1) * frac;
+            *im = 0.0;
+        }
+        fft_in_place(&mut spectrum_re, &mut spectrum_im, false);
+        for mip in &mut mips {
+            let scale = 1.0 / size as f32;
+            let mut re = vec![0.0f32; mip.len];
+            let mut im = vec![0.0f32; mip.len];
+            re[0] = spectrum_re[0] * scale;
+            for h in 1..=mip.harmonics {
+                re[h] = spectrum_re[h] * scale;
+                im[h] = spectrum_im[h] * scale;
+                re[mip.len - h] = re[h];
+                im[mip.len - h] = -im[h];
             }
-            return Some(arc);
+            fft_in_place(&mut re, &mut im, true);
+            mip.data.extend_from_slice(&re);
         }
-        None
     }
+    levels.extend(mips);
+}
 
-    fn ensure_sample_loaded(&self, idx: usize) -> Option<Arc<Vec<Vec<f32>>>> {
-        if idx >= self.sample_paths.len() {
-            return None;
-        }
-        if let Some(cache) = self.samples.try_lock() {
-            if let Some(existing) = cache.get(idx).and_then(|entry| entry.clone()) {
-                return Some(existing);
-            }
+/// In-place iterative radix-2 FFT; `re.len()` must be a power of two. The inverse is
+/// unscaled, so a forward/inverse pair multiplies by the length.
+fn fft_in_place(re: &mut [f32], im: &mut [f32], inverse: bool) {
+    let n = re.len();
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
         }
-        let path = self.sample_paths.get(idx)?.clone();
-        let data = load_audio_file(&path).ok();
-        if let Some((data, _)) = data {
-            let arc = Arc::new(data);
-            if let Some(mut cache) = self.samples.try_lock() {
-                if let Some(entry) = cache.get_mut(idx) {
-                    *entry = Some(Arc::clone(&arc));
-                }
+    }
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * std::f64::consts::PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (w_im, w_re) = (angle * k as f64).sin_cos();
+                let (w_re, w_im) = (w_re as f32, w_im as f32);
+                let a = start + k;
+                let b = a + len / 2;
+                let t_re = re[b] * w_re - im[b] * w_im;
+                let t_im = re[b] * w_im + im[b] * w_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
             }
-            return Some(arc);
         }
-        None
+        len <<= 1;
     }
+}
 
-    fn get_wavetable_cached(&self, idx: usize) -> Option<Arc<Vec<f32>>> {
-        if idx >= self.wavetable_paths.len() {
-            return None;
-        }
-        self.wavetables
-            .try_lock()
-            .and_then(|cache| cache.get(idx).and_then(|entry| entry.clone()))
+/// Body of the first chunk tagged `tag` in a RIFF/WAVE file.
+fn wav_chunk<'a>(bytes: &'a [u8], tag: &[u8; 4]) -> Option<&'a [u8]> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
     }
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let size = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().ok()?) as usize;
+        let body = pos + 8;
+        if id == tag {
+            return bytes.get(body..(body + size).min(bytes.len()));
+        }
+        pos = body + size + (size & 1);
+    }
+    None
+}
 
-    fn get_sample_cached(&self, idx: usize) -> Option<Arc<Vec<Vec<f32>>>> {
-        if idx >= self.sample_paths.len() {
-            return None;
-        }
-        self.samples
-            .try_lock()
-            .and_then(|cache| cache.get(idx).and_then(|entry| entry.clone()))
+/// Frame length from a Serum-style `clm ` chunk (`<!>2048 ...`) in a WAV file.
+fn wavetable_clm_frame_len(path: &Path) -> Option<usize> {
+    let bytes = std::fs::read(path).ok()?;
+    let digits: String = wav_chunk(&bytes, b"clm ")?
+        .strip_prefix(b"<!>")?
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .map(|b| char::from(*b))
+        .collect();
+    digits.parse().ok()
+}
+
+/// Root key from a WAV `smpl` chunk: the MIDI unity note plus its pitch fraction.
+fn wav_smpl_root(path: &Path) -> Option<f32> {
+    let bytes = std::fs::read(path).ok()?;
+    let chunk = wav_chunk(&bytes, b"smpl")?;
+    let note = u32::from_le_bytes(chunk.get(12..16)?.try_into().ok()?);
+    let fraction = u32::from_le_bytes(chunk.get(16..20)?.try_into().ok()?);
+    (note < 128).then(|| note as f32 + fraction as f32 / 4_294_967_296.0)
+}
+
+/// MIDI note for a file name token such as `E3`, `Asharp3`, `F#2` or `Bb1` (C4 = 60).
+fn sample_name_note(token: &str) -> Option<f32> {
+    let mut chars = token.chars();
+    let base: i32 = match chars.next()? {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let rest = chars.as_str();
+    let (accidental, octave) = if let Some(octave) = rest.strip_prefix("sharp") {
+        (1, octave)
+    } else if let Some(octave) = rest.strip_prefix('#') {
+        (1, octave)
+    } else if let Some(octave) = rest.strip_prefix("flat") {
+        (-1, octave)
+    } else if let Some(octave) = rest.strip_prefix('b') {
+        (-1, octave)
+    } else {
+        (0, rest)
+    };
+    if octave.is_empty() || !octave.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
     }
+    let octave: i32 = octave.parse().ok()?;
+    let note = (octave + 1) * 12 + base + accidental;
+    (0..128).contains(&note).then_some(note as f32)
 }
 
-impl Default for TLBX1 {
-    fn default() -> Self {
-        let tracks = [
-            Track::default(),
-            Track::default(),
-            Track::default(),
-            Track::default(),
-        ];
-        
+/// Whether two file name tokens are numbered variants of one name, such as `Chroma1`
+/// and `ChromaST6`: letters then a number, where one token's letters start the other's.
+fn sample_name_variants(a: &str, b: &str) -> bool {
+    let letters = |token: &str| {
+        let count = token.bytes().take_while(|b| b.is_ascii_alphabetic()).count();
+        let number = &token[count..];
+        (count > 0 && !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| token[..count].to_ascii_lowercase())
+    };
+    match (letters(a), letters(b)) {
+        (Some(a), Some(b)) => a.starts_with(&b) || b.starts_with(&a),
+        _ => false,
+    }
+}
+
+/// Velocity layer for a file name token such as `v2` or `vel3`.
+fn sample_name_layer(token: &str) -> Option<u32> {
+    let lower = token.to_ascii_lowercase();
+    let digits = lower.strip_prefix("vel").or_else(|| lower.strip_prefix('v'))?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// A library file for the Animate loader thread to decode, by index.
+#[derive(Clone, Copy)]
+enum AnimateLoadRequest {
+    Wavetable(usize),
+    Sample(usize),
+}
+
+/// A decoded Animate library sample.
+struct AnimateSample {
+    channels: Vec<Vec<f32>>,
+    sample_rate: f32,
+    /// MIDI note the sample plays back at its original pitch, if the file says.
+    root: Option<f32>,
+}
+
+/// Where a library sample sits in its multisample, parsed from the file name.
+struct AnimateSampleZone {
+    /// Index into `AnimateLibrary::sample_groups`.
+    group: usize,
+    /// Root key from a note token in the name (`_E3`, `-Asharp3`), if any.
+    name_root: Option<f32>,
+    /// Velocity layer from a `v2`/`vel2` token, 0 when there is none.
+    layer: u32,
+}
+
+/// Samples in one folder whose names match once the note and velocity tokens are removed.
+struct AnimateSampleGroup {
+    members: Vec<usize>,
+    /// Distinct velocity layers, lowest first.
+    layers: Vec<u32>,
+}
+
+struct AnimateLibrary {
+    wavetable_paths: Vec<PathBuf>,
+    /// Display name of each wavetable, relative to the folder it was found in.
+    wavetable_names: Vec<String>,
+    sample_paths: Vec<PathBuf>,
+    /// Display name of each sample, relative to the folder it was found in.
+    sample_names: Vec<String>,
+    sample_zones: Vec<AnimateSampleZone>,
+    sample_groups: Vec<AnimateSampleGroup>,
+    /// Decoded files, written once by the loader and read lock-free by the audio thread.
+    wavetables: Vec<OnceLock<Wavetable>>,
+    samples: Vec<OnceLock<AnimateSample>>,
+    /// Queue into the loader thread; `None` until `start_loader` runs, in which
+    /// case requests are decoded on the calling thread.
+    loader_tx: Mutex<Option<mpsc::Sender<AnimateLoadRequest>>>,
+    /// Requests queued but not finished yet; the UI shows a loading indicator while non-zero.
+    pending_loads: AtomicUsize,
+}
+
+/// Declares the per-track parameters exposed to the host. Every entry mirrors
+/// one `Track` atomic under a stable ID; the nested array in `TLBX1Params`
+/// suffixes each ID with the track number (`mosaic_wet_1` .. `mosaic_wet_4`).
+macro_rules! track_host_params {
+    (
+        floats {
+            $( $fname:ident: $fid:literal, $flabel:literal, $fdefault:expr, $fmin:expr, $fmax:expr => { $($fpath:tt)+ }; )*
+        }
+        ints {
+            $( $iname:ident: $iid:literal, $ilabel:literal, $idefault:expr, $imin:expr, $imax:expr => { $($ipath:tt)+ }; )*
+        }
+        bools {
+            $( $bname:ident: $bid:literal, $blabel:literal, $bdefault:expr => { $($bpath:tt)+ }; )*
+        }
+    ) => {
+        #[derive(Params)]
+        pub struct TrackParams {
+            $( #[id = $fid] pub $fname: FloatParam, )*
+            $( #[id = $iid] pub $iname: IntParam, )*
+            $( #[id = $bid] pub $bname: BoolParam, )*
+            /// Last parameter value the audio thread copied into the track.
+            host_seen: Vec<AtomicU32>,
+            /// Last track value the editor published to the host.
+            track_seen: Vec<AtomicU32>,
+        }
+
+        impl TrackParams {
+            fn new(track_idx: usize) -> Self {
+                let prefix = format!("T{}", track_idx + 1);
+                let mut defaults: Vec<u32> = Vec::new();
+                $( defaults.push(($fdefault as f32).to_bits()); )*
+                $( defaults.push($idefault as u32); )*
+                $( defaults.push(u32::from($bdefault)); )*
+                Self {
+                    $(
+                        $fname: FloatParam::new(
+                            format!("{} {}", prefix, $flabel),
+                            $fdefault,
+                            FloatRange::Linear { min: $fmin, max: $fmax },
+                        ),
+                    )*
+                    $(
+                        $iname: IntParam::new(
+                            format!("{} {}", prefix, $ilabel),
+                            $idefault,
+                            IntRange::Linear { min: $imin, max: $imax },
+                        ),
+                    )*
+                    $( $bname: BoolParam::new(format!("{} {}", prefix, $blabel), $bdefault), )*
+                    host_seen: defaults.iter().map(|bits| AtomicU32::new(*bits)).collect(),
+                    track_seen: defaults.iter().map(|bits| AtomicU32::new(*bits)).collect(),
+                }
+            }
+
+            /// Copies host-side changes (automation, restored state) into the
+            /// track. Called from the audio thread once per block.
+            fn apply_host_changes(&self, track: &Track) {
+                let mut slots = self.host_seen.iter().zip(self.track_seen.iter());
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let bits = self.$fname.value().to_bits();
+                        if bits != host_seen.load(Ordering::Relaxed) {
+                            host_seen.store(bits, Ordering::Relaxed);
+                            track.$($fpath)+.store(bits, Ordering::Relaxed);
+                            track_seen.store(bits, Ordering::Relaxed);
+                        }
+                    }
+                )*
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let value = self.$iname.value() as u32;
+                        if value != host_seen.load(Ordering::Relaxed) {
+                            host_seen.store(value, Ordering::Relaxed);
+                            track.$($ipath)+.store(value, Ordering::Relaxed);
+                            track_seen.store(value, Ordering::Relaxed);
+                        }
+                    }
+                )*
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let value = self.$bname.value();
+                        if u32::from(value) != host_seen.load(Ordering::Relaxed) {
+                            host_seen.store(u32::from(value), Ordering::Relaxed);
+                            track.$($bpath)+.store(value, Ordering::Relaxed);
+                            track_seen.store(u32::from(value), Ordering::Relaxed);
+                        }
+                    }
+                )*
+            }
+
+            /// Publishes track changes made outside the host (UI, MIDI learn,
+            /// project loads) so they show up on the host's parameters. The
+            /// published value is marked as seen so the host's echo of it
+            /// cannot overwrite a newer track value.
+            fn publish_track_changes(&self, track: &Track, setter: &ParamSetter) {
+                let mut slots = self.host_seen.iter().zip(self.track_seen.iter());
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let bits = track.$($fpath)+.load(Ordering::Relaxed);
+                        if bits != track_seen.load(Ordering::Relaxed) {
+                            track_seen.store(bits, Ordering::Relaxed);
+                            let value = f32::from_bits(bits);
+                            // The host holds the value as normalized, so expect it back that way.
+                            let echoed = self
+                                .$fname
+                                .preview_plain(self.$fname.preview_normalized(value));
+                            host_seen.store(echoed.to_bits(), Ordering::Relaxed);
+                            setter.begin_set_parameter(&self.$fname);
+                            setter.set_parameter(&self.$fname, value);
+                            setter.end_set_parameter(&self.$fname);
+                        }
+                    }
+                )*
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let value = track.$($ipath)+.load(Ordering::Relaxed);
+                        if value != track_seen.load(Ordering::Relaxed) {
+                            track_seen.store(value, Ordering::Relaxed);
+                            host_seen.store(value, Ordering::Relaxed);
+                            setter.begin_set_parameter(&self.$iname);
+                            setter.set_parameter(&self.$iname, value as i32);
+                            setter.end_set_parameter(&self.$iname);
+                        }
+                    }
+                )*
+                $(
+                    if let Some((host_seen, track_seen)) = slots.next() {
+                        let value = track.$($bpath)+.load(Ordering::Relaxed);
+                        if u32::from(value) != track_seen.load(Ordering::Relaxed) {
+                            track_seen.store(u32::from(value), Ordering::Relaxed);
+                            host_seen.store(u32::from(value), Ordering::Relaxed);
+                            setter.begin_set_parameter(&self.$bname);
+                            setter.set_parameter(&self.$bname, value);
+                            setter.end_set_parameter(&self.$bname);
+                        }
+                    }
+                )*
+            }
+        }
+    };
+}
+
+track_host_params! {
+    floats {
+        level: "level", "Level", 1.0, 0.0, 1.0 => { level };
+        swing: "swing", "Swing", 50.0, 50.0, 75.0 => { swing };
+        tape_speed: "tape_speed", "Tape Speed", 1.0, -4.0, 4.0 => { tape_speed };
+        tape_rotate: "tape_rotate", "Tape Rotate", 0.0, 0.0, 1.0 => { tape_rotate };
+        tape_glide: "tape_glide", "Tape Glide", 0.0, 0.0, 1.0 => { tape_glide };
+        tape_pitch: "tape_pitch", "Tape Pitch", 0.0, -24.0, 24.0 => { tape_pitch };
+        tape_fine: "tape_fine", "Tape Fine", 0.0, -100.0, 100.0 => { tape_fine };
+        tape_sos: "tape_sos", "Tape SOS", 0.0, 0.0, 1.0 => { tape_sos };
+        loop_start: "loop_start", "Loop Start", 0.0, 0.0, 1.0 => { loop_start };
+        loop_length: "loop_length", "Loop Length", 1.0, 0.01, 1.0 => { loop_length };
+        loop_xfade: "loop_xfade", "Loop X-Fade", 0.0, 0.0, 0.5 => { loop_xfade };
+        mosaic_pitch: "mosaic_pitch", "Mosaic Pitch", 0.0, 0.0, 1.0 => { mosaic_pitch };
+        mosaic_rate: "mosaic_rate", "Mosaic Rate", 0.5, 0.0, 1.0 => { mosaic_rate };
+        mosaic_size: "mosaic_size", "Mosaic Size", 0.5, 0.0, 1.0 => { mosaic_size };
+        mosaic_contour: "mosaic_contour", "Mosaic Contour", 0.5, 0.0, 1.0 => { mosaic_contour };
+        mosaic_warp: "mosaic_warp", "Mosaic Warp", 0.0, 0.0, 1.0 => { mosaic_warp };
+        mosaic_spray: "mosaic_spray", "Mosaic Spray", 0.0, 0.0, 1.0 => { mosaic_spray };
+        mosaic_pattern: "mosaic_pattern", "Mosaic Pattern", 0.0, 0.0, 1.0 => { mosaic_pattern };
+        mosaic_wet: "mosaic_wet", "Mosaic Wet", 0.0, 0.0, 1.0 => { mosaic_wet };
+        mosaic_spatial: "mosaic_spatial", "Mosaic Spatial", 0.0, 0.0, 1.0 => { mosaic_spatial };
+        mosaic_detune: "mosaic_detune", "Mosaic Detune", 0.0, 0.0, 1.0 => { mosaic_detune };
+        mosaic_rand_rate: "mosaic_rand_rate", "Mosaic Rand Rate", 0.0, 0.0, 1.0 => { mosaic_rand_rate };
+        mosaic_rand_size: "mosaic_rand_size", "Mosaic Rand Size", 0.0, 0.0, 1.0 => { mosaic_rand_size };
+        mosaic_sos: "mosaic_sos", "Mosaic SOS", 0.0, 0.0, 1.0 => { mosaic_sos };
+        ring_cutoff: "ring_cutoff", "Ring Cutoff", 0.5, 0.0, 1.0 => { ring_cutoff };
+        ring_resonance: "ring_resonance", "Ring Resonance", 0.0, 0.0, 1.0 => { ring_resonance };
+        ring_decay: "ring_decay", "Ring Decay", 0.0, 0.0, 1.0 => { ring_decay };
+        ring_pitch: "ring_pitch", "Ring Pitch", 0.5, 0.0, 1.0 => { ring_pitch };
+        ring_tone: "ring_tone", "Ring Tone", 0.5, 0.0, 1.0 => { ring_tone };
+        ring_tilt: "ring_tilt", "Ring Tilt", 0.5, 0.0, 1.0 => { ring_tilt };
+        ring_slope: "ring_slope", "Ring Slope", 0.5, 0.0, 1.0 => { ring_slope };
+        ring_wet: "ring_wet", "Ring Wet", 0.0, 0.0, 1.0 => { ring_wet };
+        ring_detune: "ring_detune", "Ring Detune", 0.0, 0.0, 1.0 => { ring_detune };
+        ring_waves: "ring_waves", "Ring Waves", 0.0, 0.0, 1.0 => { ring_waves };
+        ring_waves_rate: "ring_waves_rate", "Ring Waves Rate", 0.5, 0.0, 1.0 => { ring_waves_rate };
+        ring_noise: "ring_noise", "Ring Noise", 0.0, 0.0, 1.0 => { ring_noise };
+        ring_noise_rate: "ring_noise_rate", "Ring Noise Rate", 0.5, 0.0, 1.0 => { ring_noise_rate };
+        animate_vector_x: "animate_vector_x", "Vector X", 0.5, 0.0, 1.0 => { animate_vector_x };
+        animate_vector_y: "animate_vector_y", "Vector Y", 0.5, 0.0, 1.0 => { animate_vector_y };
+        animate_lfo_x_rate: "animate_lfo_x_rate", "Vector LFO X Rate", 0.5, 0.0, 1.0 => { animate_lfo_x_rate };
+        animate_lfo_x_amount: "animate_lfo_x_amount", "Vector LFO X Amount", 0.0, 0.0, 1.0 => { animate_lfo_x_amount };
+        animate_lfo_y_rate: "animate_lfo_y_rate", "Vector LFO Y Rate", 0.5, 0.0, 1.0 => { animate_lfo_y_rate };
+        animate_lfo_y_amount: "animate_lfo_y_amount", "Vector LFO Y Amount", 0.0, 0.0, 1.0 => { animate_lfo_y_amount };
+        slot_a_level: "animate_slot_a_level", "Slot A Level", 1.0, 0.0, 1.0 => { animate_slot_level[0] };
+        slot_b_level: "animate_slot_b_level", "Slot B Level", 1.0, 0.0, 1.0 => { animate_slot_level[1] };
+        slot_c_level: "animate_slot_c_level", "Slot C Level", 1.0, 0.0, 1.0 => { animate_slot_level[2] };
+        slot_d_level: "animate_slot_d_level", "Slot D Level", 1.0, 0.0, 1.0 => { animate_slot_level[3] };
+        slot_a_coarse: "animate_slot_a_coarse", "Slot A Coarse", 0.0, -24.0, 24.0 => { animate_slot_coarse[0] };
+        slot_b_coarse: "animate_slot_b_coarse", "Slot B Coarse", 0.0, -24.0, 24.0 => { animate_slot_coarse[1] };
+        slot_c_coarse: "animate_slot_c_coarse", "Slot C Coarse", 0.0, -24.0, 24.0 => { animate_slot_coarse[2] };
+        slot_d_coarse: "animate_slot_d_coarse", "Slot D Coarse", 0.0, -24.0, 24.0 => { animate_slot_coarse[3] };
+        slot_a_fine: "animate_slot_a_fine", "Slot A Fine", 0.0, -100.0, 100.0 => { animate_slot_fine[0] };
+        slot_b_fine: "animate_slot_b_fine", "Slot B Fine", 0.0, -100.0, 100.0 => { animate_slot_fine[1] };
+        slot_c_fine: "animate_slot_c_fine", "Slot C Fine", 0.0, -100.0, 100.0 => { animate_slot_fine[2] };
+        slot_d_fine: "animate_slot_d_fine", "Slot D Fine", 0.0, -100.0, 100.0 => { animate_slot_fine[3] };
+        slot_a_pan: "animate_slot_a_pan", "Slot A Pan", 0.0, -1.0, 1.0 => { animate_slot_pan[0] };
+        slot_b_pan: "animate_slot_b_pan", "Slot B Pan", 0.0, -1.0, 1.0 => { animate_slot_pan[1] };
+        slot_c_pan: "animate_slot_c_pan", "Slot C Pan", 0.0, -1.0, 1.0 => { animate_slot_pan[2] };
+        slot_d_pan: "animate_slot_d_pan", "Slot D Pan", 0.0, -1.0, 1.0 => { animate_slot_pan[3] };
+        slot_a_wt_position: "animate_slot_a_wt_position", "Slot A WT Position", 0.0, 0.0, 1.0 => { animate_slot_wt_position[0] };
+        slot_b_wt_position: "animate_slot_b_wt_position", "Slot B WT Position", 0.0, 0.0, 1.0 => { animate_slot_wt_position[1] };
+        slot_c_wt_position: "animate_slot_c_wt_position", "Slot C WT Position", 0.0, 0.0, 1.0 => { animate_slot_wt_position[2] };
+        slot_d_wt_position: "animate_slot_d_wt_position", "Slot D WT Position", 0.0, 0.0, 1.0 => { animate_slot_wt_position[3] };
+        slot_a_cutoff: "animate_slot_a_cutoff", "Slot A Cutoff", 0.5, 0.0, 1.0 => { animate_slot_filter_cutoff[0] };
+        slot_b_cutoff: "animate_slot_b_cutoff", "Slot B Cutoff", 0.5, 0.0, 1.0 => { animate_slot_filter_cutoff[1] };
+        slot_c_cutoff: "animate_slot_c_cutoff", "Slot C Cutoff", 0.5, 0.0, 1.0 => { animate_slot_filter_cutoff[2] };
+        slot_d_cutoff: "animate_slot_d_cutoff", "Slot D Cutoff", 0.5, 0.0, 1.0 => { animate_slot_filter_cutoff[3] };
+        slot_a_resonance: "animate_slot_a_resonance", "Slot A Resonance", 0.0, 0.0, 1.0 => { animate_slot_filter_resonance[0] };
+        slot_b_resonance: "animate_slot_b_resonance", "Slot B Resonance", 0.0, 0.0, 1.0 => { animate_slot_filter_resonance[1] };
+        slot_c_resonance: "animate_slot_c_resonance", "Slot C Resonance", 0.0, 0.0, 1.0 => { animate_slot_filter_resonance[2] };
+        slot_d_resonance: "animate_slot_d_resonance", "Slot D Resonance", 0.0, 0.0, 1.0 => { animate_slot_filter_resonance[3] };
+        slot_a_amp_attack: "animate_slot_a_amp_attack", "Slot A Amp Attack", 0.01, 0.0, 10.0 => { animate_slot_amp_attack[0] };
+        slot_b_amp_attack: "animate_slot_b_amp_attack", "Slot B Amp Attack", 0.01, 0.0, 10.0 => { animate_slot_amp_attack[1] };
+        slot_c_amp_attack: "animate_slot_c_amp_attack", "Slot C Amp Attack", 0.01, 0.0, 10.0 => { animate_slot_amp_attack[2] };
+        slot_d_amp_attack: "animate_slot_d_amp_attack", "Slot D Amp Attack", 0.01, 0.0, 10.0 => { animate_slot_amp_attack[3] };
+        slot_a_amp_decay: "animate_slot_a_amp_decay", "Slot A Amp Decay", 0.1, 0.0, 10.0 => { animate_slot_amp_decay[0] };
+        slot_b_amp_decay: "animate_slot_b_amp_decay", "Slot B Amp Decay", 0.1, 0.0, 10.0 => { animate_slot_amp_decay[1] };
+        slot_c_amp_decay: "animate_slot_c_amp_decay", "Slot C Amp Decay", 0.1, 0.0, 10.0 => { animate_slot_amp_decay[2] };
+        slot_d_amp_decay: "animate_slot_d_amp_decay", "Slot D Amp Decay", 0.1, 0.0, 10.0 => { animate_slot_amp_decay[3] };
+        slot_a_amp_sustain: "animate_slot_a_amp_sustain", "Slot A Amp Sustain", 0.8, 0.0, 1.0 => { animate_slot_amp_sustain[0] };
+        slot_b_amp_sustain: "animate_slot_b_amp_sustain", "Slot B Amp Sustain", 0.8, 0.0, 1.0 => { animate_slot_amp_sustain[1] };
+        slot_c_amp_sustain: "animate_slot_c_amp_sustain", "Slot C Amp Sustain", 0.8, 0.0, 1.0 => { animate_slot_amp_sustain[2] };
+        slot_d_amp_sustain: "animate_slot_d_amp_sustain", "Slot D Amp Sustain", 0.8, 0.0, 1.0 => { animate_slot_amp_sustain[3] };
+        slot_a_amp_release: "animate_slot_a_amp_release", "Slot A Amp Release", 0.3, 0.0, 10.0 => { animate_slot_amp_release[0] };
+        slot_b_amp_release: "animate_slot_b_amp_release", "Slot B Amp Release", 0.3, 0.0, 10.0 => { animate_slot_amp_release[1] };
+        slot_c_amp_release: "animate_slot_c_amp_release", "Slot C Amp Release", 0.3, 0.0, 10.0 => { animate_slot_amp_release[2] };
+        slot_d_amp_release: "animate_slot_d_amp_release", "Slot D Amp Release", 0.3, 0.0, 10.0 => { animate_slot_amp_release[3] };
+        slot_a_filter_env_attack: "animate_slot_a_filter_env_attack", "Slot A Filter Env Attack", 0.01, 0.0, 10.0 => { animate_slot_filter_env_attack[0] };
+        slot_b_filter_env_attack: "animate_slot_b_filter_env_attack", "Slot B Filter Env Attack", 0.01, 0.0, 10.0 => { animate_slot_filter_env_attack[1] };
+        slot_c_filter_env_attack: "animate_slot_c_filter_env_attack", "Slot C Filter Env Attack", 0.01, 0.0, 10.0 => { animate_slot_filter_env_attack[2] };
+        slot_d_filter_env_attack: "animate_slot_d_filter_env_attack", "Slot D Filter Env Attack", 0.01, 0.0, 10.0 => { animate_slot_filter_env_attack[3] };
+        slot_a_filter_env_decay: "animate_slot_a_filter_env_decay", "Slot A Filter Env Decay", 0.3, 0.0, 10.0 => { animate_slot_filter_env_decay[0] };
+        slot_b_filter_env_decay: "animate_slot_b_filter_env_decay", "Slot B Filter Env Decay", 0.3, 0.0, 10.0 => { animate_slot_filter_env_decay[1] };
+        slot_c_filter_env_decay: "animate_slot_c_filter_env_decay", "Slot C Filter Env Decay", 0.3, 0.0, 10.0 => { animate_slot_filter_env_decay[2] };
+        slot_d_filter_env_decay: "animate_slot_d_filter_env_decay", "Slot D Filter Env Decay", 0.3, 0.0, 10.0 => { animate_slot_filter_env_decay[3] };
+        slot_a_filter_env_sustain: "animate_slot_a_filter_env_sustain", "Slot A Filter Env Sustain", 0.0, 0.0, 1.0 => { animate_slot_filter_env_sustain[0] };
+        slot_b_filter_env_sustain: "animate_slot_b_filter_env_sustain", "Slot B Filter Env Sustain", 0.0, 0.0, 1.0 => { animate_slot_filter_env_sustain[1] };
+        slot_c_filter_env_sustain: "animate_slot_c_filter_env_sustain", "Slot C Filter Env Sustain", 0.0, 0.0, 1.0 => { animate_slot_filter_env_sustain[2] };
+        slot_d_filter_env_sustain: "animate_slot_d_filter_env_sustain", "Slot D Filter Env Sustain", 0.0, 0.0, 1.0 => { animate_slot_filter_env_sustain[3] };
+        slot_a_filter_env_release: "animate_slot_a_filter_env_release", "Slot A Filter Env Release", 0.3, 0.0, 10.0 => { animate_slot_filter_env_release[0] };
+        slot_b_filter_env_release: "animate_slot_b_filter_env_release", "Slot B Filter Env Release", 0.3, 0.0, 10.0 => { animate_slot_filter_env_release[1] };
+        slot_c_filter_env_release: "animate_slot_c_filter_env_release", "Slot C Filter Env Release", 0.3, 0.0, 10.0 => { animate_slot_filter_env_release[2] };
+        slot_d_filter_env_release: "animate_slot_d_filter_env_release", "Slot D Filter Env Release", 0.3, 0.0, 10.0 => { animate_slot_filter_env_release[3] };
+        slot_a_filter_env_amount: "animate_slot_a_filter_env_amount", "Slot A Filter Env Amount", 0.0, -1.0, 1.0 => { animate_slot_filter_env_amount[0] };
+        slot_b_filter_env_amount: "animate_slot_b_filter_env_amount", "Slot B Filter Env Amount", 0.0, -1.0, 1.0 => { animate_slot_filter_env_amount[1] };
+        slot_c_filter_env_amount: "animate_slot_c_filter_env_amount", "Slot C Filter Env Amount", 0.0, -1.0, 1.0 => { animate_slot_filter_env_amount[2] };
+        slot_d_filter_env_amount: "animate_slot_d_filter_env_amount", "Slot D Filter Env Amount", 0.0, -1.0, 1.0 => { animate_slot_filter_env_amount[3] };
+        slot_a_drive: "animate_slot_a_drive", "Slot A Drive", 0.0, 0.0, 1.0 => { animate_slot_filter_drive[0] };
+        slot_b_drive: "animate_slot_b_drive", "Slot B Drive", 0.0, 0.0, 1.0 => { animate_slot_filter_drive[1] };
+        slot_c_drive: "animate_slot_c_drive", "Slot C Drive", 0.0, 0.0, 1.0 => { animate_slot_filter_drive[2] };
+        slot_d_drive: "animate_slot_d_drive", "Slot D Drive", 0.0, 0.0, 1.0 => { animate_slot_filter_drive[3] };
+        unison_detune: "animate_unison_detune", "Unison Detune", 0.2, 0.0, 1.0 => { animate_unison_detune };
+        glide: "animate_glide", "Glide", 0.0, 0.0, 2.0 => { animate_glide };
+        kick_pitch: "kick_pitch", "Kick Pitch", 0.5, 0.0, 1.0 => { kick_pitch };
+        kick_decay: "kick_decay", "Kick Decay", 0.5, 0.0, 1.0 => { kick_decay };
+        kick_attack: "kick_attack", "Kick Attack", 0.0, 0.0, 1.0 => { kick_attack };
+        kick_drive: "kick_drive", "Kick Drive", 0.0, 0.0, 1.0 => { kick_drive };
+        kick_level: "kick_level", "Kick Level", 1.0, 0.0, 1.0 => { kick_level };
+        kick_filter_cutoff: "kick_filter_cutoff", "Kick Filter Cutoff", 0.6, 0.0, 1.0 => { kick_filter_cutoff };
+        kick_filter_resonance: "kick_filter_resonance", "Kick Filter Resonance", 0.2, 0.0, 1.0 => { kick_filter_resonance };
+        snare_tone: "snare_tone", "Snare Tone", 0.5, 0.0, 1.0 => { snare_tone };
+        snare_decay: "snare_decay", "Snare Decay", 0.4, 0.0, 1.0 => { snare_decay };
+        snare_snappy: "snare_snappy", "Snare Snappy", 0.6, 0.0, 1.0 => { snare_snappy };
+        snare_attack: "snare_attack", "Snare Attack", 0.0, 0.0, 1.0 => { snare_attack };
+        snare_drive: "snare_drive", "Snare Drive", 0.0, 0.0, 1.0 => { snare_drive };
+        snare_level: "snare_level", "Snare Level", 0.8, 0.0, 1.0 => { snare_level };
+        snare_filter_cutoff: "snare_filter_cutoff", "Snare Filter Cutoff", 0.6, 0.0, 1.0 => { snare_filter_cutoff };
+        snare_filter_resonance: "snare_filter_resonance", "Snare Filter Resonance", 0.2, 0.0, 1.0 => { snare_filter_resonance };
+        void_base_freq: "void_base_freq", "Void Base Freq", 40.0, 20.0, 200.0 => { void_base_freq };
+        void_chaos_depth: "void_chaos_depth", "Void Chaos Depth", 0.5, 0.0, 1.0 => { void_chaos_depth };
+        void_entropy: "void_entropy", "Void Entropy", 0.2, 0.0, 1.0 => { void_entropy };
+        void_feedback: "void_feedback", "Void Feedback", 0.8, 0.0, 0.98 => { void_feedback };
+        void_diffusion: "void_diffusion", "Void Diffusion", 0.5, 0.0, 1.0 => { void_diffusion };
+        void_mod_rate: "void_mod_rate", "Void Mod Rate", 0.1, 0.01, 10.0 => { void_mod_rate };
+        void_level: "void_level", "Void Level", 0.8, 0.0, 1.0 => { void_level };
+    }
+    ints {
+        loop_mode: "loop_mode", "Loop Mode", 0, 0, 5 => { loop_mode };
+        tape_rate_mode: "tape_rate_mode", "Tape Rate Mode", 0, 0, 3 => { tape_rate_mode };
+        tape_keylock_mode: "tape_keylock_mode", "Tape Keylock Mode", 1, 0, 2 => { tape_keylock_mode };
+        tape_slice_mode: "tape_slice_mode", "Tape Slice Mode", 0, 0, 2 => { tape_slice_mode };
+        slot_a_filter_type: "animate_slot_a_filter_type", "Slot A Filter Type", 0, 0, ANIMATE_FILTER_MOOG as i32 => { animate_slot_filter_type[0] };
+        slot_b_filter_type: "animate_slot_b_filter_type", "Slot B Filter Type", 0, 0, ANIMATE_FILTER_MOOG as i32 => { animate_slot_filter_type[1] };
+        slot_c_filter_type: "animate_slot_c_filter_type", "Slot C Filter Type", 0, 0, ANIMATE_FILTER_MOOG as i32 => { animate_slot_filter_type[2] };
+        slot_d_filter_type: "animate_slot_d_filter_type", "Slot D Filter Type", 0, 0, ANIMATE_FILTER_MOOG as i32 => { animate_slot_filter_type[3] };
+        kick_filter_type: "kick_filter_type", "Kick Filter Type", 0, 0, SYNDRM_FILTER_TYPES as i32 - 1 => { kick_filter_type };
+        snare_filter_type: "snare_filter_type", "Snare Filter Type", 0, 0, SYNDRM_FILTER_TYPES as i32 - 1 => { snare_filter_type };
+    }
+    bools {
+        muted: "muted", "Mute", false => { is_muted };
+        swing_override: "swing_override", "Swing Override", false => { swing_override };
+        tape_reverse: "tape_reverse", "Tape Reverse", false => { tape_reverse };
+        tape_freeze: "tape_freeze", "Tape Freeze", false => { tape_freeze };
+        tape_keylock: "tape_keylock", "Tape Keylock", false => { tape_keylock };
+        mosaic_enabled: "mosaic_enabled", "Mosaic Enabled", true => { mosaic_enabled };
+        ring_enabled: "ring_enabled", "Ring Enabled", false => { ring_enabled };
+        g8_enabled: "g8_enabled", "G8 Enabled", false => { g8_enabled };
+        void_enabled: "void_enabled", "Void Enabled", false => { void_enabled };
+    }
+}
+
+#[derive(Params)]
+pub struct TLBX1Params {
+    #[id = "selected_track"]
+    pub selected_track: IntParam,
+
+    #[id = "gain"]
+    pub gain: FloatParam,
+
+    #[id = "master_filter"]
+    pub master_filter: FloatParam,
+
+    #[id = "master_comp"]
+    pub master_comp: FloatParam,
+
+    #[id = "swing"]
+    pub swing: FloatParam,
+
+    #[nested(array, group = "Track")]
+    pub tracks: [TrackParams; NUM_TRACKS],
+
+    /// Start/stop with the host and lock the step clock to its song position.
+    #[persist = "follow_host_transport"]
+    pub follow_host_transport: AtomicBool,
+
+    /// Seconds of tape a new recording pre-allocates at the host sample rate.
+    #[persist = "record_buffer_seconds"]
+    pub record_buffer_seconds: AtomicU32,
+}
+
+impl AnimateLibrary {
+    fn load() -> Self {
+        fn scan_dir(dir: &Path, paths: &mut Vec<PathBuf>) {
+            if let Ok(entries) = std::fs::read_dir(dir) {
+                let mut sorted_entries: Vec<_> = entries.flatten().collect();
+                sorted_entries.sort_by_key(|e| e.file_name());
+
+                for entry in sorted_entries {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        scan_dir(&path, paths);
+                    } else if path.extension().map_or(false, |ext| ext == "wav" || ext == "mp3") {
+                        paths.push(path);
+                    }
+                }
+            }
+        }
+
+        let mut wavetable_paths = Vec::new();
+        let mut wavetable_names = Vec::new();
+        let mut sample_paths = Vec::new();
+
+        // Factory tables first, then the user's own folder, so factory indices stay put.
+        for (root, prefix) in [
+            (PathBuf::from("src/library/factory/wavetables"), ""),
+            (user_wavetables_dir(), "User/"),
+        ] {
+            let first = wavetable_paths.len();
+            scan_dir(&root, &mut wavetable_paths);
+            for path in &wavetable_paths[first..] {
+                let relative = path.strip_prefix(&root).unwrap_or(path);
+                wavetable_names.push(format!(
+                    "{}{}",
+                    prefix,
+                    relative.to_string_lossy().replace('\\', "/")
+                ));
+            }
+        }
+        let mut sample_names = Vec::new();
+        for (root, prefix) in [
+            (PathBuf::from("src/library/factory/samples"), ""),
+            (PathBuf::from("src/library/factory/kits"), "kits/"),
+        ] {
+            let first = sample_paths.len();
+            scan_dir(&root, &mut sample_paths);
+            for path in &sample_paths[first..] {
+                let relative = path.strip_prefix(&root).unwrap_or(path);
+                sample_names.push(format!(
+                    "{}{}",
+                    prefix,
+                    relative.to_string_lossy().replace('\\', "/")
+                ));
+            }
+        }
+
+        // Group samples into multisamples by folder and name, minus the note and layer tokens.
+        // Note-named files that differ only in a numbered variant token
+        // (`Tom_8R8_Chroma1_Dsharp2`, `Tom_8R8_Chroma9_G3`) join one set, as long as no
+        // two of them share a root and layer.
+        let mut group_keys: Vec<(String, Vec<String>)> = Vec::new();
+        let mut sample_groups: Vec<AnimateSampleGroup> = Vec::new();
+        let mut sample_zones = Vec::with_capacity(sample_paths.len());
+        for (idx, path) in sample_paths.iter().enumerate() {
+            let stem = path.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+            let mut name_root = None;
+            let mut layer = 0;
+            let mut kept = Vec::new();
+            for token in stem.split(['_', '-']) {
+                if let Some(note) = sample_name_note(token) {
+                    name_root = Some(note);
+                } else if let Some(number) = sample_name_layer(token) {
+                    layer = number;
+                } else {
+                    kept.push(token.to_ascii_lowercase());
+                }
+            }
+            let folder = path
+                .parent()
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let exact = group_keys.iter().position(|(existing_folder, existing)| {
+                *existing_folder == folder && *existing == kept
+            });
+            let variant = || {
+                let root = name_root?;
+                group_keys.iter().enumerate().position(|(group, (existing_folder, existing))| {
+                    let mut differing = existing.iter().zip(&kept).filter(|(a, b)| a != b);
+                    *existing_folder == folder
+                        && existing.len() == kept.len()
+                        && differing
+                            .next()
+                            .is_some_and(|(a, b)| sample_name_variants(a, b))
+                        && differing.next().is_none()
+                        && sample_groups[group].members.iter().all(|&member| {
+                            let zone: &AnimateSampleZone = &sample_zones[member];
+                            zone.name_root.is_some()
+                                && (zone.name_root != Some(root) || zone.layer != layer)
+                        })
+                })
+            };
+            let group = match exact.or_else(variant) {
+                Some(group) => group,
+                None => {
+                    group_keys.push((folder, kept));
+                    sample_groups.push(AnimateSampleGroup {
+                        members: Vec::new(),
+                        layers: Vec::new(),
+                    });
+                    sample_groups.len() - 1
+                }
+            };
+            sample_groups[group].members.push(idx);
+            if !sample_groups[group].layers.contains(&layer) {
+                sample_groups[group].layers.push(layer);
+                sample_groups[group].layers.sort_unstable();
+            }
+            sample_zones.push(AnimateSampleZone {
+                group,
+                name_root,
+                layer,
+            });
+        }
+
+        let wavetables = wavetable_paths.iter().map(|_| OnceLock::new()).collect();
+        let samples = sample_paths.iter().map(|_| OnceLock::new()).collect();
+
+        Self {
+            wavetable_paths,
+            wavetable_names,
+            sample_paths,
+            sample_names,
+            sample_zones,
+            sample_groups,
+            wavetables,
+            samples,
+            loader_tx: Mutex::new(None),
+            pending_loads: AtomicUsize::new(0),
+        }
+    }
+
+    /// Spawns the loader thread. It only holds a weak reference, so it exits
+    /// once the library is dropped and the queue closes.
+    fn start_loader(self: &Arc<Self>) {
+        let (tx, rx) = mpsc::channel::<AnimateLoadRequest>();
+        let library: Weak<Self> = Arc::downgrade(self);
+        let spawned = std::thread::Builder::new()
+            .name("tlbx1-animate-loader".into())
+            .spawn(move || {
+                while let Ok(request) = rx.recv() {
+                    let Some(library) = library.upgrade() else {
+                        break;
+                    };
+                    library.load_now(request);
+                    library.pending_loads.fetch_sub(1, Ordering::AcqRel);
+                }
+            });
+        if spawned.is_ok() {
+            *self.loader_tx.lock() = Some(tx);
+        }
+    }
+
+    fn is_loaded(&self, request: AnimateLoadRequest) -> bool {
+        match request {
+            AnimateLoadRequest::Wavetable(idx) => {
+                self.wavetables.get(idx).is_none_or(|entry| entry.get().is_some())
+            }
+            AnimateLoadRequest::Sample(idx) => {
+                self.samples.get(idx).is_none_or(|entry| entry.get().is_some())
+            }
+        }
+    }
+
+    /// Queues a file for the loader thread. Files that are already decoded (or
+    /// out of range) are skipped.
+    fn request_load(&self, request: AnimateLoadRequest) {
+        if self.is_loaded(request) {
+            return;
+        }
+        let queued = self.loader_tx.lock().as_ref().is_some_and(|tx| {
+            self.pending_loads.fetch_add(1, Ordering::AcqRel);
+            if tx.send(request).is_ok() {
+                true
+            } else {
+                self.pending_loads.fetch_sub(1, Ordering::AcqRel);
+                false
+            }
+        });
+        if !queued {
+            self.load_now(request);
+        }
+    }
+
+    /// Queues every wavetable and sample an Animate track's slots point at. Called
+    /// off the audio thread, since files are decoded in place without a loader.
+    fn request_track(&self, track: &Track) {
+        if track.engine_type.load(Ordering::Relaxed) != 2 {
+            return;
+        }
+        for request in self.track_load_requests(track) {
+            self.request_load(request);
+        }
+    }
+
+    /// The library files each slot of an Animate track plays, including every
+    /// member of a multisample slot's set.
+    fn track_load_requests(&self, track: &Track) -> Vec<AnimateLoadRequest> {
+        let mut requests = Vec::new();
+        for slot in 0..4 {
+            if track.animate_slot_types[slot].load(Ordering::Relaxed) == 0 {
+                let idx = track.animate_slot_wavetables[slot].load(Ordering::Relaxed) as usize;
+                requests.push(AnimateLoadRequest::Wavetable(idx));
+                continue;
+            }
+            let idx = track.animate_slot_samples[slot].load(Ordering::Relaxed) as usize;
+            match self.sample_zones.get(idx) {
+                Some(zone) if track.animate_slot_multisample[slot].load(Ordering::Relaxed) => {
+                    requests.extend(
+                        self.sample_groups[zone.group]
+                            .members
+                            .iter()
+                            .map(|member| AnimateLoadRequest::Sample(*member)),
+                    );
+                }
+                _ => requests.push(AnimateLoadRequest::Sample(idx)),
+            }
+        }
+        requests
+    }
+
+    /// The sample a multisample set plays for `note` at `velocity` (0..1): the
+    /// velocity layer first, then the loaded member whose root is nearest the note.
+    /// Members without a root rank at `default_root`, the root they play at.
+    fn pick_sample_zone(&self, idx: usize, note: f32, velocity: f32, default_root: f32) -> usize {
+        let Some(zone) = self.sample_zones.get(idx) else {
+            return idx;
+        };
+        let group = &self.sample_groups[zone.group];
+        let layer_idx = ((velocity.clamp(0.0, 1.0) * group.layers.len() as f32) as usize)
+            .min(group.layers.len().saturating_sub(1));
+        let layer = group.layers.get(layer_idx).copied().unwrap_or(0);
+        let mut best = idx;
+        let mut best_distance = f32::MAX;
+        for &member in &group.members {
+            if self.sample_zones[member].layer != layer {
+                continue;
+            }
+            let Some(sample) = self.get_sample_cached(member) else {
+                continue;
+            };
+            let distance = (sample.root.unwrap_or(default_root) - note).abs();
+            if distance < best_distance {
+                best = member;
+                best_distance = distance;
+            }
+        }
+        best
+    }
+
+    /// Decodes a file on the calling thread. Never call this from the audio thread.
+    fn load_now(&self, request: AnimateLoadRequest) {
+        if self.is_loaded(request) {
+            return;
+        }
+        match request {
+            AnimateLoadRequest::Wavetable(idx) => {
+                let path = &self.wavetable_paths[idx];
+                let wavetable = load_audio_file(path)
+                    .ok()
+                    .and_then(|(data, _)| data.into_iter().next())
+                    .and_then(|data| Wavetable::new(data, wavetable_clm_frame_len(path)));
+                match wavetable {
+                    Some(wt) => {
+                        let _ = self.wavetables[idx].set(wt);
+                    }
+                    None => nih_log!("Failed to load wavetable: {:?}", path),
+                }
+            }
+            AnimateLoadRequest::Sample(idx) => {
+                let path = &self.sample_paths[idx];
+                match load_audio_file(path) {
+                    Ok((channels, sample_rate)) => {
+                        // The `smpl` chunk wins over the file name; the track decides when
+                        // neither says.
+                        let root = wav_smpl_root(path).or(self.sample_zones[idx].name_root);
+                        let _ = self.samples[idx].set(AnimateSample {
+                            channels,
+                            sample_rate: sample_rate.max(1) as f32,
+                            root,
+                        });
+                    }
+                    Err(err) => nih_log!("Failed to load sample {:?}: {:?}", path, err),
+                }
+            }
+        }
+    }
+
+    fn get_wavetable_cached(&self, idx: usize) -> Option<&Wavetable> {
+        self.wavetables.get(idx).and_then(|entry| entry.get())
+    }
+
+    fn get_sample_cached(&self, idx: usize) -> Option<&AnimateSample> {
+        self.samples.get(idx).and_then(|entry| entry.get())
+    }
+}
+
+/// Type, wavetable, sample and multisample flag of each Animate slot.
+type AnimateSlotSources = [(u32, u32, u32, bool); 4];
+
+/// What an Animate track's slots play, or `None` for other engines.
+fn animate_slot_sources(track: &Track) -> Option<AnimateSlotSources> {
+    if track.engine_type.load(Ordering::Relaxed) != 2 {
+        return None;
+    }
+    Some(std::array::from_fn(|slot| {
+        (
+            track.animate_slot_types[slot].load(Ordering::Relaxed),
+            track.animate_slot_wavetables[slot].load(Ordering::Relaxed),
+            track.animate_slot_samples[slot].load(Ordering::Relaxed),
+            track.animate_slot_multisample[slot].load(Ordering::Relaxed),
+        )
+    }))
+}
+
+impl Default for TLBX1 {
+    fn default() -> Self {
+        let animate_library = Arc::new(AnimateLibrary::load());
+        animate_library.start_loader();
+        // Warm the wavetable new Animate slots start on, so a fresh engine plays straight away.
+        animate_library.request_load(AnimateLoadRequest::Wavetable(0));
+        Self::with_library(animate_library)
+    }
+}
+
+impl TLBX1 {
+    fn with_library(animate_library: Arc<AnimateLibrary>) -> Self {
+        let tracks = [
+            Track::default(),
+            Track::default(),
+            Track::default(),
+            Track::default(),
+        ];
+        // Give each track its own MIDI channel so they can be played independently.
+        for (i, track) in tracks.iter().enumerate() {
+            track.midi_channel.store(i as u32 + 1, Ordering::Relaxed);
+        }
+        
         Self {
             params: Arc::new(TLBX1Params::default()),
             tracks: Arc::new(tracks),
@@ -1229,12 +3165,20 @@ impl Default for TLBX1 {
             master_step_phase: 0.0,
             master_step_index: 0,
             master_step_count: 0,
-            animate_library: Arc::new(AnimateLibrary::load()),
+            animate_library,
             master_fx: MasterFxState::default(),
-            sample_rate: AtomicU32::new(44100),
+            sample_rate: Arc::new(AtomicU32::new(44100)),
             pending_project_params: Arc::new(Mutex::new(None)),
             track_buffer: vec![vec![0.0; 1024]; 2],
+            midi_events: Vec::with_capacity(MIDI_EVENT_CAPACITY),
             syndrm_dsp: std::array::from_fn(|_| SynDRMDspState::new()),
+            master_recorder: Arc::new(MasterRecorder::new()),
+            midi_cc: Arc::new(MidiCcState::default()),
+            midi_clock: Arc::new(MidiClock::default()),
+            host_was_playing: false,
+            animate_sources_seen: [None; NUM_TRACKS],
+            render_master: None,
+            render_stems: Vec::new(),
         }
     }
 }
@@ -1274,6 +3218,66 @@ impl Default for MasterFxState {
     }
 }
 
+/// Captures the post-master-FX output for the master record toggle.
+///
+/// The audio thread pushes interleaved stereo frames into a lock-free ring; a writer
+/// thread drains it to disk so the audio thread never allocates or touches files.
+struct MasterRecorder {
+    /// Set by the UI; the audio thread only feeds the ring while this is on.
+    armed: AtomicBool,
+    /// True while a writer thread owns the ring and the output file.
+    writer_active: AtomicBool,
+    sample_rate: AtomicU32,
+    ring: Box<[AtomicU32]>,
+    write_pos: AtomicUsize,
+    read_pos: AtomicUsize,
+    dropped_frames: AtomicUsize,
+}
+
+impl MasterRecorder {
+    fn new() -> Self {
+        Self {
+            armed: AtomicBool::new(false),
+            writer_active: AtomicBool::new(false),
+            sample_rate: AtomicU32::new(44_100),
+            ring: (0..MASTER_RECORD_RING_FRAMES * 2)
+                .map(|_| AtomicU32::new(0))
+                .collect(),
+            write_pos: AtomicUsize::new(0),
+            read_pos: AtomicUsize::new(0),
+            dropped_frames: AtomicUsize::new(0),
+        }
+    }
+
+    /// Audio thread only. Drops the frame if the writer has fallen a full ring behind.
+    fn push_frame(&self, left: f32, right: f32) {
+        let write = self.write_pos.load(Ordering::Relaxed);
+        let read = self.read_pos.load(Ordering::Acquire);
+        if write.wrapping_sub(read) >= MASTER_RECORD_RING_FRAMES {
+            self.dropped_frames.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        let idx = (write % MASTER_RECORD_RING_FRAMES) * 2;
+        self.ring[idx].store(left.to_bits(), Ordering::Relaxed);
+        self.ring[idx + 1].store(right.to_bits(), Ordering::Relaxed);
+        self.write_pos.store(write.wrapping_add(1), Ordering::Release);
+    }
+
+    /// Writer thread only. Appends every queued frame to `out` as interleaved samples.
+    fn pop_frames(&self, out: &mut Vec<f32>) {
+        let read = self.read_pos.load(Ordering::Relaxed);
+        let write = self.write_pos.load(Ordering::Acquire);
+        let available = write.wrapping_sub(read);
+        for i in 0..available {
+            let idx = (read.wrapping_add(i) % MASTER_RECORD_RING_FRAMES) * 2;
+            out.push(f32::from_bits(self.ring[idx].load(Ordering::Relaxed)));
+            out.push(f32::from_bits(self.ring[idx + 1].load(Ordering::Relaxed)));
+        }
+        self.read_pos
+            .store(read.wrapping_add(available), Ordering::Release);
+    }
+}
+
 #[derive(Default)]
 struct VisualizerState {
     oscilloscope: Mutex<Vec<f32>>,
@@ -1321,6 +3325,22 @@ impl Default for TLBX1Params {
             )
             .with_unit("%")
             .with_value_to_string(formatters::v2s_f32_percentage(0)),
+
+            swing: FloatParam::new(
+                "Swing",
+                SWING_MIN,
+                FloatRange::Linear {
+                    min: SWING_MIN,
+                    max: SWING_MAX,
+                },
+            )
+            .with_unit("%")
+            .with_value_to_string(formatters::v2s_f32_rounded(0)),
+
+            tracks: std::array::from_fn(TrackParams::new),
+
+            follow_host_transport: AtomicBool::new(false),
+            record_buffer_seconds: AtomicU32::new(RECORD_BUFFER_DEFAULT_SECONDS),
         }
     }
 }
@@ -1338,6 +3358,34 @@ pub enum TLBX1Task {
         title: String,
         description: String,
     },
+    RenderAudio {
+        path: PathBuf,
+        options: RenderOptions,
+    },
+    MidiCc {
+        channel: u8,
+        cc: u8,
+        value: f32,
+    },
+    RequestAnimateLoads(usize),
+}
+
+/// How much of the arrangement an offline render covers.
+#[derive(Clone, Copy)]
+pub enum RenderLength {
+    Bars(u32),
+    Seconds(f32),
+}
+
+/// Settings for an offline bounce of the master output.
+#[derive(Clone, Copy)]
+pub struct RenderOptions {
+    pub length: RenderLength,
+    /// 16 or 24 for integer PCM, 32 for float.
+    pub bit_depth: u16,
+    pub sample_rate: u32,
+    /// Also write one post-FX WAV per track next to the master file.
+    pub stems: bool,
 }
 
 struct PendingEngineLoad {
@@ -1345,6 +3393,307 @@ struct PendingEngineLoad {
     engine_type: u32,
 }
 
+/// Mono mix of the first two channels at `idx`.
+fn tape_onset_mono(samples: &[Vec<f32>], idx: usize) -> f32 {
+    let channels = samples.len().min(2);
+    samples[..channels].iter().map(|channel| channel[idx]).sum::<f32>() / channels as f32
+}
+
+/// Spectral flux of a sample, one value per `TAPE_ONSET_HOP` frame. A hit shows up
+/// about one frame before the hop it lands in; frame 0 has no flux.
+fn tape_onset_flux(samples: &[Vec<f32>]) -> Vec<f32> {
+    let len = samples.first().map_or(0, |channel| channel.len());
+    if len < TAPE_ONSET_FRAME {
+        return Vec::new();
+    }
+    let window: Vec<f32> = (0..TAPE_ONSET_FRAME)
+        .map(|k| {
+            0.5 - 0.5
+                * (2.0 * std::f32::consts::PI * k as f32 / TAPE_ONSET_FRAME as f32).cos()
+        })
+        .collect();
+    let bins = TAPE_ONSET_FRAME / 2;
+    let frames = (len - TAPE_ONSET_FRAME) / TAPE_ONSET_HOP + 1;
+    let mut re = vec![0.0f32; TAPE_ONSET_FRAME];
+    let mut im = vec![0.0f32; TAPE_ONSET_FRAME];
+    let mut prev = vec![0.0f32; bins];
+    let mut flux = Vec::with_capacity(frames);
+    for frame in 0..frames {
+        let start = frame * TAPE_ONSET_HOP;
+        for k in 0..TAPE_ONSET_FRAME {
+            re[k] = tape_onset_mono(samples, start + k) * window[k];
+            im[k] = 0.0;
+        }
+        fft_in_place(&mut re, &mut im, false);
+        let mut sum = 0.0f32;
+        for bin in 1..bins {
+            // Log-compressed magnitudes keep quiet hits from being swamped by loud ones.
+            let mag = (1.0 + 100.0 * (re[bin] * re[bin] + im[bin] * im[bin]).sqrt()).ln();
+            sum += (mag - prev[bin]).max(0.0);
+            prev[bin] = mag;
+        }
+        flux.push(if frame == 0 { 0.0 } else { sum });
+    }
+    flux
+}
+
+/// Moves an onset found at flux `frame` to the 64-sample block where the energy
+/// rises most.
+fn refine_tape_onset(samples: &[Vec<f32>], frame: usize) -> usize {
+    const BLOCK: usize = 64;
+    let len = samples.first().map_or(0, |channel| channel.len());
+    let start = frame.saturating_sub(1) * TAPE_ONSET_HOP;
+    let end = (frame * TAPE_ONSET_HOP + TAPE_ONSET_FRAME).min(len);
+    let mut best = start;
+    let mut best_rise = 0.0f32;
+    let mut prev_energy = None;
+    let mut pos = start;
+    while pos + BLOCK <= end {
+        // The first difference favours the broadband attack over low sustained tones.
+        let energy = (pos.max(1)..pos + BLOCK)
+            .map(|idx| {
+                let diff = tape_onset_mono(samples, idx) - tape_onset_mono(samples, idx - 1);
+                diff * diff
+            })
+            .sum::<f32>()
+            + 1.0e-9;
+        if let Some(prev_energy) = prev_energy {
+            if energy / prev_energy > best_rise {
+                best_rise = energy / prev_energy;
+                best = pos;
+            }
+        }
+        prev_energy = Some(energy);
+        pos += BLOCK;
+    }
+    best
+}
+
+/// Slice start positions for a loaded sample, found by spectral flux onset detection.
+/// The first slice always starts at 0.
+fn detect_tape_slices(samples: &[Vec<f32>], flux: &[f32], sample_rate: u32) -> Vec<usize> {
+    let len = samples.first().map_or(0, |channel| channel.len());
+    if len == 0 {
+        return Vec::new();
+    }
+    if len < TAPE_ONSET_FRAME * 2 {
+        return vec![0];
+    }
+    let frames = flux.len();
+
+    // A peak counts when it stands above the local median, with a floor so
+    // near-silence doesn't slice, and at least 50 ms after the previous onset.
+    let mean = flux.iter().sum::<f32>() / frames as f32;
+    let reach = 8;
+    let min_gap = ((sample_rate as f32 * 0.05) / TAPE_ONSET_HOP as f32).ceil() as usize;
+    let mut peaks: Vec<(usize, f32)> = Vec::new();
+    let mut window_buf = Vec::with_capacity(reach * 2 + 1);
+    for i in 1..frames.saturating_sub(1) {
+        if flux[i] <= flux[i - 1] || flux[i] < flux[i + 1] {
+            continue;
+        }
+        let lo = i.saturating_sub(reach);
+        let hi = (i + reach + 1).min(frames);
+        window_buf.clear();
+        window_buf.extend_from_slice(&flux[lo..hi]);
+        window_buf.sort_by(|a, b| a.total_cmp(b));
+        let median = window_buf[window_buf.len() / 2];
+        if flux[i] < median * 1.5 + mean * 0.1 {
+            continue;
+        }
+        match peaks.last_mut() {
+            Some(last) if i - last.0 < min_gap => {
+                if flux[i] > last.1 {
+                    *last = (i, flux[i]);
+                }
+            }
+            _ => peaks.push((i, flux[i])),
+        }
+    }
+    if peaks.len() >= TAPE_SLICE_MAX {
+        peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+        peaks.truncate(TAPE_SLICE_MAX - 1);
+        peaks.sort_by_key(|peak| peak.0);
+    }
+
+    let mut slices = vec![0];
+    for (frame, _) in peaks {
+        let best = refine_tape_onset(samples, frame);
+        if best > *slices.last().unwrap_or(&0) + 64 {
+            slices.push(best);
+        }
+    }
+    slices
+}
+
+/// Tempo and first downbeat of a loaded sample, from the autocorrelation of its
+/// onset envelope. Returns `(bpm, downbeat sample)`, or `None` when the sample is
+/// too short or has no steady pulse.
+fn detect_tape_tempo(
+    samples: &[Vec<f32>],
+    flux: &[f32],
+    sample_rate: u32,
+) -> Option<(f32, usize)> {
+    let len = samples.first().map_or(0, |channel| channel.len());
+    if sample_rate == 0 || len < sample_rate as usize * 2 {
+        return None;
+    }
+    let frames = flux.len();
+    let frame_rate = sample_rate as f32 / TAPE_ONSET_HOP as f32;
+
+    // Onset envelope: flux above its local mean, so sustained material doesn't
+    // lift every lag.
+    let reach = 8;
+    let env: Vec<f32> = (0..frames)
+        .map(|i| {
+            let lo = i.saturating_sub(reach);
+            let hi = (i + reach + 1).min(frames);
+            let local = flux[lo..hi].iter().sum::<f32>() / (hi - lo) as f32;
+            (flux[i] - local).max(0.0)
+        })
+        .collect();
+
+    // Loops repeat, so the autocorrelation wraps around the end of the sample.
+    let max_lag =
+        (((60.0 / TAPE_TEMPO_MIN) * frame_rate * 4.0).ceil() as usize + 1).min(frames - 1);
+    let acf: Vec<f32> = (0..=max_lag)
+        .map(|lag| {
+            (0..frames)
+                .map(|i| env[i] * env[(i + lag) % frames])
+                .sum::<f32>()
+        })
+        .collect();
+    if acf[0] <= 1.0e-9 {
+        return None;
+    }
+    let acf_at = |lag: f32| {
+        let idx = lag.floor() as usize;
+        let frac = lag - idx as f32;
+        acf[idx] * (1.0 - frac) + acf[(idx + 1).min(max_lag)] * frac
+    };
+
+    // Each candidate also scores at 2, 3 and 4 beats, which sharpens the peak. The
+    // search spans one octave so half and double tempo can't compete, and a mild
+    // pull toward 120 BPM settles ties at its edges.
+    let mut best_bpm = 0.0f32;
+    let mut best_score = 0.0f32;
+    let mut score_sum = 0.0f32;
+    let mut score_count = 0;
+    let mut bpm = TAPE_TEMPO_MIN;
+    while bpm <= TAPE_TEMPO_MAX {
+        let lag = 60.0 * frame_rate / bpm;
+        let beats = (1..=4).filter(|beats| lag * *beats as f32 <= max_lag as f32);
+        let (sum, terms) = beats.fold((0.0f32, 0), |(sum, terms), beats| {
+            (sum + acf_at(lag * beats as f32), terms + 1)
+        });
+        if terms > 0 {
+            let octave = (bpm / 120.0).log2() / 1.5;
+            let score = sum / terms as f32 * (-0.5 * octave * octave).exp();
+            score_sum += score;
+            score_count += 1;
+            if score > best_score {
+                best_score = score;
+                best_bpm = bpm;
+            }
+        }
+        bpm += 0.05;
+    }
+    // A pulse stands well clear of the average lag; pads and noise don't.
+    if score_count == 0 || best_score < 4.0 * score_sum / score_count as f32 {
+        return None;
+    }
+
+    // Beat phase: the offset whose beat grid collects the most onset energy.
+    let period = 60.0 * frame_rate / best_bpm;
+    let strength = |pos: f32| {
+        let idx = pos.round() as isize;
+        (idx - 1..=idx + 1)
+            .filter(|idx| *idx >= 0 && (*idx as usize) < frames)
+            .map(|idx| env[idx as usize])
+            .fold(0.0f32, f32::max)
+    };
+    let beat_grid = |phase: f32| {
+        let mut pos = phase;
+        std::iter::from_fn(move || {
+            let beat = pos;
+            pos += period;
+            (beat < frames as f32).then_some(beat)
+        })
+    };
+    let mut phase = 0.0f32;
+    let mut best_phase = -1.0f32;
+    let mut offset = 0.0f32;
+    while offset < period {
+        let score = beat_grid(offset)
+            .map(|pos| {
+                let idx = pos.round() as usize;
+                let side = |idx: usize| env.get(idx).copied().unwrap_or(0.0) * 0.5;
+                env[idx.min(frames - 1)] + side(idx + 1) + idx.checked_sub(1).map_or(0.0, side)
+            })
+            .sum::<f32>();
+        if score > best_phase {
+            best_phase = score;
+            phase = offset;
+        }
+        offset += 0.5;
+    }
+
+    // Loops are normally cut on the downbeat, so a file that opens on a hit starts
+    // on one. Otherwise the first beat on the grid that carries a real hit is taken.
+    let opening = (0..TAPE_ONSET_FRAME.min(len))
+        .map(|idx| tape_onset_mono(samples, idx).abs())
+        .fold(0.0f32, f32::max);
+    let peak = (0..len)
+        .step_by(16)
+        .map(|idx| tape_onset_mono(samples, idx).abs())
+        .fold(0.0f32, f32::max);
+    let first = ((phase + 2.0) % period - 2.0).max(0.0);
+    let beats = beat_grid(first).count().max(1);
+    let average = beat_grid(first).map(strength).sum::<f32>() / beats as f32;
+    let downbeat = if opening > peak * 0.25 {
+        0
+    } else {
+        beat_grid(first)
+            .find(|pos| strength(*pos) >= average * 0.5)
+            .map_or(0, |pos| refine_tape_onset(samples, pos.round() as usize))
+    };
+
+    // Loops usually hold whole bars; snap to that tempo when it is close.
+    let seconds = (len - downbeat) as f32 / sample_rate as f32;
+    let bars = (seconds * best_bpm / 240.0).round();
+    let mut tempo = best_bpm;
+    if bars >= 1.0 {
+        let snapped = bars * 240.0 / seconds;
+        if (snapped - best_bpm).abs() / best_bpm < 0.02 {
+            tempo = snapped;
+        }
+    }
+    Some((tempo, downbeat))
+}
+
+/// Stores freshly detected slice starts; `reset_order` puts the slices back in
+/// their original order, as after loading new material.
+fn store_tape_slices(track: &Track, slices: &[usize], reset_order: bool) {
+    let count = slices.len().min(TAPE_SLICE_MAX);
+    for (slot, start) in track.tape_slice_starts.iter().zip(slices.iter()) {
+        slot.store(*start as u32, Ordering::Relaxed);
+    }
+    track.tape_slice_count.store(count as u32, Ordering::Relaxed);
+    if reset_order {
+        for (i, slot) in track.tape_slice_order.iter().enumerate() {
+            slot.store(i as u32, Ordering::Relaxed);
+        }
+    }
+    track.tape_slice_playing.store(false, Ordering::Relaxed);
+}
+
+/// Stores the detected tempo and downbeat, or clears them.
+fn store_tape_tempo(track: &Track, tempo: Option<(f32, usize)>) {
+    let (bpm, downbeat) = tempo.unwrap_or((0.0, 0));
+    track.tape_detected_bpm.store(bpm.to_bits(), Ordering::Relaxed);
+    track.tape_downbeat.store(downbeat as u32, Ordering::Relaxed);
+}
+
 fn calculate_waveform_summary(samples: &[f32], summary: &mut [f32]) {
     if samples.is_empty() {
         for s in summary.iter_mut() { *s = 0.0; }
@@ -1383,12 +3732,18 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
     track.pending_record.store(false, Ordering::Relaxed);
     track.count_in_remaining.store(0, Ordering::Relaxed);
     track.play_pos.store(0.0f32.to_bits(), Ordering::Relaxed);
-    track.record_pos.store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.record_pos.store(0, Ordering::Relaxed);
+    track.record_appending.store(false, Ordering::Relaxed);
+    track.record_dropped.store(0, Ordering::Relaxed);
+    // Retires any recording helper thread without letting it finalize the old take.
+    track.record_generation.fetch_add(1, Ordering::AcqRel);
     track.level.store(1.0f32.to_bits(), Ordering::Relaxed);
     track.level_smooth.store(1.0f32.to_bits(), Ordering::Relaxed);
     track.meter_left.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.meter_right.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.is_muted.store(false, Ordering::Relaxed);
+    track.swing_override.store(false, Ordering::Relaxed);
+    track.swing.store(SWING_MIN.to_bits(), Ordering::Relaxed);
     track.video_enabled.store(false, Ordering::Relaxed);
     track.video_width.store(0, Ordering::Relaxed);
     track.video_height.store(0, Ordering::Relaxed);
@@ -1406,9 +3761,21 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
     track.tape_reverse.store(false, Ordering::Relaxed);
     track.tape_freeze.store(false, Ordering::Relaxed);
     track.tape_keylock.store(false, Ordering::Relaxed);
+    track.tape_pitch.store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.tape_fine.store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.tape_stretch_active.store(false, Ordering::Relaxed);
     track.keylock_phase.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.keylock_grain_a.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.keylock_grain_b.store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.tape_keylock_mode.store(1, Ordering::Relaxed);
+    track.keylock_xfade.store(1.0f32.to_bits(), Ordering::Relaxed);
+    track.keylock_onset.store((-1.0f32).to_bits(), Ordering::Relaxed);
+    track.keylock_onset_last.store((-1.0f32).to_bits(), Ordering::Relaxed);
+    track.tape_slice_mode.store(0, Ordering::Relaxed);
+    store_tape_slices(track, &[], true);
+    track.tape_slice_current.store(0, Ordering::Relaxed);
+    track.tape_slice_step.store(-1, Ordering::Relaxed);
+    store_tape_tempo(track, None);
     track.tape_monitor.store(false, Ordering::Relaxed);
     track.tape_overdub.store(false, Ordering::Relaxed);
     track.loop_start.store(0.0f32.to_bits(), Ordering::Relaxed);
@@ -1519,6 +3886,9 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
         track.animate_slot_level_smooth[i].store(1.0f32.to_bits(), Ordering::Relaxed);
         track.animate_slot_pan[i].store(0.0f32.to_bits(), Ordering::Relaxed);
         track.animate_slot_pan_smooth[i].store(0.0f32.to_bits(), Ordering::Relaxed);
+        track
+            .animate_slot_wt_position[i]
+            .store(0.0f32.to_bits(), Ordering::Relaxed);
         track
             .animate_slot_wt_lfo_amount[i]
             .store(0.0f32.to_bits(), Ordering::Relaxed);
@@ -1549,6 +3919,7 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
         track
             .animate_slot_loop_end[i]
             .store(1.0f32.to_bits(), Ordering::Relaxed);
+        track.animate_slot_multisample[i].store(false, Ordering::Relaxed);
         track
             .animate_slot_filter_type[i]
             .store(0, Ordering::Relaxed);
@@ -1558,11 +3929,38 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
         track
             .animate_slot_filter_resonance[i]
             .store(0.0f32.to_bits(), Ordering::Relaxed);
+        track
+            .animate_slot_filter_drive[i]
+            .store(0.0f32.to_bits(), Ordering::Relaxed);
+        for param in 0..ANIMATE_SLOT_ENV_PARAMS {
+            animate_slot_env_param(track, param)[i]
+                .store(ANIMATE_SLOT_ENV_DEFAULTS[param].to_bits(), Ordering::Relaxed);
+        }
     }
+    track
+        .animate_sample_default_root
+        .store(ANIMATE_SAMPLE_DEFAULT_ROOT.to_bits(), Ordering::Relaxed);
     track.animate_vector_x.store(0.5f32.to_bits(), Ordering::Relaxed);
     track.animate_vector_y.store(0.5f32.to_bits(), Ordering::Relaxed);
     track.animate_vector_x_smooth.store(0.5f32.to_bits(), Ordering::Relaxed);
     track.animate_vector_y_smooth.store(0.5f32.to_bits(), Ordering::Relaxed);
+    track.animate_vector_path_enabled.store(false, Ordering::Relaxed);
+    track.animate_vector_path_sync.store(false, Ordering::Relaxed);
+    track.animate_vector_path_loop.store(false, Ordering::Relaxed);
+    track.animate_vector_path_loop_start.store(0, Ordering::Relaxed);
+    track.animate_vector_path_loop_end.store(0, Ordering::Relaxed);
+    track.animate_vector_path_len.store(0, Ordering::Relaxed);
+    for i in 0..ANIMATE_VECTOR_PATH_POINTS {
+        track.animate_vector_path_x[i].store(0.5f32.to_bits(), Ordering::Relaxed);
+        track.animate_vector_path_y[i].store(0.5f32.to_bits(), Ordering::Relaxed);
+        track.animate_vector_path_time[i].store(0.0f32.to_bits(), Ordering::Relaxed);
+    }
+    track.animate_vector_path_tempo.store(120.0f32.to_bits(), Ordering::Relaxed);
+    track.animate_vector_path_recording.store(false, Ordering::Relaxed);
+    track.animate_vector_path_rec_elapsed.store(0, Ordering::Relaxed);
+    for pos in &track.animate_vector_path_pos {
+        pos.store(0.0f32.to_bits(), Ordering::Relaxed);
+    }
     track.animate_lfo_x_waveform.store(0, Ordering::Relaxed);
     track.animate_lfo_x_sync.store(false, Ordering::Relaxed);
     track.animate_lfo_x_division.store(0, Ordering::Relaxed);
@@ -1578,15 +3976,33 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
     track.animate_lfo_y_phase.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.animate_lfo_y_snh.store(0.0f32.to_bits(), Ordering::Relaxed);
     track.animate_lfo_rng_state.store(0x2468_ace1, Ordering::Relaxed);
-    for i in 0..160 {
-        track.animate_sequencer_grid[i].store(false, Ordering::Relaxed);
+    for lane in 0..ANIMATE_SEQ_VOICES {
+        let note = animate_lane_note(0, ANIMATE_SEQ_DEFAULT_ROOT, lane);
+        for i in 0..ANIMATE_SEQ_STEPS {
+            track.animate_sequencer_grid[lane][i].store(false, Ordering::Relaxed);
+            track.animate_step_note_override[lane][i].store(false, Ordering::Relaxed);
+            track.animate_step_note[lane][i].store(note, Ordering::Relaxed);
+        }
+    }
+    for i in 0..ANIMATE_SEQ_STEPS {
+        for param in 0..ANIMATE_LOCK_PARAMS {
+            track.animate_step_lock_enabled[i][param].store(false, Ordering::Relaxed);
+            track.animate_step_lock_values[i][param].store(0.0f32.to_bits(), Ordering::Relaxed);
+        }
     }
+    track.animate_lock_param.store(0, Ordering::Relaxed);
+    track.animate_scale.store(0, Ordering::Relaxed);
+    track.animate_root_note.store(ANIMATE_SEQ_DEFAULT_ROOT, Ordering::Relaxed);
+    track.animate_page.store(0, Ordering::Relaxed);
+    track.animate_edit_lane.store(0, Ordering::Relaxed);
+    track.animate_edit_step.store(0, Ordering::Relaxed);
     track.animate_sequencer_step.store(-1, Ordering::Relaxed);
     track.animate_sequencer_phase.store(0, Ordering::Relaxed);
-    for voice in 0..10 {
+    for voice in 0..ANIMATE_VOICES {
         for slot in 0..4 {
             track.animate_slot_phases[voice][slot].store(0.0f32.to_bits(), Ordering::Relaxed);
             track.animate_slot_sample_pos[voice][slot].store(0.0f32.to_bits(), Ordering::Relaxed);
+            track.animate_slot_voice_sample[voice][slot].store(0, Ordering::Relaxed);
             track.animate_slot_filter_v1[voice][slot].store(0.0f32.to_bits(), Ordering::Relaxed);
             track.animate_slot_filter_v2[voice][slot].store(0.0f32.to_bits(), Ordering::Relaxed);
             track
@@ -1595,35 +4011,53 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
             track
                 .animate_slot_filter_v2_stage2[voice][slot]
                 .store(0.0f32.to_bits(), Ordering::Relaxed);
+            track.animate_slot_amp_env_stage[voice][slot].store(0, Ordering::Relaxed);
+            track
+                .animate_slot_amp_env_level[voice][slot]
+                .store(0.0f32.to_bits(), Ordering::Relaxed);
+            track.animate_slot_filter_env_stage[voice][slot].store(0, Ordering::Relaxed);
+            track
+                .animate_slot_filter_env_level[voice][slot]
+                .store(0.0f32.to_bits(), Ordering::Relaxed);
+            for stage in &track.animate_slot_ladder_state[voice][slot] {
+                stage.store(0.0f32.to_bits(), Ordering::Relaxed);
+            }
         }
         track.animate_amp_stage[voice].store(0, Ordering::Relaxed);
         track.animate_amp_level[voice].store(0.0f32.to_bits(), Ordering::Relaxed);
     }
-    track.animate_keybed_note.store(60, Ordering::Relaxed);
-    track.animate_keybed_trigger.store(false, Ordering::Relaxed);
-    track.animate_keybed_hold.store(false, Ordering::Relaxed);
-    track.animate_keybed_amp_stage.store(0, Ordering::Relaxed);
-    track.animate_keybed_amp_level.store(0.0f32.to_bits(), Ordering::Relaxed);
-    for slot in 0..4 {
-        track
-            .animate_keybed_slot_phases[slot]
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        track
-            .animate_keybed_slot_sample_pos[slot]
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        track
-            .animate_keybed_filter_v1[slot]
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.animate_voice_mode.store(0, Ordering::Relaxed);
+    track
+        .animate_polyphony
+        .store(ANIMATE_VOICES as u32, Ordering::Relaxed);
+    track.animate_steal_mode.store(0, Ordering::Relaxed);
+    track.animate_unison_voices.store(4, Ordering::Relaxed);
+    track
+        .animate_unison_detune
+        .store(0.2f32.to_bits(), Ordering::Relaxed);
+    track.animate_glide.store(0.0f32.to_bits(), Ordering::Relaxed);
+    for voice in 0..ANIMATE_VOICES {
+        track.animate_voice_note[voice].store(-1, Ordering::Relaxed);
+        track.animate_voice_source[voice].store(0, Ordering::Relaxed);
         track
-            .animate_keybed_filter_v2[slot]
+            .animate_voice_velocity[voice]
             .store(0.0f32.to_bits(), Ordering::Relaxed);
+        track.animate_voice_age[voice].store(0, Ordering::Relaxed);
         track
-            .animate_keybed_filter_v1_stage2[slot]
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
+            .animate_voice_pitch[voice]
+            .store(60.0f32.to_bits(), Ordering::Relaxed);
         track
-            .animate_keybed_filter_v2_stage2[slot]
+            .animate_voice_detune[voice]
             .store(0.0f32.to_bits(), Ordering::Relaxed);
+        track.animate_voice_gate[voice].store(0, Ordering::Relaxed);
     }
+    track.animate_voice_counter.store(0, Ordering::Relaxed);
+    track
+        .animate_last_pitch
+        .store((-1.0f32).to_bits(), Ordering::Relaxed);
+    track.animate_keybed_note.store(60, Ordering::Relaxed);
+    track.animate_keybed_trigger.store(false, Ordering::Relaxed);
+    track.animate_keybed_hold.store(false, Ordering::Relaxed);
     track.kick_pitch.store(0.5f32.to_bits(), Ordering::Relaxed);
     track.kick_decay.store(0.5f32.to_bits(), Ordering::Relaxed);
     track.kick_attack.store(0.0f32.to_bits(), Ordering::Relaxed);
@@ -1691,6 +4125,24 @@ fn reset_track_for_engine(track: &Track, engine_type: u32) {
         track.snare_step_filter_cutoff[i].store(0.6f32.to_bits(), Ordering::Relaxed);
         track.snare_step_filter_resonance[i].store(0.2f32.to_bits(), Ordering::Relaxed);
     }
+    for voice in 0..SYNDRM_VOICES {
+        for p in 0..SYNDRM_VOICE_PARAMS {
+            let default = SYNDRM_VOICE_DEFAULTS[voice][p].to_bits();
+            track.syndrm_voice_params[voice][p].store(default, Ordering::Relaxed);
+            for i in 0..SYNDRM_STEPS {
+                track.syndrm_voice_step_params[voice][i][p].store(default, Ordering::Relaxed);
+            }
+        }
+        for i in 0..SYNDRM_STEPS {
+            track.syndrm_voice_grid[voice][i].store(false, Ordering::Relaxed);
+            track.syndrm_voice_step_override_enabled[voice][i].store(false, Ordering::Relaxed);
+        }
+    }
+    for lane in 0..SYNDRM_LANES {
+        for i in 0..SYNDRM_STEPS {
+            syndrm_reset_step_trigger(track, lane, i);
+        }
+    }
 
     track.void_base_freq.store(40.0f32.to_bits(), Ordering::Relaxed);
     track.void_chaos_depth.store(0.5f32.to_bits(), Ordering::Relaxed);
@@ -1757,9 +4209,10 @@ impl TLBX1 {
         num_buffer_samples: usize,
         global_tempo: &AtomicU32,
         animate_library: &AnimateLibrary,
-        master_step: i32,
+        midi_events: &[MidiNoteEvent],
+        master_step_count: i64,
         master_phase: f32,
-        samples_per_step: f32,
+        swing: SwingClock,
         transport_running: bool,
     ) {
         let sr = track.sample_rate.load(Ordering::Relaxed).max(1) as f32;
@@ -1771,16 +4224,36 @@ impl TLBX1 {
             120.0
         };
 
-        // Sequencer timing
+        // Sequencer timing; the loop ends after the last page with an active step.
+        let mut max_active_step = None;
+        for i in 0..ANIMATE_SEQ_STEPS {
+            if track
+                .animate_sequencer_grid
+                .iter()
+                .any(|lane| lane[i].load(Ordering::Relaxed))
+            {
+                max_active_step = Some(i);
+            }
+        }
+        let mut loop_steps = ANIMATE_SEQ_PAGE_SIZE;
+        if let Some(max_step) = max_active_step {
+            loop_steps = ((max_step / ANIMATE_SEQ_PAGE_SIZE) + 1) * ANIMATE_SEQ_PAGE_SIZE;
+        }
+        let loop_steps_i32 = loop_steps.max(1) as i32;
         let mut sequencer_phase = if transport_running {
             master_phase
         } else {
             f32::from_bits(track.animate_sequencer_phase.load(Ordering::Relaxed))
         };
         let mut current_step = if transport_running {
-            master_step
+            (master_step_count as i32).rem_euclid(loop_steps_i32)
         } else {
-            track.animate_sequencer_step.load(Ordering::Relaxed)
+            let step = track.animate_sequencer_step.load(Ordering::Relaxed);
+            if step < 0 {
+                step
+            } else {
+                step.rem_euclid(loop_steps_i32)
+            }
         };
         if transport_running {
             track
@@ -1788,10 +4261,38 @@ impl TLBX1 {
                 .store(current_step, Ordering::Relaxed);
         }
 
-        // Animate Parameters
-        let target_x = f32::from_bits(track.animate_vector_x.load(Ordering::Relaxed));
-        let target_y = f32::from_bits(track.animate_vector_y.load(Ordering::Relaxed));
+        // Animate Parameters, with the current step's parameter locks on top.
+        let lock_base = animate_lock_base(track);
+        let mut locks = animate_step_locks(
+            track,
+            (transport_running && current_step >= 0).then_some(current_step as usize),
+            &lock_base,
+        );
         let mut x_smooth = f32::from_bits(track.animate_vector_x_smooth.load(Ordering::Relaxed));
+
+        // Vector path: recorded from the pad while armed, otherwise replayed per voice
+        // from each note's trigger.
+        let path_recording = track.animate_vector_path_recording.load(Ordering::Relaxed);
+        if path_recording && transport_running {
+            record_vector_path(track, num_buffer_samples, sr, tempo);
+        }
+        let vector_path = VectorPath::load(track);
+        let path_active = !path_recording
+            && vector_path.len > 1
+            && track.animate_vector_path_enabled.load(Ordering::Relaxed);
+        let path_tempo = f32::from_bits(track.animate_vector_path_tempo.load(Ordering::Relaxed));
+        let path_rate = if track.animate_vector_path_sync.load(Ordering::Relaxed)
+            && path_tempo.is_finite()
+            && path_tempo > 0.0
+        {
+            tempo / path_tempo
+        } else {
+            1.0
+        };
+        let path_step = path_rate / sr;
+        let mut path_pos: [f32; ANIMATE_VOICES] = std::array::from_fn(|i| {
+            f32::from_bits(track.animate_vector_path_pos[i].load(Ordering::Relaxed))
+        });
         let mut y_smooth = f32::from_bits(track.animate_vector_y_smooth.load(Ordering::Relaxed));
         let lfo_x_waveform = track.animate_lfo_x_waveform.load(Ordering::Relaxed);
         let lfo_x_sync = track.animate_lfo_x_sync.load(Ordering::Relaxed);
@@ -1847,6 +4348,10 @@ impl TLBX1 {
             )
             .clamp(0.0, 1.0),
         ];
+        let wt_position: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(track.animate_slot_wt_position[slot].load(Ordering::Relaxed))
+                .clamp(0.0, 1.0)
+        });
         let wt_lfo_shape = [
             track.animate_slot_wt_lfo_shape[0].load(Ordering::Relaxed),
             track.animate_slot_wt_lfo_shape[1].load(Ordering::Relaxed),
@@ -1937,6 +4442,8 @@ impl TLBX1 {
             f32::from_bits(track.animate_slot_loop_start[3].load(Ordering::Relaxed))
                 .clamp(0.0, 1.0),
         ];
+        let sample_default_root =
+            f32::from_bits(track.animate_sample_default_root.load(Ordering::Relaxed));
         let loop_end = [
             f32::from_bits(track.animate_slot_loop_end[0].load(Ordering::Relaxed))
                 .clamp(0.0, 1.0),
@@ -1948,160 +4455,170 @@ impl TLBX1 {
                 .clamp(0.0, 1.0),
         ];
 
-        let attack = 0.01f32;
-        let decay = 0.1f32;
-        let sustain = 0.8f32;
-        let release = 0.3f32;
-
-        let mut amp_levels = [0.0f32; 10];
-        let mut amp_stages = [0u32; 10];
-        for i in 0..10 {
+        let mut amp_levels = [0.0f32; ANIMATE_VOICES];
+        let mut amp_stages = [0u32; ANIMATE_VOICES];
+        let mut slot_amp_levels = [[0.0f32; 4]; ANIMATE_VOICES];
+        let mut slot_amp_stages = [[0u32; 4]; ANIMATE_VOICES];
+        let mut slot_filter_levels = [[0.0f32; 4]; ANIMATE_VOICES];
+        let mut slot_filter_stages = [[0u32; 4]; ANIMATE_VOICES];
+        for i in 0..ANIMATE_VOICES {
             amp_levels[i] = f32::from_bits(track.animate_amp_level[i].load(Ordering::Relaxed));
             amp_stages[i] = track.animate_amp_stage[i].load(Ordering::Relaxed);
+            for slot in 0..4 {
+                slot_amp_levels[i][slot] =
+                    f32::from_bits(track.animate_slot_amp_env_level[i][slot].load(Ordering::Relaxed));
+                slot_amp_stages[i][slot] =
+                    track.animate_slot_amp_env_stage[i][slot].load(Ordering::Relaxed);
+                slot_filter_levels[i][slot] = f32::from_bits(
+                    track.animate_slot_filter_env_level[i][slot].load(Ordering::Relaxed),
+                );
+                slot_filter_stages[i][slot] =
+                    track.animate_slot_filter_env_stage[i][slot].load(Ordering::Relaxed);
+            }
         }
 
-        let mut keybed_amp_level =
-            f32::from_bits(track.animate_keybed_amp_level.load(Ordering::Relaxed));
-        let mut keybed_amp_stage = track.animate_keybed_amp_stage.load(Ordering::Relaxed);
-        let mut keybed_phases = [
-            f32::from_bits(track.animate_keybed_slot_phases[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_phases[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_phases[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_phases[3].load(Ordering::Relaxed)),
-        ];
-        let mut keybed_sample_pos = [
-            f32::from_bits(track.animate_keybed_slot_sample_pos[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_sample_pos[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_sample_pos[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_slot_sample_pos[3].load(Ordering::Relaxed)),
-        ];
-        let mut keybed_filter_v1 = [
-            f32::from_bits(track.animate_keybed_filter_v1[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1[3].load(Ordering::Relaxed)),
-        ];
-        let mut keybed_filter_v2 = [
-            f32::from_bits(track.animate_keybed_filter_v2[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2[3].load(Ordering::Relaxed)),
-        ];
-        let mut keybed_filter_v1_stage2 = [
-            f32::from_bits(track.animate_keybed_filter_v1_stage2[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1_stage2[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1_stage2[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v1_stage2[3].load(Ordering::Relaxed)),
-        ];
-        let mut keybed_filter_v2_stage2 = [
-            f32::from_bits(track.animate_keybed_filter_v2_stage2[0].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2_stage2[1].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2_stage2[2].load(Ordering::Relaxed)),
-            f32::from_bits(track.animate_keybed_filter_v2_stage2[3].load(Ordering::Relaxed)),
-        ];
+        let midi_channel = track.midi_channel.load(Ordering::Relaxed);
+        let mut voices = AnimateVoices::load(track, sr);
+        let mut midi_event_idx = 0usize;
+        let last_sample_idx = num_buffer_samples.saturating_sub(1);
 
-        let keybed_triggered = track
-            .animate_keybed_trigger
-            .swap(false, Ordering::Relaxed);
-        let mut keybed_note = track.animate_keybed_note.load(Ordering::Relaxed);
-        let mut keybed_freq = (440.0
-            * 2.0_f32.powf((keybed_note as f32 - 69.0) / 12.0))
-            .max(1.0);
-
-        if keybed_triggered {
-            keybed_note = track.animate_keybed_note.load(Ordering::Relaxed);
-            keybed_freq = (440.0
-                * 2.0_f32.powf((keybed_note as f32 - 69.0) / 12.0))
-                .max(1.0);
-            keybed_amp_stage = 1;
-            keybed_amp_level = 0.0;
-            for slot in 0..4 {
-                if track.animate_slot_types[slot].load(Ordering::Relaxed) == 1 {
-                    let smp_idx =
-                        track.animate_slot_samples[slot].load(Ordering::Relaxed) as usize;
-                    if let Some(smp) = animate_library.get_sample_cached(smp_idx) {
-                        let len = smp.get(0).map(|ch| ch.len()).unwrap_or(0);
-                        if len > 0 {
-                            let start = (sample_start[slot]
-                                * (len.saturating_sub(1) as f32))
-                                .round()
-                                .clamp(0.0, (len.saturating_sub(1)) as f32);
-                            keybed_sample_pos[slot] = start;
-                        }
-                    }
-                }
-            }
+        let filter_drive: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(track.animate_slot_filter_drive[slot].load(Ordering::Relaxed))
+                .clamp(0.0, 1.0)
+        });
+
+        // Keybed notes play through the voice pool: a held keybed keeps its note until
+        // the next key, otherwise each key plays for one sequencer step.
+        if track.animate_keybed_trigger.swap(false, Ordering::Relaxed) {
+            let keybed_note = track.animate_keybed_note.load(Ordering::Relaxed);
+            let keybed_hold = track.animate_keybed_hold.load(Ordering::Relaxed);
+            let gate = if keybed_hold {
+                voices.note_off(&mut amp_stages, ANIMATE_SOURCE_KEYBED, None);
+                0
+            } else {
+                ((sr * 60.0 / tempo) / 4.0).max(1.0) as u32
+            };
+            let triggered = voices.note_on(
+                &mut amp_stages,
+                &amp_levels,
+                ANIMATE_SOURCE_KEYBED,
+                keybed_note,
+                1.0,
+                gate,
+            );
+            animate_reset_sample_starts(track, animate_library, &voices, &sample_start, triggered);
         }
 
-        
         // Pre-calculate envelope coefficients
         let calc_coef = |time_secs: f32| -> f32 {
             if time_secs <= 0.001 { 0.0 }
             else { (-1.0 / (time_secs * sr)).exp() }
         };
 
-        let coef_attack = calc_coef(attack);
-        let coef_decay = calc_coef(decay);
-        let coef_release = calc_coef(release);
-        let keybed_hold = track.animate_keybed_hold.load(Ordering::Relaxed);
-        let keybed_sustain = if keybed_hold { 1.0f32 } else { 0.0f32 };
-
-        let frequencies = [
-            65.41, 73.42, 82.41, 98.00, 110.00, // C2, D2, E2, G2, A2
-            130.81, 146.83, 164.81, 196.00, 220.00, // C3, D3, E3, G3, A3
-        ];
+        let mut slot_env = [[0.0f32; ANIMATE_SLOT_ENV_PARAMS]; 4];
+        for slot in 0..4 {
+            for param in 0..ANIMATE_SLOT_ENV_PARAMS {
+                let (min, max) = animate_slot_env_range(param);
+                slot_env[slot][param] = f32::from_bits(
+                    animate_slot_env_param(track, param)[slot].load(Ordering::Relaxed),
+                )
+                .clamp(min, max);
+            }
+        }
+        let slot_amp_coefs: [[f32; 3]; 4] = std::array::from_fn(|slot| {
+            [
+                calc_coef(slot_env[slot][0]),
+                calc_coef(slot_env[slot][1]),
+                calc_coef(slot_env[slot][3]),
+            ]
+        });
+        let slot_filter_coefs: [[f32; 3]; 4] = std::array::from_fn(|slot| {
+            [
+                calc_coef(slot_env[slot][4]),
+                calc_coef(slot_env[slot][5]),
+                calc_coef(slot_env[slot][7]),
+            ]
+        });
 
         let num_channels = track_output.len();
         let output = track_output;
 
         for sample_idx in 0..num_buffer_samples {
+            // External MIDI notes, dispatched at their exact sample offset.
+            while midi_event_idx < midi_events.len()
+                && (midi_events[midi_event_idx].timing as usize).min(last_sample_idx) <= sample_idx
+            {
+                let event = midi_events[midi_event_idx];
+                midi_event_idx += 1;
+                if midi_channel != 0 && event.channel as u32 + 1 != midi_channel {
+                    continue;
+                }
+                let note = event.note as i32;
+                if event.is_on {
+                    let triggered = voices.note_on(
+                        &mut amp_stages,
+                        &amp_levels,
+                        ANIMATE_SOURCE_MIDI,
+                        note,
+                        event.velocity.clamp(0.0, 1.0),
+                        0,
+                    );
+                    animate_reset_sample_starts(track, animate_library, &voices, &sample_start, triggered);
+                } else {
+                    voices.note_off(&mut amp_stages, ANIMATE_SOURCE_MIDI, Some(note));
+                }
+            }
+
             // Step sequencer (master-synced)
             if transport_running {
                 sequencer_phase += 1.0;
-                if sequencer_phase >= samples_per_step {
-                    sequencer_phase -= samples_per_step;
-                    current_step = (current_step + 1).rem_euclid(16);
+                let step_len = swing.step_len(current_step as i64);
+                if sequencer_phase >= step_len {
+                    sequencer_phase -= step_len;
+                    current_step = (current_step + 1).rem_euclid(loop_steps_i32);
                     track
                         .animate_sequencer_step
                         .store(current_step, Ordering::Relaxed);
 
-                    // Update envelope stages for all voices based on grid
-                    for row in 0..10 {
-                        let note_active = track.animate_sequencer_grid[row * 16 + current_step as usize]
-                            .load(Ordering::Relaxed);
-                        if note_active {
-                            if amp_stages[row] == 0 || amp_stages[row] == 4 {
-                                amp_stages[row] = 1; // Attack
-                                for slot in 0..4 {
-                                    if track.animate_slot_types[slot].load(Ordering::Relaxed) == 1 {
-                                        let smp_idx =
-                                            track.animate_slot_samples[slot].load(Ordering::Relaxed)
-                                                as usize;
-                                        if let Some(smp) = animate_library.get_sample_cached(smp_idx) {
-                                            let len = smp.get(0).map(|ch| ch.len()).unwrap_or(0);
-                                            if len > 0 {
-                                                let start = (sample_start[slot]
-                                                    * (len.saturating_sub(1) as f32))
-                                                    .round()
-                                                    .clamp(0.0, (len.saturating_sub(1)) as f32);
-                                                track.animate_slot_sample_pos[row][slot]
-                                                    .store(start.to_bits(), Ordering::Relaxed);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        } else if amp_stages[row] != 0 && amp_stages[row] != 4 {
-                            amp_stages[row] = 4; // Release
+                    // Each lane plays its step through the voice pool; a lane still
+                    // holding its voice from the previous step ties into the new note.
+                    let step_idx = current_step as usize;
+                    locks = animate_step_locks(track, Some(step_idx), &lock_base);
+                    for row in 0..ANIMATE_SEQ_VOICES {
+                        let source = row as u32;
+                        let note_active =
+                            track.animate_sequencer_grid[row][step_idx].load(Ordering::Relaxed);
+                        if !note_active {
+                            voices.note_off(&mut amp_stages, source, None);
+                            continue;
+                        }
+                        let note = animate_resolve_step_note(track, row, step_idx) as i32;
+                        if voices.held(&amp_stages, source) {
+                            voices.retune(&amp_stages, source, note);
+                        } else {
+                            let triggered =
+                                voices.note_on(&mut amp_stages, &amp_levels, source, note, 1.0, 0);
+                            animate_reset_sample_starts(
+                                track,
+                                animate_library,
+                                &voices,
+                                &sample_start,
+                                triggered,
+                            );
                         }
                     }
                 }
             }
+            voices.tick(&mut amp_stages);
 
-            let mut wt_lfo_values = [0.0f32; 4];
+            // Wavetable position per slot, pushed up from the knob by the wavetable LFO.
+            let mut wt_positions = [0.0f32; 4];
             for slot in 0..4 {
-                wt_lfo_values[slot] =
+                let lfo_value =
                     lfo_waveform_value(wt_lfo_shape[slot], wt_lfo_phase[slot], wt_lfo_snh[slot]);
+                wt_positions[slot] = (wt_position[slot]
+                    + (lfo_value * 0.5 + 0.5) * wt_lfo_amount[slot])
+                    .clamp(0.0, 1.0);
                 wt_lfo_phase[slot] += wt_lfo_rate_hz[slot] / sr;
                 if wt_lfo_phase[slot] >= 1.0 {
                     wt_lfo_phase[slot] -= 1.0;
@@ -2112,80 +4629,61 @@ impl TLBX1 {
                 }
             }
 
-            // Process Envelopes for all voices
-            for i in 0..10 {
+            // Voice gates drive each slot's amp and filter envelopes. A triggered voice
+            // (stage 1) holds at stage 2 until released, and goes idle once every slot's
+            // amp envelope has finished its release.
+            for i in 0..ANIMATE_VOICES {
                 match amp_stages[i] {
-                    1 => { // Attack
-                        amp_levels[i] = amp_levels[i] * coef_attack + 1.1 * (1.0 - coef_attack);
-                        if amp_levels[i] >= 1.0 {
-                            amp_levels[i] = 1.0;
-                            amp_stages[i] = 2;
-                        }
+                    1 => {
+                        slot_amp_stages[i] = [1; 4];
+                        slot_filter_stages[i] = [1; 4];
+                        amp_stages[i] = 2;
+                        path_pos[i] = 0.0;
                     }
-                    2 => { // Decay
-                        amp_levels[i] = amp_levels[i] * coef_decay + sustain * (1.0 - coef_decay);
-                        if (amp_levels[i] - sustain).abs() < 0.001 {
-                            amp_levels[i] = sustain;
-                            amp_stages[i] = 3;
-                        }
-                    }
-                    3 => { // Sustain
-                        amp_levels[i] = sustain;
-                    }
-                    4 => { // Release
-                        amp_levels[i] = amp_levels[i] * coef_release;
-                        if amp_levels[i] < 0.0001 {
-                            amp_levels[i] = 0.0;
-                            amp_stages[i] = 0;
+                    4 => {
+                        for slot in 0..4 {
+                            if (1..=3).contains(&slot_amp_stages[i][slot]) {
+                                slot_amp_stages[i][slot] = 4;
+                            }
+                            if (1..=3).contains(&slot_filter_stages[i][slot]) {
+                                slot_filter_stages[i][slot] = 4;
+                            }
                         }
                     }
-                    _ => {
-                        amp_levels[i] = 0.0;
-                    }
-                }
-            }
-
-            match keybed_amp_stage {
-                1 => {
-                    keybed_amp_level =
-                        keybed_amp_level * coef_attack + 1.1 * (1.0 - coef_attack);
-                    if keybed_amp_level >= 1.0 {
-                        keybed_amp_level = 1.0;
-                        keybed_amp_stage = 2;
-                    }
-                }
-                2 => {
-                    keybed_amp_level =
-                        keybed_amp_level * coef_decay + keybed_sustain * (1.0 - coef_decay);
-                    if (keybed_amp_level - keybed_sustain).abs() < 0.001 {
-                        keybed_amp_level = keybed_sustain;
-                        keybed_amp_stage = if keybed_hold { 3 } else { 4 };
-                    }
+                    _ => {}
                 }
-                3 => {
-                    if keybed_hold {
-                        keybed_amp_level = keybed_sustain;
-                    } else {
-                        keybed_amp_stage = 4;
-                    }
+                let mut level = 0.0f32;
+                for slot in 0..4 {
+                    adsr_tick(
+                        &mut slot_amp_stages[i][slot],
+                        &mut slot_amp_levels[i][slot],
+                        slot_amp_coefs[slot],
+                        slot_env[slot][2],
+                    );
+                    adsr_tick(
+                        &mut slot_filter_stages[i][slot],
+                        &mut slot_filter_levels[i][slot],
+                        slot_filter_coefs[slot],
+                        slot_env[slot][6],
+                    );
+                    level = level.max(slot_amp_levels[i][slot]);
                 }
-                4 => {
-                    keybed_amp_level = keybed_amp_level * coef_release;
-                    if keybed_amp_level < 0.0001 {
-                        keybed_amp_level = 0.0;
-                        keybed_amp_stage = 0;
-                    }
+                amp_levels[i] = level;
+                if amp_stages[i] == 4 && slot_amp_stages[i].iter().all(|stage| *stage == 0) {
+                    amp_stages[i] = 0;
                 }
-                _ => {
-                    keybed_amp_level = 0.0;
+                if path_active && amp_stages[i] != 0 {
+                    path_pos[i] = vector_path.wrap(path_pos[i] + path_step);
                 }
             }
 
             // Smooth vector position
             let lfo_x_value = lfo_waveform_value(lfo_x_waveform, lfo_x_phase, lfo_x_snh);
             let lfo_y_value = lfo_waveform_value(lfo_y_waveform, lfo_y_phase, lfo_y_snh);
-            let target_x_mod = (target_x + lfo_x_value * lfo_x_amount).clamp(0.0, 1.0);
-            let target_y_mod = (target_y + lfo_y_value * lfo_y_amount).clamp(0.0, 1.0);
+            let target_x_mod =
+                (locks[ANIMATE_LOCK_VECTOR_X] + lfo_x_value * lfo_x_amount).clamp(0.0, 1.0);
+            let target_y_mod =
+                (locks[ANIMATE_LOCK_VECTOR_Y] + lfo_y_value * lfo_y_amount).clamp(0.0, 1.0);
             x_smooth = x_smooth * 0.999 + target_x_mod * 0.001;
             y_smooth = y_smooth * 0.999 + target_y_mod * 0.001;
 
@@ -2215,16 +4713,31 @@ impl TLBX1 {
             let mut mixed_sample_r = 0.0f32;
 
             // Sum active voices
-            for row in 0..10 {
+            for row in 0..ANIMATE_VOICES {
                 if amp_levels[row] <= 0.0 {
                     continue;
                 }
                 
-                let base_freq = frequencies[row as usize];
-                
+                let voice_weights = if path_active {
+                    let (path_x, path_y) = vector_path.position(path_pos[row]);
+                    let vx = (path_x + lfo_x_value * lfo_x_amount).clamp(0.0, 1.0);
+                    let vy = (path_y + lfo_y_value * lfo_y_amount).clamp(0.0, 1.0);
+                    [
+                        (1.0 - vx) * (1.0 - vy),
+                        vx * (1.0 - vy),
+                        (1.0 - vx) * vy,
+                        vx * vy,
+                    ]
+                } else {
+                    weights
+                };
+
+                let base_freq = midi_pitch_frequency(voices.pitch[row] + voices.offset[row]);
+                let voice_gain = voices.velocity[row];
+
                 for slot in 0..4 {
                     let slot_type = track.animate_slot_types[slot].load(Ordering::Relaxed);
-                    let coarse = f32::from_bits(track.animate_slot_coarse[slot].load(Ordering::Relaxed));
+                    let coarse = locks[ANIMATE_LOCK_SLOT_COARSE + slot];
                     let fine = f32::from_bits(track.animate_slot_fine[slot].load(Ordering::Relaxed));
                     let pitch_ratio = 2.0f32.powf((coarse + fine / 100.0) / 12.0);
                     let freq = base_freq * pitch_ratio;
@@ -2234,38 +4747,17 @@ impl TLBX1 {
                         let wt_idx =
                             track.animate_slot_wavetables[slot].load(Ordering::Relaxed) as usize;
                         if let Some(wt) = animate_library.get_wavetable_cached(wt_idx) {
-                            if !wt.is_empty() {
-                                let phase = f32::from_bits(track.animate_slot_phases[row][slot].load(Ordering::Relaxed));
-                                // Use first cycle (2048 samples)
-                                let cycle_len = wt.len().min(2048);
-                                if cycle_len > 0 {
-                                    let num_cycles = wt.len() / cycle_len;
-                                    let cycle_offset = if num_cycles > 1 && wt_lfo_amount[slot] > 0.0 {
-                                        let lfo_pos = (wt_lfo_values[slot] * 0.5 + 0.5).clamp(0.0, 1.0);
-                                        let max_idx = (num_cycles - 1) as f32;
-                                        (lfo_pos * wt_lfo_amount[slot] * max_idx).round() as usize
-                                    } else {
-                                        0
-                                    };
-                                    let base = cycle_offset * cycle_len;
-                                    let pos = phase * (cycle_len - 1) as f32;
-                                    let idx = pos as usize;
-                                    let frac = pos - idx as f32;
-                                    let s1 = wt[base + idx];
-                                    let s2 = wt[base + ((idx + 1) % cycle_len)];
-                                    slot_sample = s1 + (s2 - s1) * frac;
-                                }
-
-                                let new_phase = (phase + freq / sr) % 1.0;
-                                track.animate_slot_phases[row][slot].store(new_phase.to_bits(), Ordering::Relaxed);
-                            }
+                            let phase = f32::from_bits(track.animate_slot_phases[row][slot].load(Ordering::Relaxed));
+                            slot_sample = wt.read(wt_positions[slot], phase, freq, sr);
+                            let new_phase = (phase + freq / sr) % 1.0;
+                            track.animate_slot_phases[row][slot].store(new_phase.to_bits(), Ordering::Relaxed);
                         }
                     } else { // Sample
                         let smp_idx =
-                            track.animate_slot_samples[slot].load(Ordering::Relaxed) as usize;
+                            track.animate_slot_voice_sample[row][slot].load(Ordering::Relaxed) as usize;
                         if let Some(smp) = animate_library.get_sample_cached(smp_idx) {
-                            if !smp.is_empty() && !smp[0].is_empty() {
-                                let len = smp[0].len();
+                            if !smp.channels.is_empty() && !smp.channels[0].is_empty() {
+                                let len = smp.channels[0].len();
                                 if len > 0 {
                                     let mut pos =
                                         f32::from_bits(track.animate_slot_sample_pos[row][slot].load(Ordering::Relaxed));
@@ -2290,8 +4782,13 @@ impl TLBX1 {
                                         idx = loop_start_idx;
                                     }
                                     if idx < len {
-                                        slot_sample = smp[0][idx];
-                                        let mut new_pos = pos + (freq / 440.0); // Rough sample playback
+                                        slot_sample = smp.channels[0][idx];
+                                        // Plays at the original pitch on the sample's root key.
+                                        let root = smp.root.unwrap_or(sample_default_root);
+                                        let rate = freq / midi_pitch_frequency(root)
+                                            * smp.sample_rate
+                                            / sr;
+                                        let mut new_pos = pos + rate;
                                         if new_pos >= loop_end_idx as f32 {
                                             new_pos = loop_start_idx as f32;
                                         }
@@ -2305,223 +4802,98 @@ impl TLBX1 {
 
                     let filter_type =
                         track.animate_slot_filter_type[slot].load(Ordering::Relaxed) as u32;
-                    let filter_cutoff = f32::from_bits(
-                        track.animate_slot_filter_cutoff[slot].load(Ordering::Relaxed),
-                    )
-                    .clamp(0.001, 1.0);
-                    let filter_resonance = f32::from_bits(
-                        track.animate_slot_filter_resonance[slot].load(Ordering::Relaxed),
-                    )
-                    .clamp(0.0, 0.95);
+                    let filter_cutoff = (locks[ANIMATE_LOCK_SLOT_CUTOFF + slot]
+                        + slot_env[slot][8] * slot_filter_levels[row][slot])
+                        .clamp(0.001, 1.0);
+                    let filter_resonance =
+                        locks[ANIMATE_LOCK_SLOT_RESONANCE + slot].clamp(0.0, 0.95);
                     let cutoff_hz = 20.0 + filter_cutoff.powf(2.0) * (20_000.0 - 20.0);
-                    let filter_f =
-                        (2.0 * (std::f32::consts::PI * cutoff_hz / sr).sin()).clamp(0.0, 0.99);
-                    let filter_q = 1.0 - filter_resonance;
-                    let filter_v1 = f32::from_bits(
-                        track.animate_slot_filter_v1[row][slot].load(Ordering::Relaxed),
-                    );
-                    let filter_v2 = f32::from_bits(
-                        track.animate_slot_filter_v2[row][slot].load(Ordering::Relaxed),
-                    );
-                    let filter_v1_stage2 = f32::from_bits(
-                        track
-                            .animate_slot_filter_v1_stage2[row][slot]
-                            .load(Ordering::Relaxed),
-                    );
-                    let filter_v2_stage2 = f32::from_bits(
-                        track
-                            .animate_slot_filter_v2_stage2[row][slot]
-                            .load(Ordering::Relaxed),
-                    );
-                    let filter_low = filter_v2 + filter_f * filter_v1;
-                    let filter_high = slot_sample - filter_low - filter_q * filter_v1;
-                    let filter_band = filter_f * filter_high + filter_v1;
-                    let filter_low_stage2 = filter_v2_stage2 + filter_f * filter_v1_stage2;
-                    let filter_high_stage2 = filter_low - filter_low_stage2 - filter_q * filter_v1_stage2;
-                    let filter_band_stage2 = filter_f * filter_high_stage2 + filter_v1_stage2;
-                    let mut filtered_sample = match filter_type {
-                        0 => filter_low_stage2,
-                        1 => filter_low,
-                        2 => filter_high,
-                        3 => filter_band,
-                        _ => filter_low,
-                    };
-                    if !filtered_sample.is_finite() {
-                        filtered_sample = slot_sample;
-                        track.animate_slot_filter_v1[row][slot]
-                            .store(0.0f32.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v2[row][slot]
-                            .store(0.0f32.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v1_stage2[row][slot]
-                            .store(0.0f32.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v2_stage2[row][slot]
-                            .store(0.0f32.to_bits(), Ordering::Relaxed);
-                    } else {
-                        track.animate_slot_filter_v1[row][slot]
-                            .store(filter_band.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v2[row][slot]
-                            .store(filter_low.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v1_stage2[row][slot]
-                            .store(filter_band_stage2.to_bits(), Ordering::Relaxed);
-                        track.animate_slot_filter_v2_stage2[row][slot]
-                            .store(filter_low_stage2.to_bits(), Ordering::Relaxed);
-                    }
-                    slot_sample = filtered_sample;
-
-                    let level = f32::from_bits(track.animate_slot_level[slot].load(Ordering::Relaxed)) * weights[slot] * amp_levels[row];
-                    let pan = f32::from_bits(track.animate_slot_pan[slot].load(Ordering::Relaxed)).clamp(-1.0, 1.0);
-                    
-                    let left_gain = (1.0 - pan).min(1.0);
-                    let right_gain = (1.0 + pan).min(1.0);
-                    
-                    mixed_sample_l += slot_sample * level * left_gain;
-                    mixed_sample_r += slot_sample * level * right_gain;
-                }
-            }
-
-            if keybed_amp_level > 0.0 {
-                for slot in 0..4 {
-                    let slot_type = track.animate_slot_types[slot].load(Ordering::Relaxed);
-                    let coarse =
-                        f32::from_bits(track.animate_slot_coarse[slot].load(Ordering::Relaxed));
-                    let fine =
-                        f32::from_bits(track.animate_slot_fine[slot].load(Ordering::Relaxed));
-                    let pitch_ratio = 2.0f32.powf((coarse + fine / 100.0) / 12.0);
-                    let freq = keybed_freq * pitch_ratio;
-
-                    let mut slot_sample = 0.0f32;
-                    if slot_type == 0 {
-                        let wt_idx =
-                            track.animate_slot_wavetables[slot].load(Ordering::Relaxed) as usize;
-                        if let Some(wt) = animate_library.get_wavetable_cached(wt_idx) {
-                            if !wt.is_empty() {
-                                let phase = keybed_phases[slot];
-                                let cycle_len = wt.len().min(2048);
-                                if cycle_len > 0 {
-                                    let num_cycles = wt.len() / cycle_len;
-                                    let cycle_offset = if num_cycles > 1
-                                        && wt_lfo_amount[slot] > 0.0
-                                    {
-                                        let lfo_pos =
-                                            (wt_lfo_values[slot] * 0.5 + 0.5).clamp(0.0, 1.0);
-                                        let max_idx = (num_cycles - 1) as f32;
-                                        (lfo_pos * wt_lfo_amount[slot] * max_idx).round() as usize
-                                    } else {
-                                        0
-                                    };
-                                    let base = cycle_offset * cycle_len;
-                                    let pos = phase * (cycle_len - 1) as f32;
-                                    let idx = pos as usize;
-                                    let frac = pos - idx as f32;
-                                    let s1 = wt[base + idx];
-                                    let s2 = wt[base + ((idx + 1) % cycle_len)];
-                                    slot_sample = s1 + (s2 - s1) * frac;
-                                }
-                                keybed_phases[slot] = (phase + freq / sr) % 1.0;
-                            }
+                    let filtered_sample = if filter_type == ANIMATE_FILTER_MOOG {
+                        let ladder = &track.animate_slot_ladder_state[row][slot];
+                        let mut state: [f32; 4] =
+                            std::array::from_fn(|i| f32::from_bits(ladder[i].load(Ordering::Relaxed)));
+                        let mut out = ladder_filter_tick(
+                            &mut state,
+                            slot_sample,
+                            cutoff_hz,
+                            filter_resonance,
+                            filter_drive[slot],
+                            sr,
+                        );
+                        if !out.is_finite() {
+                            out = slot_sample;
+                            state = [0.0; 4];
+                        }
+                        for (stage, value) in ladder.iter().zip(state) {
+                            stage.store(value.to_bits(), Ordering::Relaxed);
                         }
+                        out
                     } else {
-                        let smp_idx =
-                            track.animate_slot_samples[slot].load(Ordering::Relaxed) as usize;
-                        if let Some(smp) = animate_library.get_sample_cached(smp_idx) {
-                            if !smp.is_empty() && !smp[0].is_empty() {
-                                let len = smp[0].len();
-                                if len > 0 {
-                                    let mut pos = keybed_sample_pos[slot];
-                                    let start_idx = (sample_start[slot]
-                                        * (len.saturating_sub(1) as f32))
-                                        .round()
-                                        .clamp(0.0, (len.saturating_sub(1)) as f32)
-                                        as usize;
-                                    let mut loop_start_idx = (loop_start[slot]
-                                        * (len.saturating_sub(1) as f32))
-                                        .round()
-                                        .clamp(0.0, (len.saturating_sub(1)) as f32)
-                                        as usize;
-                                    let mut loop_end_idx = (loop_end[slot]
-                                        * (len.saturating_sub(1) as f32))
-                                        .round()
-                                        .clamp(0.0, (len.saturating_sub(1)) as f32)
-                                        as usize;
-                                    if loop_end_idx <= loop_start_idx {
-                                        loop_start_idx = start_idx.min(len.saturating_sub(1));
-                                        loop_end_idx = len.saturating_sub(1);
-                                    }
-                                    let mut idx = pos as usize;
-                                    if idx >= loop_end_idx {
-                                        pos = loop_start_idx as f32;
-                                        idx = loop_start_idx;
-                                    }
-                                    if idx < len {
-                                        slot_sample = smp[0][idx];
-                                        let mut new_pos = pos + (freq / 440.0);
-                                        if new_pos >= loop_end_idx as f32 {
-                                            new_pos = loop_start_idx as f32;
-                                        }
-                                        keybed_sample_pos[slot] = new_pos;
-                                    }
-                                }
-                            }
+                        let filter_f =
+                            (2.0 * (std::f32::consts::PI * cutoff_hz / sr).sin()).clamp(0.0, 0.99);
+                        let filter_q = 1.0 - filter_resonance;
+                        let filter_v1 = f32::from_bits(
+                            track.animate_slot_filter_v1[row][slot].load(Ordering::Relaxed),
+                        );
+                        let filter_v2 = f32::from_bits(
+                            track.animate_slot_filter_v2[row][slot].load(Ordering::Relaxed),
+                        );
+                        let filter_v1_stage2 = f32::from_bits(
+                            track
+                                .animate_slot_filter_v1_stage2[row][slot]
+                                .load(Ordering::Relaxed),
+                        );
+                        let filter_v2_stage2 = f32::from_bits(
+                            track
+                                .animate_slot_filter_v2_stage2[row][slot]
+                                .load(Ordering::Relaxed),
+                        );
+                        let filter_low = filter_v2 + filter_f * filter_v1;
+                        let filter_high = slot_sample - filter_low - filter_q * filter_v1;
+                        let filter_band = filter_f * filter_high + filter_v1;
+                        let filter_low_stage2 = filter_v2_stage2 + filter_f * filter_v1_stage2;
+                        let filter_high_stage2 = filter_low - filter_low_stage2 - filter_q * filter_v1_stage2;
+                        let filter_band_stage2 = filter_f * filter_high_stage2 + filter_v1_stage2;
+                        let mut filtered_sample = match filter_type {
+                            0 => filter_low_stage2,
+                            1 => filter_low,
+                            2 => filter_high,
+                            3 => filter_band,
+                            _ => filter_low,
+                        };
+                        if !filtered_sample.is_finite() {
+                            filtered_sample = slot_sample;
+                            track.animate_slot_filter_v1[row][slot]
+                                .store(0.0f32.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v2[row][slot]
+                                .store(0.0f32.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v1_stage2[row][slot]
+                                .store(0.0f32.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v2_stage2[row][slot]
+                                .store(0.0f32.to_bits(), Ordering::Relaxed);
+                        } else {
+                            track.animate_slot_filter_v1[row][slot]
+                                .store(filter_band.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v2[row][slot]
+                                .store(filter_low.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v1_stage2[row][slot]
+                                .store(filter_band_stage2.to_bits(), Ordering::Relaxed);
+                            track.animate_slot_filter_v2_stage2[row][slot]
+                                .store(filter_low_stage2.to_bits(), Ordering::Relaxed);
                         }
-                    }
-
-                    let filter_type =
-                        track.animate_slot_filter_type[slot].load(Ordering::Relaxed) as u32;
-                    let filter_cutoff = f32::from_bits(
-                        track.animate_slot_filter_cutoff[slot].load(Ordering::Relaxed),
-                    )
-                    .clamp(0.001, 1.0);
-                    let filter_resonance = f32::from_bits(
-                        track.animate_slot_filter_resonance[slot].load(Ordering::Relaxed),
-                    )
-                    .clamp(0.0, 0.95);
-                    let cutoff_hz = 20.0 + filter_cutoff.powf(2.0) * (20_000.0 - 20.0);
-                    let filter_f =
-                        (2.0 * (std::f32::consts::PI * cutoff_hz / sr).sin()).clamp(0.0, 0.99);
-                    let filter_q = 1.0 - filter_resonance;
-                    let mut filter_v1 = keybed_filter_v1[slot];
-                    let mut filter_v2 = keybed_filter_v2[slot];
-                    let mut filter_v1_stage2 = keybed_filter_v1_stage2[slot];
-                    let mut filter_v2_stage2 = keybed_filter_v2_stage2[slot];
-                    let filter_low = filter_v2 + filter_f * filter_v1;
-                    let filter_high = slot_sample - filter_low - filter_q * filter_v1;
-                    let filter_band = filter_f * filter_high + filter_v1;
-                    let filter_low_stage2 = filter_v2_stage2 + filter_f * filter_v1_stage2;
-                    let filter_high_stage2 =
-                        filter_low - filter_low_stage2 - filter_q * filter_v1_stage2;
-                    let filter_band_stage2 = filter_f * filter_high_stage2 + filter_v1_stage2;
-                    let mut filtered_sample = match filter_type {
-                        0 => filter_low_stage2,
-                        1 => filter_low,
-                        2 => filter_high,
-                        3 => filter_band,
-                        _ => filter_low,
+                        filtered_sample
                     };
-                    if !filtered_sample.is_finite() {
-                        filtered_sample = slot_sample;
-                        keybed_filter_v1[slot] = 0.0;
-                        keybed_filter_v2[slot] = 0.0;
-                        keybed_filter_v1_stage2[slot] = 0.0;
-                        keybed_filter_v2_stage2[slot] = 0.0;
-                    } else {
-                        filter_v1 = filter_band;
-                        filter_v2 = filter_low;
-                        filter_v1_stage2 = filter_band_stage2;
-                        filter_v2_stage2 = filter_low_stage2;
-                        keybed_filter_v1[slot] = filter_v1;
-                        keybed_filter_v2[slot] = filter_v2;
-                        keybed_filter_v1_stage2[slot] = filter_v1_stage2;
-                        keybed_filter_v2_stage2[slot] = filter_v2_stage2;
-                    }
                     slot_sample = filtered_sample;
 
-                    let level = f32::from_bits(track.animate_slot_level[slot].load(Ordering::Relaxed))
-                        * weights[slot]
-                        * keybed_amp_level;
-                    let pan = f32::from_bits(track.animate_slot_pan[slot].load(Ordering::Relaxed))
-                        .clamp(-1.0, 1.0);
+                    let level = locks[ANIMATE_LOCK_SLOT_LEVEL + slot]
+                        * voice_weights[slot]
+                        * slot_amp_levels[row][slot]
+                        * voice_gain;
+                    let pan = f32::from_bits(track.animate_slot_pan[slot].load(Ordering::Relaxed)).clamp(-1.0, 1.0);
+                    
                     let left_gain = (1.0 - pan).min(1.0);
                     let right_gain = (1.0 + pan).min(1.0);
+                    
                     mixed_sample_l += slot_sample * level * left_gain;
                     mixed_sample_r += slot_sample * level * right_gain;
                 }
@@ -2561,36 +4933,28 @@ impl TLBX1 {
             track.animate_slot_wt_lfo_snh[slot]
                 .store(wt_lfo_snh[slot].to_bits(), Ordering::Relaxed);
         }
-        for i in 0..10 {
+        for i in 0..ANIMATE_VOICES {
             track.animate_amp_stage[i].store(amp_stages[i], Ordering::Relaxed);
-            track.animate_amp_level[i].store(amp_levels[i].to_bits(), Ordering::Relaxed);
-        }
-        track
-            .animate_keybed_amp_stage
-            .store(keybed_amp_stage, Ordering::Relaxed);
-        track
-            .animate_keybed_amp_level
-            .store(keybed_amp_level.to_bits(), Ordering::Relaxed);
-        for slot in 0..4 {
-            track
-                .animate_keybed_slot_phases[slot]
-                .store(keybed_phases[slot].to_bits(), Ordering::Relaxed);
-            track
-                .animate_keybed_slot_sample_pos[slot]
-                .store(keybed_sample_pos[slot].to_bits(), Ordering::Relaxed);
-            track
-                .animate_keybed_filter_v1[slot]
-                .store(keybed_filter_v1[slot].to_bits(), Ordering::Relaxed);
             track
-                .animate_keybed_filter_v2[slot]
-                .store(keybed_filter_v2[slot].to_bits(), Ordering::Relaxed);
-            track
-                .animate_keybed_filter_v1_stage2[slot]
-                .store(keybed_filter_v1_stage2[slot].to_bits(), Ordering::Relaxed);
-            track
-                .animate_keybed_filter_v2_stage2[slot]
-                .store(keybed_filter_v2_stage2[slot].to_bits(), Ordering::Relaxed);
+                .animate_vector_path_pos[i]
+                .store(path_pos[i].to_bits(), Ordering::Relaxed);
+            track.animate_amp_level[i].store(amp_levels[i].to_bits(), Ordering::Relaxed);
+            for slot in 0..4 {
+                track
+                    .animate_slot_amp_env_stage[i][slot]
+                    .store(slot_amp_stages[i][slot], Ordering::Relaxed);
+                track
+                    .animate_slot_amp_env_level[i][slot]
+                    .store(slot_amp_levels[i][slot].to_bits(), Ordering::Relaxed);
+                track
+                    .animate_slot_filter_env_stage[i][slot]
+                    .store(slot_filter_stages[i][slot], Ordering::Relaxed);
+                track
+                    .animate_slot_filter_env_level[i][slot]
+                    .store(slot_filter_levels[i][slot].to_bits(), Ordering::Relaxed);
+            }
         }
+        voices.store(track);
     }
 
     fn process_syndrm(
@@ -2602,16 +4966,23 @@ impl TLBX1 {
         _master_step: i32,
         master_phase: f32,
         master_step_count: i64,
-        samples_per_step: f32,
+        swing: SwingClock,
         sample_rate: f32,
         transport_running: bool,
     ) {
         let sr = sample_rate.max(1.0);
         dsp_state.set_sample_rate(sr);
+        if !transport_running {
+            dsp_state.clear_pending_hits();
+        }
         let mut max_active_step = None;
         for i in 0..SYNDRM_STEPS {
             if track.kick_sequencer_grid[i].load(Ordering::Relaxed)
                 || track.snare_sequencer_grid[i].load(Ordering::Relaxed)
+                || track
+                    .syndrm_voice_grid
+                    .iter()
+                    .any(|grid| grid[i].load(Ordering::Relaxed))
             {
                 max_active_step = Some(i);
             }
@@ -2639,7 +5010,20 @@ impl TLBX1 {
         if transport_running {
             track.kick_sequencer_step.store(current_step, Ordering::Relaxed);
             track.snare_sequencer_step.store(current_step, Ordering::Relaxed);
+            // Step boundaries schedule one step ahead, so the first step needs its hits now.
+            if !dsp_state.was_running {
+                let next_idx = (current_step + 1).rem_euclid(loop_steps_i32) as usize;
+                dsp_state.schedule_step_hits(
+                    track,
+                    current_step as usize,
+                    next_idx,
+                    swing.step_len(current_step as i64),
+                    swing.step_len(current_step as i64 + 1),
+                    Some(sequencer_phase),
+                );
+            }
         }
+        dsp_state.was_running = transport_running;
 
         let mut kick_pitch_base =
             f32::from_bits(track.kick_pitch.load(Ordering::Relaxed)).clamp(0.0, 1.0);
@@ -2743,6 +5127,51 @@ impl TLBX1 {
         let mut snare_cutoff_hz = cutoff_min * cutoff_span.powf(snare_filter_cutoff);
         let mut snare_q = 0.1 + snare_filter_resonance * 0.9;
         let mut snare_drive_gain = 1.0 + snare_drive * 8.0;
+        let mut voice_params_base: [[f32; SYNDRM_VOICE_PARAMS]; SYNDRM_VOICES] =
+            std::array::from_fn(|v| {
+                std::array::from_fn(|p| {
+                    f32::from_bits(track.syndrm_voice_params[v][p].load(Ordering::Relaxed))
+                        .clamp(0.0, 1.0)
+                })
+            });
+        let mut voice_shapes: [SynDRMVoiceShape; SYNDRM_VOICES] = std::array::from_fn(|v| {
+            SynDRMVoiceShape::new(v, &voice_params_base[v], sr, track_muted)
+        });
+
+        fn apply_voice_step_params(
+            track: &Track,
+            step_idx: usize,
+            step_hold: bool,
+            track_muted: bool,
+            sr: f32,
+            voice_params_base: &mut [[f32; SYNDRM_VOICE_PARAMS]; SYNDRM_VOICES],
+            voice_shapes: &mut [SynDRMVoiceShape; SYNDRM_VOICES],
+        ) {
+            for voice in 0..SYNDRM_VOICES {
+                let override_enabled = track.syndrm_voice_step_override_enabled[voice][step_idx]
+                    .load(Ordering::Relaxed);
+                let params = if override_enabled {
+                    let step_params: [f32; SYNDRM_VOICE_PARAMS] = std::array::from_fn(|p| {
+                        f32::from_bits(
+                            track.syndrm_voice_step_params[voice][step_idx][p]
+                                .load(Ordering::Relaxed),
+                        )
+                        .clamp(0.0, 1.0)
+                    });
+                    if step_hold {
+                        voice_params_base[voice] = step_params;
+                        for (p, value) in step_params.iter().enumerate() {
+                            track.syndrm_voice_params[voice][p]
+                                .store(value.to_bits(), Ordering::Relaxed);
+                        }
+                    }
+                    step_params
+                } else {
+                    voice_params_base[voice]
+                };
+                voice_shapes[voice] = SynDRMVoiceShape::new(voice, &params, sr, track_muted);
+            }
+        }
 
         fn apply_kick_step_params(
             track: &Track,
@@ -3114,6 +5543,15 @@ impl TLBX1 {
                     &mut snare_q,
                     &mut snare_drive_gain,
                 );
+                apply_voice_step_params(
+                    track,
+                    step_idx,
+                    step_hold,
+                    track_muted,
+                    sr,
+                    &mut voice_params_base,
+                    &mut voice_shapes,
+                );
             }
         }
 
@@ -3122,8 +5560,9 @@ impl TLBX1 {
         for sample_idx in 0..num_buffer_samples {
             if transport_running {
                 sequencer_phase += 1.0;
-                if sequencer_phase >= samples_per_step {
-                    sequencer_phase -= samples_per_step;
+                let step_len = swing.step_len(current_step as i64);
+                if sequencer_phase >= step_len {
+                    sequencer_phase -= step_len;
                     current_step = (current_step + 1).rem_euclid(loop_steps_i32);
                     track.kick_sequencer_step.store(current_step, Ordering::Relaxed);
                     track.snare_sequencer_step.store(current_step, Ordering::Relaxed);
@@ -3205,40 +5644,74 @@ impl TLBX1 {
                             &mut snare_q,
                             &mut snare_drive_gain,
                         );
+                        apply_voice_step_params(
+                            track,
+                            step_idx,
+                            step_hold,
+                            track_muted,
+                            sr,
+                            &mut voice_params_base,
+                            &mut voice_shapes,
+                        );
 
-                        if track.kick_sequencer_grid[step_idx].load(Ordering::Relaxed) {
-                            pitch_env = 1.0;
-                            if attack_samples > 0 {
-                                attack_remaining = attack_samples;
-                            } else {
-                                env = 1.0;
-                            }
-                        }
-                        if track.snare_sequencer_grid[step_idx].load(Ordering::Relaxed) {
-                            if snare_attack_samples > 0 {
-                                snare_attack_remaining = snare_attack_samples;
-                                snare_env = 0.0;
-                                snare_noise_env = 0.0;
-                            } else {
-                                snare_env = 1.0;
-                                snare_noise_env = 1.0;
-                            }
-                        }
+                        let next_idx = (current_step + 1).rem_euclid(loop_steps_i32) as usize;
+                        dsp_state.schedule_step_hits(
+                            track,
+                            step_idx,
+                            next_idx,
+                            swing.step_len(current_step as i64),
+                            swing.step_len(current_step as i64 + 1),
+                            None,
+                        );
                     }
                 }
             }
 
+            let due_hits = dsp_state.take_due_hits();
+            if let Some(velocity) = due_hits[0] {
+                dsp_state.attack_peak[0] = velocity;
+                pitch_env = 1.0;
+                if attack_samples > 0 {
+                    attack_remaining = attack_samples;
+                } else {
+                    env = velocity;
+                }
+            }
+            if let Some(velocity) = due_hits[1] {
+                dsp_state.attack_peak[1] = velocity;
+                if snare_attack_samples > 0 {
+                    snare_attack_remaining = snare_attack_samples;
+                    snare_env = 0.0;
+                    snare_noise_env = 0.0;
+                } else {
+                    snare_env = velocity;
+                    snare_noise_env = velocity;
+                }
+            }
+            for voice in 0..SYNDRM_VOICES {
+                let Some(velocity) = due_hits[voice + 2] else {
+                    continue;
+                };
+                // Closed hat chokes the open hat.
+                if voice == SYNDRM_VOICE_CLOSED_HAT {
+                    dsp_state.voices[SYNDRM_VOICE_OPEN_HAT].choke();
+                }
+                dsp_state.voices[voice].trigger(&voice_shapes[voice], velocity);
+            }
+
             if attack_remaining > 0 {
-                env = (env + (1.0 - env) * attack_step).min(1.0);
+                let peak = dsp_state.attack_peak[0];
+                env = (env + (peak - env) * attack_step).min(1.0);
                 attack_remaining = attack_remaining.saturating_sub(1);
             } else {
                 env *= env_coeff;
             }
             pitch_env *= pitch_coeff;
             if snare_attack_remaining > 0 {
-                snare_env = (snare_env + (1.0 - snare_env) * snare_attack_step).min(1.0);
+                let peak = dsp_state.attack_peak[1];
+                snare_env = (snare_env + (peak - snare_env) * snare_attack_step).min(1.0);
                 snare_noise_env =
-                    (snare_noise_env + (1.0 - snare_noise_env) * snare_attack_step).min(1.0);
+                    (snare_noise_env + (peak - snare_noise_env) * snare_attack_step).min(1.0);
                 snare_attack_remaining = snare_attack_remaining.saturating_sub(1);
             } else {
                 snare_env *= snare_env_coeff;
@@ -3321,6 +5794,10 @@ impl TLBX1 {
                 sample += snare_sample * snare_level;
             }
 
+            for (voice, voice_dsp) in dsp_state.voices.iter_mut().enumerate() {
+                sample += voice_dsp.tick(&voice_shapes[voice]);
+            }
+
             for channel in output.iter_mut() {
                 channel[sample_idx] += sample;
             }
@@ -3592,6 +6069,7 @@ impl TLBX1 {
         master_step_count: i64,
         master_phase: f32,
         samples_per_step: f32,
+        swing: SwingClock,
     ) {
         if track.granular_type.load(Ordering::Relaxed) != 1 {
             return;
@@ -3836,10 +6314,11 @@ impl TLBX1 {
             if sync_rate {
                 if steps_per_trigger > 0.0 && samples_per_step.is_finite() && samples_per_step > 0.0
                 {
-                    let step_pos =
-                        base_global_step + (sample_idx as f64 / samples_per_step as f64);
-                    let prev_step_pos = base_global_step
-                        + ((sample_idx as f64 - 1.0) / samples_per_step as f64);
+                    let step_pos = swing
+                        .position(base_global_step + (sample_idx as f64 / samples_per_step as f64));
+                    let prev_step_pos = swing.position(
+                        base_global_step + ((sample_idx as f64 - 1.0) / samples_per_step as f64),
+                    );
                     let bucket = (step_pos / steps_per_trigger).floor();
                     let prev_bucket = (prev_step_pos / steps_per_trigger).floor();
                     if bucket != prev_bucket {
@@ -4253,287 +6732,114 @@ impl TLBX1 {
                                 .wrapping_add(1013904223);
                             let noise = (noise_rng as f32 / u32::MAX as f32) * 2.0 - 1.0;
                             if noise.is_finite() {
-                                noise_value = noise;
-                            } else {
-                                noise_value = 0.0;
-                            }
-                        }
-                    }
-                }
-                track.ring_low[channel_idx]
-                    .store(low.to_bits(), Ordering::Relaxed);
-                track.ring_band[channel_idx]
-                    .store(band.to_bits(), Ordering::Relaxed);
-            }
-            track
-                .ring_detune_phase
-                .store(phase.to_bits(), Ordering::Relaxed);
-            track
-                .ring_waves_phase
-                .store(waves_phase.to_bits(), Ordering::Relaxed);
-            track
-                .ring_noise_phase
-                .store(noise_phase.to_bits(), Ordering::Relaxed);
-            track
-                .ring_noise_value
-                .store(noise_value.to_bits(), Ordering::Relaxed);
-            track
-                .ring_noise_rng
-                .store(noise_rng, Ordering::Relaxed);
-        }
-    }
-
-    fn process_track_g8(
-        track: &Track,
-        track_output: &mut [Vec<f32>],
-        num_buffer_samples: usize,
-        master_step_count: i64,
-        master_phase: f32,
-        samples_per_step: f32,
-        sample_rate: f32,
-    ) {
-        if !track.g8_enabled.load(Ordering::Relaxed) {
-            return;
-        }
-        if num_buffer_samples == 0 || track_output.is_empty() {
-            return;
-        }
-        if !samples_per_step.is_finite() || samples_per_step <= 0.0 {
-            return;
-        }
-
-        let rate_index = track.g8_rate_index.load(Ordering::Relaxed).min(4);
-        let division = match rate_index {
-            0 => 1.0,
-            1 => 0.5,
-            2 => 0.25,
-            3 => 0.125,
-            _ => 0.0625,
-        };
-        let step_len = (samples_per_step * division).max(1.0);
-        let base_global_phase = (master_step_count as f32 * samples_per_step) + master_phase;
-
-        let mut steps = [1.0f32; 32];
-        for i in 0..32 {
-            steps[i] = f32::from_bits(track.g8_steps[i].load(Ordering::Relaxed))
-                .clamp(0.0, 1.0);
-        }
-
-        let smoothing_samples = (sample_rate * (G8_GAIN_SMOOTH_MS / 1000.0)).max(1.0);
-        let mut smooth_gain =
-            f32::from_bits(track.g8_gain_smooth.load(Ordering::Relaxed)).clamp(0.0, 1.0);
-
-        let num_channels = track_output.len();
-        for sample_idx in 0..num_buffer_samples {
-            let step_pos = (base_global_phase + sample_idx as f32) / step_len;
-            let step_idx = (step_pos.floor() as i64).rem_euclid(32) as usize;
-            let target_gain = steps[step_idx];
-            smooth_gain += (target_gain - smooth_gain) / smoothing_samples;
-            let gain = smooth_gain.clamp(0.0, 1.0);
-            for channel_idx in 0..num_channels {
-                track_output[channel_idx][sample_idx] *= gain;
-            }
-        }
-
-        track
-            .g8_gain_smooth
-            .store(smooth_gain.to_bits(), Ordering::Relaxed);
-    }
-}
-
-impl Plugin for TLBX1 {
-    const NAME: &'static str = "TLBX-1";
-    const VENDOR: &'static str = "Zencoder";
-    const URL: &'static str = "https://example.com";
-    const EMAIL: &'static str = "info@example.com";
-
-    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
-
-    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
-        AudioIOLayout {
-            // Input-enabled layout for recording/monitoring.
-            main_input_channels: NonZeroU32::new(2),
-            main_output_channels: NonZeroU32::new(2),
-            ..AudioIOLayout::const_default()
-        },
-        AudioIOLayout {
-            // Generator-style layout.
-            main_input_channels: None,
-            main_output_channels: NonZeroU32::new(2),
-            ..AudioIOLayout::const_default()
-        },
-    ];
-
-    const MIDI_INPUT: MidiConfig = MidiConfig::None;
-    const MIDI_OUTPUT: MidiConfig = MidiConfig::None;
-
-    const SAMPLE_ACCURATE_AUTOMATION: bool = true;
-
-    type SysExMessage = ();
-    type BackgroundTask = TLBX1Task;
-
-    fn initialize(
-        &mut self,
-        _audio_io_layout: &AudioIOLayout,
-        buffer_config: &BufferConfig,
-        _context: &mut impl InitContext<Self>,
-    ) -> bool {
-        self.sample_rate.store(buffer_config.sample_rate as u32, Ordering::Relaxed);
-        self.track_buffer = vec![vec![0.0; buffer_config.max_buffer_size as usize]; 2];
-        true
-    }
-
-    fn params(&self) -> Arc<dyn Params> {
-        self.params.clone()
-    }
-
-    fn editor(&mut self, async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
-        Some(Box::new(SlintEditor {
-            params: self.params.clone(),
-            tracks: self.tracks.clone(),
-            master_meters: self.master_meters.clone(),
-            visualizer: self.visualizer.clone(),
-            global_tempo: self.global_tempo.clone(),
-            follow_host_tempo: self.follow_host_tempo.clone(),
-            metronome_enabled: self.metronome_enabled.clone(),
-            metronome_count_in_ticks: self.metronome_count_in_ticks.clone(),
-            metronome_count_in_playback: self.metronome_count_in_playback.clone(),
-            metronome_count_in_record: self.metronome_count_in_record.clone(),
-            async_executor,
-            pending_project_params: self.pending_project_params.clone(),
-            animate_library: self.animate_library.clone(),
-        }))
-    }
-
-    fn task_executor(&mut self) -> TaskExecutor<Self> {
-        let tracks = self.tracks.clone();
-        let global_tempo = self.global_tempo.clone();
-        let params = self.params.clone();
-        let pending_project_params = self.pending_project_params.clone();
-        Box::new(move |task| match task {
-            TLBX1Task::LoadSample(track_idx, path) => {
-                if track_idx >= NUM_TRACKS {
-                    return;
-                }
-                
-                match load_media_file(&path) {
-                    Ok((new_samples, sample_rate, video)) => {
-                        let mut samples = tracks[track_idx].samples.lock();
-                        let mut summary = tracks[track_idx].waveform_summary.lock();
-                        let mut sample_path = tracks[track_idx].sample_path.lock();
-                        let mut video_cache = tracks[track_idx].video_cache.lock();
-
-                        *samples = new_samples;
-                        *sample_path = Some(path.clone());
-                        tracks[track_idx]
-                            .sample_rate
-                            .store(sample_rate, Ordering::Relaxed);
-
-                        if let Some(video) = video {
-                            tracks[track_idx]
-                                .video_enabled
-                                .store(true, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_width
-                                .store(video.width, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_height
-                                .store(video.height, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_fps
-                                .store(video.fps.to_bits(), Ordering::Relaxed);
-                            *video_cache = Some(video);
-                            tracks[track_idx]
-                                .video_cache_id
-                                .fetch_add(1, Ordering::Relaxed);
-                        } else {
-                            tracks[track_idx]
-                                .video_enabled
-                                .store(false, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_width
-                                .store(0, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_height
-                                .store(0, Ordering::Relaxed);
-                            tracks[track_idx]
-                                .video_fps
-                                .store(0.0f32.to_bits(), Ordering::Relaxed);
-                            *video_cache = None;
-                            tracks[track_idx]
-                                .video_cache_id
-                                .fetch_add(1, Ordering::Relaxed);
-                        }
-
-                        if !samples.is_empty() {
-                            calculate_waveform_summary(&samples[0], &mut summary);
-                        } else {
-                            summary.fill(0.0);
-                        }
-
-                        nih_log!("Loaded media: {:?}", path);
-                    }
-                    Err(e) => {
-                        tracks[track_idx]
-                            .video_enabled
-                            .store(false, Ordering::Relaxed);
-                        tracks[track_idx]
-                            .video_cache_id
-                            .fetch_add(1, Ordering::Relaxed);
-                        *tracks[track_idx].video_cache.lock() = None;
-                        nih_log!("Failed to load media: {:?}", e);
+                                noise_value = noise;
+                            } else {
+                                noise_value = 0.0;
+                            }
+                        }
                     }
                 }
+                track.ring_low[channel_idx]
+                    .store(low.to_bits(), Ordering::Relaxed);
+                track.ring_band[channel_idx]
+                    .store(band.to_bits(), Ordering::Relaxed);
             }
-            TLBX1Task::SaveProject {
-                path,
-                title,
-                description,
-            } => {
-                let tempo = f32::from_bits(global_tempo.load(Ordering::Relaxed));
-                if let Err(err) = save_project(&tracks, tempo, &params, &title, &description, &path) {
-                    nih_log!("Failed to save project: {:?}", err);
-                } else {
-                    nih_log!("Saved project: {:?}", path);
-                }
-            }
-            TLBX1Task::LoadProject(path) => {
-                if let Err(err) = load_project(
-                    &tracks,
-                    &global_tempo,
-                    &params,
-                    &pending_project_params,
-                    &path,
-                ) {
-                    nih_log!("Failed to load project: {:?}", err);
-                } else {
-                    nih_log!("Loaded project: {:?}", path);
-                }
-            }
-            TLBX1Task::ExportProjectZip {
-                path,
-                title,
-                description,
-            } => {
-                let tempo = f32::from_bits(global_tempo.load(Ordering::Relaxed));
-                if let Err(err) =
-                    export_project_as_zip(&tracks, tempo, &params, &title, &description, &path)
-                {
-                    nih_log!("Failed to export project: {:?}", err);
-                } else {
-                    nih_log!("Exported project zip: {:?}", path);
-                }
-            }
-        })
+            track
+                .ring_detune_phase
+                .store(phase.to_bits(), Ordering::Relaxed);
+            track
+                .ring_waves_phase
+                .store(waves_phase.to_bits(), Ordering::Relaxed);
+            track
+                .ring_noise_phase
+                .store(noise_phase.to_bits(), Ordering::Relaxed);
+            track
+                .ring_noise_value
+                .store(noise_value.to_bits(), Ordering::Relaxed);
+            track
+                .ring_noise_rng
+                .store(noise_rng, Ordering::Relaxed);
+        }
     }
 
-    fn process(
+    fn process_track_g8(
+        track: &Track,
+        track_output: &mut [Vec<f32>],
+        num_buffer_samples: usize,
+        master_step_count: i64,
+        master_phase: f32,
+        samples_per_step: f32,
+        swing: SwingClock,
+        sample_rate: f32,
+    ) {
+        if !track.g8_enabled.load(Ordering::Relaxed) {
+            return;
+        }
+        if num_buffer_samples == 0 || track_output.is_empty() {
+            return;
+        }
+        if !samples_per_step.is_finite() || samples_per_step <= 0.0 {
+            return;
+        }
+
+        let rate_index = track.g8_rate_index.load(Ordering::Relaxed).min(4);
+        let division = match rate_index {
+            0 => 1.0,
+            1 => 0.5,
+            2 => 0.25,
+            3 => 0.125,
+            _ => 0.0625,
+        };
+        let base_global_step =
+            master_step_count as f64 + (master_phase as f64 / samples_per_step as f64);
+
+        let mut steps = [1.0f32; 32];
+        for i in 0..32 {
+            steps[i] = f32::from_bits(track.g8_steps[i].load(Ordering::Relaxed))
+                .clamp(0.0, 1.0);
+        }
+
+        let smoothing_samples = (sample_rate * (G8_GAIN_SMOOTH_MS / 1000.0)).max(1.0);
+        let mut smooth_gain =
+            f32::from_bits(track.g8_gain_smooth.load(Ordering::Relaxed)).clamp(0.0, 1.0);
+
+        let num_channels = track_output.len();
+        for sample_idx in 0..num_buffer_samples {
+            let step_pos = swing
+                .position(base_global_step + (sample_idx as f64 / samples_per_step as f64))
+                / division;
+            let step_idx = (step_pos.floor() as i64).rem_euclid(32) as usize;
+            let target_gain = steps[step_idx];
+            smooth_gain += (target_gain - smooth_gain) / smoothing_samples;
+            let gain = smooth_gain.clamp(0.0, 1.0);
+            for channel_idx in 0..num_channels {
+                track_output[channel_idx][sample_idx] *= gain;
+            }
+        }
+
+        track
+            .g8_gain_smooth
+            .store(smooth_gain.to_bits(), Ordering::Relaxed);
+    }
+    /// Runs one block of the engine, track FX, and master chain into `output`.
+    ///
+    /// Shared by the host callback and the offline renderer, so it takes plain channel
+    /// slices instead of a host buffer. Returns whether the plugin should be kept alive.
+    fn process_block(
         &mut self,
-        buffer: &mut Buffer,
-        _aux: &mut AuxiliaryBuffers,
-        context: &mut impl ProcessContext<Self>,
-    ) -> ProcessStatus {
+        output: &mut [&mut [f32]],
+        host_tempo: Option<f32>,
+        host_sample_rate: f32,
+        host_transport: HostTransport,
+    ) -> bool {
         let mut keep_alive = false;
+        let block_channels = output.len();
+        let block_samples = output.first().map(|channel| channel.len()).unwrap_or(0);
+        if self.render_master.is_none() {
+            for (track, track_params) in self.tracks.iter().zip(self.params.tracks.iter()) {
+                track_params.apply_host_changes(track);
+            }
+        }
         let mut global_tempo =
             f32::from_bits(self.global_tempo.load(Ordering::Relaxed)).clamp(20.0, 240.0);
         if !global_tempo.is_finite() {
@@ -4542,8 +6848,7 @@ impl Plugin for TLBX1 {
                 .store(global_tempo.to_bits(), Ordering::Relaxed);
         }
         if self.follow_host_tempo.load(Ordering::Relaxed) {
-            if let Some(tempo) = context.transport().tempo {
-                let tempo = tempo as f32;
+            if let Some(tempo) = host_tempo {
                 if tempo.is_finite() {
                     global_tempo = tempo.clamp(20.0, 240.0);
                     self.global_tempo
@@ -4551,7 +6856,16 @@ impl Plugin for TLBX1 {
                 }
             }
         }
-        let mut master_sr = context.transport().sample_rate;
+        let clock_follow = self.midi_clock.follow.load(Ordering::Relaxed);
+        if clock_follow {
+            let tempo = f32::from_bits(self.midi_clock.tempo.load(Ordering::Relaxed));
+            if tempo > 0.0 && tempo.is_finite() {
+                global_tempo = tempo.clamp(20.0, 240.0);
+                self.global_tempo
+                    .store(global_tempo.to_bits(), Ordering::Relaxed);
+            }
+        }
+        let mut master_sr = host_sample_rate;
         if !master_sr.is_finite() || master_sr <= 0.0 {
             master_sr = self.sample_rate.load(Ordering::Relaxed).max(1) as f32;
         }
@@ -4570,9 +6884,50 @@ impl Plugin for TLBX1 {
                 master_step_count += 1;
             }
         }
+        if clock_follow && self.midi_clock.running.load(Ordering::Relaxed) {
+            // Snap to the external song position once it is half a step away.
+            let target = self.midi_clock.tick_position.load(Ordering::Relaxed) as f64
+                / MIDI_CLOCK_TICKS_PER_STEP as f64;
+            let current =
+                master_step_count as f64 + (master_phase / samples_per_step) as f64;
+            if (target - current).abs() > 0.5 {
+                master_step_count = target.floor() as i64;
+                master_phase = (target.fract() as f32) * samples_per_step;
+                master_step = master_step_count.rem_euclid(16) as i32;
+            }
+        }
+        let follow_transport = self.params.follow_host_transport.load(Ordering::Relaxed)
+            && self.render_master.is_none()
+            && !clock_follow;
+        if follow_transport {
+            if host_transport.playing != self.host_was_playing {
+                if host_transport.playing {
+                    start_transport(&self.tracks, global_tempo, 0, false);
+                } else {
+                    stop_transport(&self.tracks);
+                }
+            }
+            if host_transport.playing {
+                if let Some(pos_beats) = host_transport.pos_beats {
+                    // One step is a sixteenth note. Both the step and the step count come
+                    // from the bar-relative position, so bar-based consumers of the count
+                    // agree with the step index whatever the host's bar length.
+                    let bar_start = host_transport.bar_start_beats.unwrap_or(0.0);
+                    let bar = host_transport
+                        .bar_number
+                        .map(i64::from)
+                        .unwrap_or_else(|| (bar_start / 4.0).floor() as i64);
+                    let target = (pos_beats - bar_start).max(0.0) * 4.0;
+                    master_step = (target.floor() as i64).rem_euclid(16) as i32;
+                    master_step_count = bar.max(0) * 16 + master_step as i64;
+                    master_phase = (target.fract() as f32) * samples_per_step;
+                }
+            }
+        }
+        self.host_was_playing = host_transport.playing;
 
 
-        let buffer_samples = buffer.samples() as u32;
+        let buffer_samples = block_samples as u32;
         let mut any_pending = false;
         if buffer_samples > 0 {
             let mut play_remaining = None;
@@ -4629,73 +6984,93 @@ impl Plugin for TLBX1 {
             }
             if track.is_recording.load(Ordering::Relaxed) {
                 if let Some(mut samples) = track.samples.try_lock() {
-                    let overdub = track.tape_overdub.load(Ordering::Relaxed);
-                    let sos = f32::from_bits(track.tape_sos.load(Ordering::Relaxed))
-                        .clamp(0.0, 1.0);
-                    // Ensure we have enough channels
-                    while samples.len() < buffer.channels() {
-                        samples.push(vec![]);
-                    }
-
-                    let input = buffer.as_slice_immutable();
-                    let loop_start_norm =
-                        f32::from_bits(track.loop_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
-                    let loop_length_norm =
-                        f32::from_bits(track.loop_length.load(Ordering::Relaxed)).clamp(0.0, 1.0);
-                    let record_start = (loop_start_norm * RECORD_MAX_SAMPLES as f32) as usize;
-                    let mut record_len =
-                        (loop_length_norm * RECORD_MAX_SAMPLES as f32) as usize;
-                    if record_len == 0 {
-                        record_len = 1;
-                    }
-                    let record_end = (record_start + record_len).min(RECORD_MAX_SAMPLES);
-                    let mut write_pos =
-                        f32::from_bits(track.record_pos.load(Ordering::Relaxed))
-                            .max(0.0) as usize;
-                    if write_pos < record_start || write_pos >= record_end {
-                        write_pos = record_start;
-                    }
-                    for channel_idx in 0..buffer.channels() {
-                        let channel_data = &input[channel_idx];
-                        let buf = &mut samples[channel_idx];
-                        let mut write_idx = write_pos;
-                        for sample in channel_data.iter() {
-                            if write_idx >= record_end {
-                                if overdub {
-                                    write_idx = record_start;
+                    // Channels are allocated with the take; extra input channels are dropped.
+                    let channels = block_channels.min(samples.len());
+                    let buffer_len = samples.first().map_or(0, |channel| channel.len());
+                    let input = &*output;
+                    let mut write_pos = track.record_pos.load(Ordering::Relaxed);
+                    if channels == 0 || buffer_len == 0 {
+                        // Nothing to record into until the UI has allocated the take.
+                    } else if track.record_appending.load(Ordering::Relaxed) {
+                        // A fresh take appends; the helper thread keeps a longer buffer ready.
+                        let written = block_samples.min(buffer_len.saturating_sub(write_pos));
+                        if written > 0 {
+                            for channel_idx in 0..channels {
+                                samples[channel_idx][write_pos..write_pos + written]
+                                    .copy_from_slice(&input[channel_idx][..written]);
+                            }
+                            write_pos += written;
+                        }
+                        if written < block_samples {
+                            track
+                                .record_dropped
+                                .fetch_add(block_samples - written, Ordering::Relaxed);
+                        }
+                        if let Some(mut spare) = track.record_spare.try_lock() {
+                            let spare_len = spare.first().map_or(0, |channel| channel.len());
+                            if spare.len() == samples.len() && spare_len > buffer_len {
+                                let migrated = track.record_migrated.load(Ordering::Relaxed);
+                                let end = (migrated
+                                    + RECORD_MIGRATE_CHUNK.max(block_samples * 4))
+                                .min(write_pos);
+                                for (dst, src) in spare.iter_mut().zip(samples.iter()) {
+                                    dst[migrated..end].copy_from_slice(&src[migrated..end]);
+                                }
+                                if end >= write_pos {
+                                    // The old buffer goes back to the helper thread to be freed.
+                                    std::mem::swap(&mut *samples, &mut *spare);
+                                    track.record_migrated.store(0, Ordering::Relaxed);
                                 } else {
-                                    track.is_recording.store(false, Ordering::Relaxed);
-                                    break;
+                                    track.record_migrated.store(end, Ordering::Relaxed);
                                 }
+                            } else if write_pos * 4 >= buffer_len {
+                                // Ask early so the helper has most of the buffer to respond.
+                                track
+                                    .record_grow_len
+                                    .store(buffer_len * 2, Ordering::Relaxed);
                             }
-                            if write_idx >= buf.len() {
-                                track.is_recording.store(false, Ordering::Relaxed);
-                                break;
-                            }
-                            if overdub {
-                                let existing = buf[write_idx];
-                                buf[write_idx] = existing * sos + *sample;
-                            } else {
-                                buf[write_idx] = *sample;
-                            }
-                            write_idx += 1;
                         }
-                        if !track.is_recording.load(Ordering::Relaxed) {
-                            break;
+                    } else {
+                        // Overdub loops over the loop region of the existing buffer.
+                        let sos = f32::from_bits(track.tape_sos.load(Ordering::Relaxed))
+                            .clamp(0.0, 1.0);
+                        let loop_start_norm =
+                            f32::from_bits(track.loop_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
+                        let loop_length_norm =
+                            f32::from_bits(track.loop_length.load(Ordering::Relaxed)).clamp(0.0, 1.0);
+                        let record_start = (loop_start_norm * buffer_len as f32) as usize;
+                        let record_len =
+                            ((loop_length_norm * buffer_len as f32) as usize).max(1);
+                        let record_end = (record_start + record_len).min(buffer_len);
+                        if write_pos < record_start || write_pos >= record_end {
+                            write_pos = record_start;
+                        }
+                        let mut next_pos = write_pos;
+                        for channel_idx in 0..channels {
+                            let buf = &mut samples[channel_idx];
+                            let mut write_idx = write_pos;
+                            for sample in input[channel_idx].iter() {
+                                if write_idx >= record_end {
+                                    write_idx = record_start;
+                                }
+                                buf[write_idx] = buf[write_idx] * sos + *sample;
+                                write_idx += 1;
+                            }
+                            next_pos = write_idx;
                         }
-                        write_pos = write_idx;
+                        write_pos = next_pos;
                     }
-                    track
-                        .record_pos
-                        .store((write_pos as f32).to_bits(), Ordering::Relaxed);
+                    track.record_pos.store(write_pos, Ordering::Relaxed);
                 }
             }
         }
 
-        let any_playing = self
-            .tracks
-            .iter()
-            .any(|track| track.is_playing.load(Ordering::Relaxed));
+        // An offline render always runs the transport so sequencers advance.
+        let any_playing = self.render_master.is_some()
+            || self
+                .tracks
+                .iter()
+                .any(|track| track.is_playing.load(Ordering::Relaxed));
         let any_recording = self
             .tracks
             .iter()
@@ -4711,26 +7086,37 @@ impl Plugin for TLBX1 {
         let any_monitoring = monitor_level > 0.0;
         if any_monitoring {
             if (monitor_level - 1.0).abs() > f32::EPSILON {
-                for channel_samples in buffer.iter_samples() {
-                    for sample in channel_samples {
+                for channel in output.iter_mut() {
+                    for sample in channel.iter_mut() {
                         *sample *= monitor_level;
                     }
                 }
             }
         } else {
-            for channel_samples in buffer.iter_samples() {
-                for sample in channel_samples {
+            for channel in output.iter_mut() {
+                for sample in channel.iter_mut() {
                     *sample = 0.0;
                 }
             }
         }
 
+        for stem in self.render_stems.iter_mut() {
+            for channel in stem.iter_mut() {
+                channel.fill(0.0);
+            }
+        }
+
         // Handle playback for all tracks
         let transport_running = any_playing;
-        for (track, syndrm_dsp) in self
+        let global_swing = match self.render_master {
+            Some(master) => master.swing,
+            None => self.params.swing.value(),
+        };
+        for (track_idx, (track, syndrm_dsp)) in self
             .tracks
             .iter()
             .zip(self.syndrm_dsp.iter_mut())
+            .enumerate()
         {
             if track.is_recording.load(Ordering::Relaxed) {
                 continue;
@@ -4738,8 +7124,24 @@ impl Plugin for TLBX1 {
 
             let engine_type = track.engine_type.load(Ordering::Relaxed);
             let track_muted = track.is_muted.load(Ordering::Relaxed);
+            let swing = SwingClock::new(
+                samples_per_step,
+                if track.swing_override.load(Ordering::Relaxed) {
+                    f32::from_bits(track.swing.load(Ordering::Relaxed))
+                } else {
+                    global_swing
+                },
+            );
+            // The swung step trails the straight one by at most a step while an off-beat
+            // is being held back.
+            let (swing_step_count, swing_phase) = swing.locate(master_step_count, master_phase);
+            let swing_step =
+                (master_step + (swing_step_count - master_step_count) as i32).rem_euclid(16);
+            // MIDI slice playback answers notes with the transport stopped.
+            let tape_slice_midi =
+                engine_type == 1 && track.tape_slice_mode.load(Ordering::Relaxed) == 2;
             let should_process =
-                transport_running || matches!(engine_type, 2 | 3 | 4);
+                transport_running || tape_slice_midi || matches!(engine_type, 2 | 3 | 4);
             if !should_process {
                 let prev_left =
                     f32::from_bits(track.meter_left.load(Ordering::Relaxed));
@@ -4785,12 +7187,13 @@ impl Plugin for TLBX1 {
                 Self::process_animate(
                     track,
                     &mut self.track_buffer,
-                    buffer.samples(),
+                    block_samples,
                     &self.global_tempo,
                     &self.animate_library,
-                    master_step,
-                    master_phase,
-                    samples_per_step,
+                    &self.midi_events,
+                    swing_step_count,
+                    swing_phase,
+                    swing,
                     transport_running,
                 );
             } else if engine_type == 3 {
@@ -4798,12 +7201,12 @@ impl Plugin for TLBX1 {
                     track,
                     &mut self.track_buffer,
                     syndrm_dsp,
-                    buffer.samples(),
+                    block_samples,
                     &self.global_tempo,
-                    master_step,
-                    master_phase,
-                    master_step_count,
-                    samples_per_step,
+                    swing_step,
+                    swing_phase,
+                    swing_step_count,
+                    swing,
                     master_sr,
                     transport_running,
                 );
@@ -4811,14 +7214,14 @@ impl Plugin for TLBX1 {
                 Self::process_voidseed(
                     track,
                     &mut self.track_buffer,
-                    buffer.samples(),
+                    block_samples,
                     &self.global_tempo,
                     master_step,
                     master_phase,
                     samples_per_step,
                     master_sr,
                 );
-            } else if transport_running {
+            } else if transport_running || tape_slice_midi {
                 if let Some(samples) = track.samples.try_lock() {
                     if samples.is_empty() || samples[0].is_empty() {
                         track.is_playing.store(false, Ordering::Relaxed);
@@ -4827,7 +7230,7 @@ impl Plugin for TLBX1 {
 
                     let num_samples = samples[0].len();
                     let num_channels = samples.len();
-                    let num_buffer_samples = buffer.samples();
+                    let num_buffer_samples = block_samples;
                     let mosaic_active =
                         track.granular_type.load(Ordering::Relaxed) == 1;
                     let mut mosaic_buffer = if mosaic_active {
@@ -4867,6 +7270,14 @@ impl Plugin for TLBX1 {
                     let tape_freeze = track.tape_freeze.load(Ordering::Relaxed);
                     let tape_reverse = track.tape_reverse.load(Ordering::Relaxed);
                     let tape_keylock = track.tape_keylock.load(Ordering::Relaxed);
+                    let tape_keylock_mode = track.tape_keylock_mode.load(Ordering::Relaxed);
+                    let tape_pitch = f32::from_bits(track.tape_pitch.load(Ordering::Relaxed))
+                        .clamp(-24.0, 24.0)
+                        .round();
+                    let tape_fine = f32::from_bits(track.tape_fine.load(Ordering::Relaxed))
+                        .clamp(-100.0, 100.0);
+                    let pitch_ratio = 2.0f32.powf((tape_pitch + tape_fine / 100.0) / 12.0);
+                    let pitch_active = (pitch_ratio - 1.0).abs() > 1.0e-4;
                     let mut smooth_speed =
                         f32::from_bits(track.tape_speed_smooth.load(Ordering::Relaxed));
                     let target_level = if track_muted { 0.0 } else { track_level };
@@ -4877,9 +7288,25 @@ impl Plugin for TLBX1 {
                     } else {
                         0.0
                     };
-                    let loop_enabled = track.loop_enabled.load(Ordering::Relaxed);
-                    let loop_mode = track.loop_mode.load(Ordering::Relaxed);
-                    let keylock_enabled = tape_keylock && loop_mode != 1 && loop_mode != 4;
+                    let tape_slice_mode = track.tape_slice_mode.load(Ordering::Relaxed);
+                    let slice_count = (track.tape_slice_count.load(Ordering::Relaxed) as usize)
+                        .min(TAPE_SLICE_MAX);
+                    let slicing = tape_slice_mode != 0 && slice_count > 0;
+                    // A slice plays once from its marker; only Reverse carries over from
+                    // the loop modes.
+                    let loop_enabled = track.loop_enabled.load(Ordering::Relaxed) && !slicing;
+                    let loop_mode = match track.loop_mode.load(Ordering::Relaxed) {
+                        3 => 3,
+                        _ if slicing => 0,
+                        mode => mode,
+                    };
+                    // Classic grains can't follow ping-pong bounces or random starts;
+                    // the WSOLA modes work in every loop mode.
+                    let keylock_enabled = tape_keylock
+                        && (tape_keylock_mode != 0 || (loop_mode != 1 && loop_mode != 4));
+                    // Pitch shifting shares the stretch heads, so it also runs in Classic.
+                    let stretch_enabled =
+                        (keylock_enabled && tape_keylock_mode != 0) || pitch_active;
                     let loop_active = loop_enabled && loop_mode != 2;
                     let loop_start_norm =
                         f32::from_bits(track.loop_start.load(Ordering::Relaxed))
@@ -4890,7 +7317,6 @@ impl Plugin for TLBX1 {
                     let loop_xfade_norm =
                         f32::from_bits(track.loop_xfade.load(Ordering::Relaxed))
                             .clamp(0.0, 0.5);
-                    let output = buffer.as_slice();
                     let mut play_pos = f32::from_bits(track.play_pos.load(Ordering::Relaxed));
                     let mut keylock_phase =
                         f32::from_bits(track.keylock_phase.load(Ordering::Relaxed));
@@ -4921,11 +7347,46 @@ impl Plugin for TLBX1 {
                         loop_len = num_samples.saturating_sub(loop_start).max(1);
                     }
                     let loop_end = (loop_start + loop_len).min(num_samples);
+                    // Slices run from their marker to the next one in time, whatever the
+                    // playback order.
+                    let slice_bounds = |slice: usize| {
+                        let start = (track.tape_slice_starts[slice].load(Ordering::Relaxed)
+                            as usize)
+                            .min(num_samples.saturating_sub(1));
+                        let end = if slice + 1 < slice_count {
+                            track.tape_slice_starts[slice + 1].load(Ordering::Relaxed) as usize
+                        } else {
+                            num_samples
+                        };
+                        (start, end.min(num_samples).max(start + 1))
+                    };
+                    let (mut loop_start, mut loop_end) = if slicing {
+                        slice_bounds(
+                            track.tape_slice_current.load(Ordering::Relaxed) as usize
+                                % slice_count,
+                        )
+                    } else {
+                        (loop_start, loop_end)
+                    };
                     let loop_len = loop_end.saturating_sub(loop_start).max(1);
                     let mut xfade_samples = (loop_xfade_norm * loop_len as f32) as usize;
                     if xfade_samples * 2 > loop_len {
                         xfade_samples = loop_len / 2;
                     }
+                    let mut stretch = TapeStretch::load(
+                        track,
+                        loop_start,
+                        loop_end,
+                        loop_active,
+                        loop_mode == 1 || loop_mode == 2 || slicing,
+                        num_samples,
+                    );
+                    if stretch_enabled && !track.tape_stretch_active.load(Ordering::Relaxed) {
+                        stretch.reset(play_pos);
+                    }
+                    track
+                        .tape_stretch_active
+                        .store(stretch_enabled, Ordering::Relaxed);
                     let mut direction = match loop_mode {
                         1 => track.loop_dir.load(Ordering::Relaxed),
                         3 => -1,
@@ -4945,6 +7406,9 @@ impl Plugin for TLBX1 {
                     };
                     let (tempo_speed, straight_bars) = match tape_rate_mode {
                         0 => (tape_speed, None),
+                        // Slices are timed by their triggers, so Straight plays them at
+                        // their recorded speed.
+                        1 if slicing => (1.0, None),
                         1 => {
                             let divisions = [
                                 1.0 / 64.0,
@@ -4976,6 +7440,8 @@ impl Plugin for TLBX1 {
                     let use_straight_lock = tape_rate_mode == 1
                         && straight_bars.is_some()
                         && samples_per_step > 0.0;
+                    // Loops lock to bar lines, which swing never moves, so tape stays on
+                    // the straight grid.
                     let mut straight_phase = master_phase;
                     let mut straight_step_count = master_step_count;
                     if sync_requested && use_straight_lock && loop_len > 0 {
@@ -5006,7 +7472,9 @@ impl Plugin for TLBX1 {
                         let last_start = track.loop_start_last.load(Ordering::Relaxed) as usize;
                         if last_start != loop_start {
                             play_pos = loop_start as f32;
-                            if keylock_enabled {
+                            if stretch_enabled {
+                                stretch.reset(play_pos);
+                            } else if keylock_enabled {
                                 keylock_phase = 0.0;
                                 keylock_grain_a = play_pos;
                                 keylock_grain_b =
@@ -5018,8 +7486,89 @@ impl Plugin for TLBX1 {
                             .store(loop_start as u32, Ordering::Relaxed);
                     }
 
+                    let mut slice_playing = track.tape_slice_playing.load(Ordering::Relaxed);
+                    let mut slice_step = track.tape_slice_step.load(Ordering::Relaxed);
+                    let mut slice_clock_step = swing_step_count;
+                    let mut slice_clock_phase = swing_phase;
+                    let midi_channel = track.midi_channel.load(Ordering::Relaxed);
+                    let last_sample_idx = num_buffer_samples.saturating_sub(1);
+                    let mut midi_event_idx = 0;
+
                     let mut prev_play_pos;
                     for sample_idx in 0..num_buffer_samples {
+                        if slicing {
+                            let mut trigger = None;
+                            if tape_slice_mode == 1 {
+                                // Each (swung) step plays the slice its row entry assigns it.
+                                if slice_clock_step != slice_step {
+                                    slice_step = slice_clock_step;
+                                    let row_idx =
+                                        slice_step.rem_euclid(TAPE_SLICE_STEPS as i64) as usize;
+                                    match track.tape_slice_order[row_idx].load(Ordering::Relaxed) {
+                                        TAPE_SLICE_HOLD => {}
+                                        TAPE_SLICE_REST => slice_playing = false,
+                                        slice => trigger = Some(slice as usize % slice_count),
+                                    }
+                                }
+                                slice_clock_phase += 1.0;
+                                let step_len = swing.step_len(slice_clock_step);
+                                if step_len > 0.0 && slice_clock_phase >= step_len {
+                                    slice_clock_phase -= step_len;
+                                    slice_clock_step += 1;
+                                }
+                            } else {
+                                while midi_event_idx < self.midi_events.len()
+                                    && (self.midi_events[midi_event_idx].timing as usize)
+                                        .min(last_sample_idx)
+                                        <= sample_idx
+                                {
+                                    let event = self.midi_events[midi_event_idx];
+                                    midi_event_idx += 1;
+                                    if !event.is_on
+                                        || (midi_channel != 0
+                                            && event.channel as u32 + 1 != midi_channel)
+                                    {
+                                        continue;
+                                    }
+                                    if let Some(slice) = (event.note as usize)
+                                        .checked_sub(TAPE_SLICE_MIDI_BASE)
+                                        .filter(|slice| *slice < slice_count)
+                                    {
+                                        trigger = Some(slice);
+                                    }
+                                }
+                            }
+                            if let Some(slice) = trigger {
+                                (loop_start, loop_end) = slice_bounds(slice);
+                                track
+                                    .tape_slice_current
+                                    .store(slice as u32, Ordering::Relaxed);
+                                let speed = smooth_speed + speed_step * sample_idx as f32;
+                                play_pos = if direction as f32 * speed < 0.0 {
+                                    loop_end.saturating_sub(1) as f32
+                                } else {
+                                    loop_start as f32
+                                };
+                                slice_playing = true;
+                                stretch.loop_start = loop_start;
+                                stretch.loop_end = loop_end;
+                                if stretch_enabled {
+                                    stretch.reset(play_pos);
+                                } else if keylock_enabled {
+                                    keylock_phase = 0.0;
+                                    keylock_grain_a = play_pos;
+                                    keylock_grain_b =
+                                        play_pos + direction as f32 * KEYLOCK_GRAIN_HOP as f32;
+                                }
+                            }
+                            if !slice_playing
+                                || play_pos < loop_start as f32
+                                || play_pos >= loop_end as f32
+                            {
+                                slice_playing = false;
+                                continue;
+                            }
+                        }
                         let mut pos = play_pos as isize;
                         if pos < 0 || pos as usize >= num_samples {
                             if loop_active {
@@ -5060,6 +7609,22 @@ impl Plugin for TLBX1 {
                             };
                             let xfade_start = loop_end.saturating_sub(xfade_samples) as f32;
                             let xfade_len = xfade_samples as f32;
+                            // Keylock and pitch read the locked position through the
+                            // stretch heads so the loop stays on the bar grid.
+                            let head_rate = if tape_keylock {
+                                pitch_ratio
+                            } else {
+                                tempo_speed * pitch_ratio
+                            };
+                            if stretch_enabled {
+                                stretch.tick(
+                                    &samples,
+                                    locked_pos,
+                                    direction,
+                                    tempo_speed,
+                                    head_rate,
+                                );
+                            }
                             for channel_idx in 0..output.len() {
                                 let src_channel = if num_channels == 1 {
                                     0
@@ -5068,16 +7633,20 @@ impl Plugin for TLBX1 {
                                 } else {
                                     continue;
                                 };
-                                let mut sample_value = sample_at_linear(
-                                    &samples,
-                                    src_channel,
-                                    locked_pos,
-                                    loop_start,
-                                    loop_end,
-                                    loop_active,
-                                    num_samples,
-                                );
-                                if loop_active && xfade_samples > 0 {
+                                let mut sample_value = if stretch_enabled {
+                                    stretch.read(&samples, src_channel)
+                                } else {
+                                    sample_at_linear(
+                                        &samples,
+                                        src_channel,
+                                        locked_pos,
+                                        loop_start,
+                                        loop_end,
+                                        loop_active,
+                                        num_samples,
+                                    )
+                                };
+                                if !stretch_enabled && loop_active && xfade_samples > 0 {
                                     if direction > 0 && locked_pos >= xfade_start {
                                         let fade_in =
                                             ((locked_pos - xfade_start) / xfade_len).clamp(0.0, 1.0);
@@ -5127,14 +7696,72 @@ impl Plugin for TLBX1 {
                                         }
                                     }
                                 }
-                            if mosaic_buffer.is_some() && mosaic_len > 0 {
-                                mosaic_write_pos = (mosaic_write_pos + 1) % mosaic_len;
-                            }
-                            play_pos = locked_pos;
-                            straight_phase += 1.0;
-                            if straight_phase >= samples_per_step {
-                                straight_phase -= samples_per_step;
-                                straight_step_count += 1;
+                            if mosaic_buffer.is_some() && mosaic_len > 0 {
+                                mosaic_write_pos = (mosaic_write_pos + 1) % mosaic_len;
+                            }
+                            if stretch_enabled {
+                                stretch.advance(direction, head_rate);
+                            }
+                            play_pos = locked_pos;
+                            straight_phase += 1.0;
+                            if straight_phase >= samples_per_step {
+                                straight_phase -= samples_per_step;
+                                straight_step_count += 1;
+                            }
+                        } else if stretch_enabled {
+                            let speed = smooth_speed + speed_step * sample_idx as f32;
+                            // Keylock holds the original pitch; without it the heads
+                            // follow the speed like tape and the pitch shift rides on top.
+                            let head_rate = if tape_keylock {
+                                pitch_ratio
+                            } else {
+                                speed.abs() * pitch_ratio
+                            };
+                            let head_dir = if speed < 0.0 { -direction } else { direction };
+                            stretch.tick(&samples, play_pos, head_dir, speed, head_rate);
+                            for channel_idx in 0..output.len() {
+                                let src_channel = if num_channels == 1 {
+                                    0
+                                } else if channel_idx < num_channels {
+                                    channel_idx
+                                } else {
+                                    continue;
+                                };
+                                let sample_value = stretch.read(&samples, src_channel);
+                                let level = smooth_level + level_step * sample_idx as f32;
+                                let out_value = sample_value * level;
+                                self.track_buffer[channel_idx][sample_idx] += out_value;
+                                if let Some(mosaic) = mosaic_buffer.as_mut() {
+                                    if mosaic_len > 0 && channel_idx < mosaic.len() {
+                                        let existing = mosaic[channel_idx][mosaic_write_pos];
+                                        mosaic[channel_idx][mosaic_write_pos] =
+                                            out_value * (1.0 - smooth_mosaic_sos)
+                                                + existing * smooth_mosaic_sos;
+                                    }
+                                }
+                            }
+
+                            if mosaic_buffer.is_some() && mosaic_len > 0 {
+                                mosaic_write_pos = (mosaic_write_pos + 1) % mosaic_len;
+                            }
+
+                            stretch.advance(head_dir, head_rate);
+                            prev_play_pos = play_pos;
+                            play_pos += direction as f32 * speed;
+                            if loop_active && loop_end > loop_start {
+                                if loop_mode == 1 {
+                                    if direction > 0 && play_pos >= loop_end as f32 && prev_play_pos < loop_end as f32 {
+                                        direction = -1;
+                                        play_pos = loop_end.saturating_sub(1) as f32;
+                                    } else if direction < 0 && play_pos <= loop_start as f32 && prev_play_pos > loop_start as f32 {
+                                        direction = 1;
+                                        play_pos = loop_start as f32;
+                                    }
+                                } else if direction > 0 && play_pos >= loop_end as f32 && prev_play_pos < loop_end as f32 {
+                                    play_pos = loop_start as f32;
+                                } else if direction < 0 && play_pos < loop_start as f32 && prev_play_pos >= loop_start as f32 {
+                                    play_pos = loop_end.saturating_sub(1) as f32;
+                                }
                             }
                         } else if keylock_enabled {
                             let speed = smooth_speed + speed_step * sample_idx as f32;
@@ -5222,7 +7849,7 @@ impl Plugin for TLBX1 {
                                     play_pos = loop_end.saturating_sub(1) as f32;
                                 }
                             }
-                            if loop_mode != 2 {
+                            if loop_mode != 2 && !slicing {
                                 keylock_grain_a = wrap_loop_pos(
                                     keylock_grain_a,
                                     loop_start,
@@ -5308,6 +7935,12 @@ impl Plugin for TLBX1 {
                     }
 
                     track.play_pos.store(play_pos.to_bits(), Ordering::Relaxed);
+                    if slicing {
+                        track
+                            .tape_slice_playing
+                            .store(slice_playing, Ordering::Relaxed);
+                        track.tape_slice_step.store(slice_step, Ordering::Relaxed);
+                    }
                     if mosaic_buffer.is_some() && mosaic_len > 0 {
                         track
                             .mosaic_write_pos
@@ -5317,7 +7950,9 @@ impl Plugin for TLBX1 {
                     track
                         .tape_speed_smooth
                         .store(smooth_speed.to_bits(), Ordering::Relaxed);
-                    if tape_keylock {
+                    if stretch_enabled {
+                        stretch.store(track);
+                    } else if tape_keylock {
                         track
                             .keylock_phase
                             .store(keylock_phase.to_bits(), Ordering::Relaxed);
@@ -5342,31 +7977,35 @@ impl Plugin for TLBX1 {
             Self::process_track_mosaic(
                 track,
                 &mut self.track_buffer,
-                buffer.samples(),
+                block_samples,
                 global_tempo,
                 master_step_count,
                 master_phase,
                 samples_per_step,
+                swing,
             );
-            Self::process_track_ring(track, &mut self.track_buffer, buffer.samples(), global_tempo);
+            Self::process_track_ring(track, &mut self.track_buffer, block_samples, global_tempo);
             Self::process_track_g8(
                 track,
                 &mut self.track_buffer,
-                buffer.samples(),
+                block_samples,
                 master_step_count,
                 master_phase,
                 samples_per_step,
+                swing,
                 master_sr,
             );
 
             let mix_gain = if track_muted && engine_type != 1 { 0.0 } else { 1.0 };
             // Sum track buffer to master output and calculate final peaks
-            let num_buffer_samples = buffer.samples();
-            let output = buffer.as_slice();
+            let num_buffer_samples = block_samples;
             for sample_idx in 0..num_buffer_samples {
                 for channel_idx in 0..output.len() {
                     let val = self.track_buffer[channel_idx][sample_idx] * mix_gain;
                     output[channel_idx][sample_idx] += val;
+                    if let Some(stem) = self.render_stems.get_mut(track_idx) {
+                        stem[channel_idx][sample_idx] = val;
+                    }
 
                     if channel_idx == 0 {
                         track_peak_left = track_peak_left.max(val.abs());
@@ -5377,7 +8016,7 @@ impl Plugin for TLBX1 {
             }
 
             // Update meters with final peaks
-            if output.len() == 1 && buffer.channels() > 1 {
+            if output.len() == 1 && block_channels > 1 {
                 track_peak_right = track_peak_left;
             }
             let prev_left = f32::from_bits(track.meter_left.load(Ordering::Relaxed));
@@ -5396,8 +8035,7 @@ impl Plugin for TLBX1 {
         let metronome_active = self.metronome_enabled.load(Ordering::Relaxed)
             && (any_playing || any_recording || any_pending);
         if metronome_active {
-            let num_buffer_samples = buffer.samples();
-            let output = buffer.as_slice();
+            let num_buffer_samples = block_samples;
             let sr = self.tracks[0].sample_rate.load(Ordering::Relaxed).max(1);
             let tempo = global_tempo.clamp(20.0, 240.0);
             let samples_per_beat =
@@ -5431,12 +8069,17 @@ impl Plugin for TLBX1 {
 
         // Master FX Chain
         let sr = self.sample_rate.load(Ordering::Relaxed) as f32;
-        let num_channels = buffer.channels();
-        let num_samples = buffer.samples();
+        let num_channels = block_channels;
+        let num_samples = block_samples;
 
         for sample_idx in 0..num_samples {
-            let master_filter = self.params.master_filter.smoothed.next();
-            let master_comp = self.params.master_comp.smoothed.next();
+            let (master_filter, master_comp) = match self.render_master {
+                Some(master) => (master.master_filter, master.master_comp),
+                None => (
+                    self.params.master_filter.smoothed.next(),
+                    self.params.master_comp.smoothed.next(),
+                ),
+            };
 
             // Calculate DJ Filter coefficients
             let mut filter_type = 0; // 0=None, 1=HP, 2=LP
@@ -5468,7 +8111,7 @@ impl Plugin for TLBX1 {
             
             // Process Filter + Find Max for Compressor
             for channel_idx in 0..num_channels {
-                let mut x = buffer.as_slice()[channel_idx][sample_idx];
+                let mut x = output[channel_idx][sample_idx];
                 
                 if filter_type > 0 {
                     let low = self.master_fx.filter_low[channel_idx];
@@ -5487,7 +8130,7 @@ impl Plugin for TLBX1 {
                     }
                 }
                 
-                buffer.as_slice()[channel_idx][sample_idx] = x;
+                output[channel_idx][sample_idx] = x;
                 max_abs = max_abs.max(x.abs());
             }
 
@@ -5509,23 +8152,41 @@ impl Plugin for TLBX1 {
             }
 
             // Apply global gain + compression
-            let gain = self.params.gain.smoothed.next();
+            let gain = match self.render_master {
+                Some(master) => master.gain,
+                None => self.params.gain.smoothed.next(),
+            };
             for channel_idx in 0..num_channels {
-                buffer.as_slice()[channel_idx][sample_idx] *= gain * reduction;
+                output[channel_idx][sample_idx] *= gain * reduction;
             }
         }
 
-        for channel_samples in buffer.iter_samples() {
-            for sample in channel_samples {
+        for channel in output.iter_mut() {
+            for sample in channel.iter_mut() {
                 if !sample.is_finite() {
                     *sample = 0.0;
                 }
             }
         }
 
+        // Feed the master recorder; its writer thread does the file I/O.
+        self.master_recorder
+            .sample_rate
+            .store(master_sr as u32, Ordering::Relaxed);
+        if self.render_master.is_none() && self.master_recorder.armed.load(Ordering::Relaxed) {
+            keep_alive = true;
+            if let Some(left) = output.first() {
+                let right = output.get(1).unwrap_or(left);
+                for sample_idx in 0..block_samples {
+                    self.master_recorder
+                        .push_frame(left[sample_idx], right[sample_idx]);
+                }
+            }
+        }
+
         // Update master output meters + visualizer data.
-        if !buffer.is_empty() {
-            let output = buffer.as_slice_immutable();
+        if block_samples > 0 {
+            let output = &*output;
             let left = output.get(0).map(|ch| ch.as_ref()).unwrap_or(&[]);
             let right = output
                 .get(1)
@@ -5585,52 +8246,390 @@ impl Plugin for TLBX1 {
                     }
                 }
 
-                let window_len = SPECTRUM_WINDOW.min(total_samples);
-                if window_len >= 2 {
-                    let bins = SPECTRUM_BINS.min(window_len / 2);
-                    if let Some(mut spectrum) = self.visualizer.spectrum.try_lock() {
-                        for bin in 0..bins {
-                            let mut re = 0.0_f32;
-                            let mut im = 0.0_f32;
-                            let bin_f = bin as f32;
-                            let win_f = window_len as f32;
-                            for i in 0..window_len {
-                                let sample = left[i];
-                                let phase = 2.0 * PI * bin_f * (i as f32) / win_f;
-                                re += sample * phase.cos();
-                                im -= sample * phase.sin();
-                            }
-                            let mag = (re * re + im * im).sqrt() / window_len as f32;
-                            let mag = mag.clamp(0.0, 1.0);
-                            // Compress dynamic range to make low-level movement more visible.
-                            let mag = (1.0_f32 + 20.0 * mag).ln() / (1.0_f32 + 20.0).ln();
-                            spectrum[bin] = mag.clamp(0.0, 1.0);
-                        }
-                        for bin in bins..SPECTRUM_BINS {
-                            spectrum[bin] = 0.0;
-                        }
+                let window_len = SPECTRUM_WINDOW.min(total_samples);
+                if window_len >= 2 {
+                    let bins = SPECTRUM_BINS.min(window_len / 2);
+                    if let Some(mut spectrum) = self.visualizer.spectrum.try_lock() {
+                        for bin in 0..bins {
+                            let mut re = 0.0_f32;
+                            let mut im = 0.0_f32;
+                            let bin_f = bin as f32;
+                            let win_f = window_len as f32;
+                            for i in 0..window_len {
+                                let sample = left[i];
+                                let phase = 2.0 * PI * bin_f * (i as f32) / win_f;
+                                re += sample * phase.cos();
+                                im -= sample * phase.sin();
+                            }
+                            let mag = (re * re + im * im).sqrt() / window_len as f32;
+                            let mag = mag.clamp(0.0, 1.0);
+                            // Compress dynamic range to make low-level movement more visible.
+                            let mag = (1.0_f32 + 20.0 * mag).ln() / (1.0_f32 + 20.0).ln();
+                            spectrum[bin] = mag.clamp(0.0, 1.0);
+                        }
+                        for bin in bins..SPECTRUM_BINS {
+                            spectrum[bin] = 0.0;
+                        }
+                    }
+                }
+            }
+        }
+
+        if any_playing || any_pending {
+            let mut phase = master_phase + block_samples as f32;
+            let mut step = master_step;
+            if samples_per_step > 0.0 {
+                while phase >= samples_per_step {
+                    phase -= samples_per_step;
+                    step = (step + 1).rem_euclid(16);
+                    master_step_count += 1;
+                }
+            }
+            self.master_step_phase = phase;
+            self.master_step_index = step;
+            self.master_step_count = master_step_count;
+            let ticks = master_step_count.max(0) as u64 * MIDI_CLOCK_TICKS_PER_STEP
+                + ((phase / samples_per_step) * MIDI_CLOCK_TICKS_PER_STEP as f32) as u64;
+            self.midi_clock.out_ticks.store(ticks, Ordering::Relaxed);
+            self.midi_clock
+                .out_playing
+                .store(any_playing, Ordering::Release);
+        } else {
+            self.master_step_phase = 0.0;
+            self.master_step_index = 0;
+            self.master_step_count = 0;
+            self.midi_clock.out_ticks.store(0, Ordering::Relaxed);
+            self.midi_clock.out_playing.store(false, Ordering::Release);
+        }
+
+        keep_alive
+    }
+}
+
+impl Plugin for TLBX1 {
+    const NAME: &'static str = "TLBX-1";
+    const VENDOR: &'static str = "Zencoder";
+    const URL: &'static str = "https://example.com";
+    const EMAIL: &'static str = "info@example.com";
+
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
+        AudioIOLayout {
+            // Input-enabled layout for recording/monitoring.
+            main_input_channels: NonZeroU32::new(2),
+            main_output_channels: NonZeroU32::new(2),
+            ..AudioIOLayout::const_default()
+        },
+        AudioIOLayout {
+            // Generator-style layout.
+            main_input_channels: None,
+            main_output_channels: NonZeroU32::new(2),
+            ..AudioIOLayout::const_default()
+        },
+    ];
+
+    const MIDI_INPUT: MidiConfig = MidiConfig::MidiCCs;
+    const MIDI_OUTPUT: MidiConfig = MidiConfig::None;
+
+    const SAMPLE_ACCURATE_AUTOMATION: bool = true;
+
+    type SysExMessage = ();
+    type BackgroundTask = TLBX1Task;
+
+    fn initialize(
+        &mut self,
+        _audio_io_layout: &AudioIOLayout,
+        buffer_config: &BufferConfig,
+        _context: &mut impl InitContext<Self>,
+    ) -> bool {
+        self.sample_rate.store(buffer_config.sample_rate as u32, Ordering::Relaxed);
+        self.track_buffer = vec![vec![0.0; buffer_config.max_buffer_size as usize]; 2];
+        true
+    }
+
+    fn params(&self) -> Arc<dyn Params> {
+        self.params.clone()
+    }
+
+    fn editor(&mut self, async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
+        Some(Box::new(SlintEditor {
+            params: self.params.clone(),
+            tracks: self.tracks.clone(),
+            master_meters: self.master_meters.clone(),
+            visualizer: self.visualizer.clone(),
+            global_tempo: self.global_tempo.clone(),
+            host_sample_rate: self.sample_rate.clone(),
+            follow_host_tempo: self.follow_host_tempo.clone(),
+            metronome_enabled: self.metronome_enabled.clone(),
+            metronome_count_in_ticks: self.metronome_count_in_ticks.clone(),
+            metronome_count_in_playback: self.metronome_count_in_playback.clone(),
+            metronome_count_in_record: self.metronome_count_in_record.clone(),
+            async_executor,
+            pending_project_params: self.pending_project_params.clone(),
+            animate_library: self.animate_library.clone(),
+            master_recorder: self.master_recorder.clone(),
+            midi_cc: self.midi_cc.clone(),
+            midi_clock: self.midi_clock.clone(),
+        }))
+    }
+
+    fn task_executor(&mut self) -> TaskExecutor<Self> {
+        let tracks = self.tracks.clone();
+        let global_tempo = self.global_tempo.clone();
+        let params = self.params.clone();
+        let pending_project_params = self.pending_project_params.clone();
+        let animate_library = self.animate_library.clone();
+        let midi_cc = self.midi_cc.clone();
+        Box::new(move |task| match task {
+            TLBX1Task::LoadSample(track_idx, path) => {
+                if track_idx >= NUM_TRACKS {
+                    return;
+                }
+                
+                match load_media_file(&path) {
+                    Ok((new_samples, sample_rate, video)) => {
+                        // Analyse before taking the lock the audio thread reads through.
+                        let flux = tape_onset_flux(&new_samples);
+                        let slices = detect_tape_slices(&new_samples, &flux, sample_rate);
+                        let tempo = detect_tape_tempo(&new_samples, &flux, sample_rate);
+                        // A take in progress would keep writing into the replaced buffer.
+                        tracks[track_idx]
+                            .is_recording
+                            .store(false, Ordering::Relaxed);
+                        tracks[track_idx]
+                            .pending_record
+                            .store(false, Ordering::Relaxed);
+                        tracks[track_idx]
+                            .record_appending
+                            .store(false, Ordering::Relaxed);
+                        tracks[track_idx]
+                            .record_generation
+                            .fetch_add(1, Ordering::AcqRel);
+                        let mut samples = tracks[track_idx].samples.lock();
+                        let mut summary = tracks[track_idx].waveform_summary.lock();
+                        let mut sample_path = tracks[track_idx].sample_path.lock();
+                        let mut video_cache = tracks[track_idx].video_cache.lock();
+
+                        *samples = new_samples;
+                        *sample_path = Some(path.clone());
+                        tracks[track_idx]
+                            .sample_rate
+                            .store(sample_rate, Ordering::Relaxed);
+
+                        if let Some(video) = video {
+                            tracks[track_idx]
+                                .video_enabled
+                                .store(true, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_width
+                                .store(video.width, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_height
+                                .store(video.height, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_fps
+                                .store(video.fps.to_bits(), Ordering::Relaxed);
+                            *video_cache = Some(video);
+                            tracks[track_idx]
+                                .video_cache_id
+                                .fetch_add(1, Ordering::Relaxed);
+                        } else {
+                            tracks[track_idx]
+                                .video_enabled
+                                .store(false, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_width
+                                .store(0, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_height
+                                .store(0, Ordering::Relaxed);
+                            tracks[track_idx]
+                                .video_fps
+                                .store(0.0f32.to_bits(), Ordering::Relaxed);
+                            *video_cache = None;
+                            tracks[track_idx]
+                                .video_cache_id
+                                .fetch_add(1, Ordering::Relaxed);
+                        }
+
+                        if !samples.is_empty() {
+                            calculate_waveform_summary(&samples[0], &mut summary);
+                        } else {
+                            summary.fill(0.0);
+                        }
+                        store_tape_slices(&tracks[track_idx], &slices, true);
+                        store_tape_tempo(&tracks[track_idx], tempo);
+
+                        nih_log!("Loaded media: {:?}", path);
+                    }
+                    Err(e) => {
+                        tracks[track_idx]
+                            .video_enabled
+                            .store(false, Ordering::Relaxed);
+                        tracks[track_idx]
+                            .video_cache_id
+                            .fetch_add(1, Ordering::Relaxed);
+                        *tracks[track_idx].video_cache.lock() = None;
+                        nih_log!("Failed to load media: {:?}", e);
+                    }
+                }
+            }
+            TLBX1Task::SaveProject {
+                path,
+                title,
+                description,
+            } => {
+                let tempo = f32::from_bits(global_tempo.load(Ordering::Relaxed));
+                if let Err(err) =
+                    save_project(&tracks, tempo, &params, &midi_cc, &title, &description, &path)
+                {
+                    nih_log!("Failed to save project: {:?}", err);
+                } else {
+                    nih_log!("Saved project: {:?}", path);
+                }
+            }
+            TLBX1Task::LoadProject(path) => {
+                if let Err(err) = load_project(
+                    &tracks,
+                    &global_tempo,
+                    &params,
+                    &pending_project_params,
+                    &midi_cc,
+                    &path,
+                ) {
+                    nih_log!("Failed to load project: {:?}", err);
+                } else {
+                    for track in tracks.iter() {
+                        animate_library.request_track(track);
+                    }
+                    nih_log!("Loaded project: {:?}", path);
+                }
+            }
+            TLBX1Task::ExportProjectZip {
+                path,
+                title,
+                description,
+            } => {
+                let tempo = f32::from_bits(global_tempo.load(Ordering::Relaxed));
+                if let Err(err) = export_project_as_zip(
+                    &tracks,
+                    tempo,
+                    &params,
+                    &midi_cc,
+                    &title,
+                    &description,
+                    &path,
+                ) {
+                    nih_log!("Failed to export project: {:?}", err);
+                } else {
+                    nih_log!("Exported project zip: {:?}", path);
+                }
+            }
+            TLBX1Task::RenderAudio { path, options } => {
+                let tempo = f32::from_bits(global_tempo.load(Ordering::Relaxed));
+                if let Err(err) =
+                    render_offline(&tracks, &animate_library, tempo, &params, options, &path)
+                {
+                    nih_log!("Failed to render audio: {:?}", err);
+                } else {
+                    nih_log!("Rendered audio: {:?}", path);
+                }
+            }
+            TLBX1Task::RequestAnimateLoads(track_idx) => {
+                if let Some(track) = tracks.get(track_idx) {
+                    animate_library.request_track(track);
+                }
+            }
+            TLBX1Task::MidiCc { channel, cc, value } => {
+                handle_midi_cc(&tracks, &midi_cc, channel, cc, value);
+                // A mapped CC can switch a slot's wavetable or sample.
+                for track in tracks.iter() {
+                    animate_library.request_track(track);
+                }
+            }
+        })
+    }
+
+    fn process(
+        &mut self,
+        buffer: &mut Buffer,
+        _aux: &mut AuxiliaryBuffers,
+        context: &mut impl ProcessContext<Self>,
+    ) -> ProcessStatus {
+        self.midi_events.clear();
+        while let Some(event) = context.next_event() {
+            let note_event = match event {
+                NoteEvent::NoteOn {
+                    timing,
+                    channel,
+                    note,
+                    velocity,
+                    ..
+                } => Some(MidiNoteEvent {
+                    timing,
+                    channel,
+                    note,
+                    velocity,
+                    is_on: velocity > 0.0,
+                }),
+                NoteEvent::NoteOff {
+                    timing,
+                    channel,
+                    note,
+                    velocity,
+                    ..
+                } => Some(MidiNoteEvent {
+                    timing,
+                    channel,
+                    note,
+                    velocity,
+                    is_on: false,
+                }),
+                NoteEvent::MidiCC {
+                    channel, cc, value, ..
+                } => {
+                    // Mapping lookups and parameter writes happen on the background thread.
+                    if self.midi_cc.learn_armed.load(Ordering::Relaxed)
+                        || self.midi_cc.has_mappings.load(Ordering::Relaxed)
+                    {
+                        context.execute_background(TLBX1Task::MidiCc { channel, cc, value });
                     }
+                    None
+                }
+                _ => None,
+            };
+            // The queue is preallocated; drop events rather than allocate on the audio thread.
+            if let Some(note_event) = note_event {
+                if self.midi_events.len() < self.midi_events.capacity() {
+                    self.midi_events.push(note_event);
                 }
             }
         }
+        let transport = context.transport();
+        let host_transport = HostTransport {
+            playing: transport.playing,
+            pos_beats: transport.pos_beats(),
+            bar_start_beats: transport.bar_start_pos_beats(),
+            bar_number: transport.bar_number(),
+        };
+        let keep_alive = self.process_block(
+            buffer.as_slice(),
+            transport.tempo.map(|tempo| tempo as f32),
+            transport.sample_rate,
+            host_transport,
+        );
 
-        if any_playing || any_pending {
-            let mut phase = master_phase + buffer.samples() as f32;
-            let mut step = master_step;
-            if samples_per_step > 0.0 {
-                while phase >= samples_per_step {
-                    phase -= samples_per_step;
-                    step = (step + 1).rem_euclid(16);
-                    master_step_count += 1;
+        // Slot sources can change without the editor (automation, restored state), so
+        // queue their files from here rather than relying on each caller.
+        for (idx, track) in self.tracks.iter().enumerate() {
+            let sources = animate_slot_sources(track);
+            if sources != self.animate_sources_seen[idx] {
+                self.animate_sources_seen[idx] = sources;
+                if sources.is_some() {
+                    context.execute_background(TLBX1Task::RequestAnimateLoads(idx));
                 }
             }
-            self.master_step_phase = phase;
-            self.master_step_index = step;
-            self.master_step_count = master_step_count;
-        } else {
-            self.master_step_phase = 0.0;
-            self.master_step_index = 0;
-            self.master_step_count = 0;
         }
 
         if keep_alive {
@@ -5698,6 +8697,564 @@ fn lfo_waveform_value(waveform: u32, phase: f32, sample_hold: f32) -> f32 {
     }
 }
 
+/// MIDI note for an Animate sequencer lane: lanes walk up the scale from the root and
+/// wrap into the next octave.
+fn animate_lane_note(scale: u32, root: u32, lane: usize) -> u32 {
+    let intervals = ANIMATE_SCALES[(scale as usize).min(ANIMATE_SCALES.len() - 1)].1;
+    let octave = (lane / intervals.len()) as u32;
+    (root + octave * 12 + intervals[lane % intervals.len()]).min(127)
+}
+
+/// Note an Animate lane plays on `step`, taking the step's note override into account.
+fn animate_resolve_step_note(track: &Track, lane: usize, step: usize) -> u32 {
+    if track.animate_step_note_override[lane][step].load(Ordering::Relaxed) {
+        track.animate_step_note[lane][step].load(Ordering::Relaxed).min(127)
+    } else {
+        animate_lane_note(
+            track.animate_scale.load(Ordering::Relaxed),
+            track.animate_root_note.load(Ordering::Relaxed),
+            lane,
+        )
+    }
+}
+
+/// Current (unlocked) values of the lockable Animate parameters.
+fn animate_lock_base(track: &Track) -> [f32; ANIMATE_LOCK_PARAMS] {
+    let f = |a: &AtomicU32| f32::from_bits(a.load(Ordering::Relaxed));
+    let mut base = [0.0f32; ANIMATE_LOCK_PARAMS];
+    base[ANIMATE_LOCK_VECTOR_X] = f(&track.animate_vector_x);
+    base[ANIMATE_LOCK_VECTOR_Y] = f(&track.animate_vector_y);
+    for slot in 0..4 {
+        base[ANIMATE_LOCK_SLOT_LEVEL + slot] = f(&track.animate_slot_level[slot]);
+        base[ANIMATE_LOCK_SLOT_CUTOFF + slot] = f(&track.animate_slot_filter_cutoff[slot]);
+        base[ANIMATE_LOCK_SLOT_RESONANCE + slot] = f(&track.animate_slot_filter_resonance[slot]);
+        base[ANIMATE_LOCK_SLOT_COARSE + slot] = f(&track.animate_slot_coarse[slot]);
+    }
+    base
+}
+
+fn animate_lock_range(param: usize) -> (f32, f32) {
+    if param >= ANIMATE_LOCK_SLOT_COARSE {
+        (-24.0, 24.0)
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+/// Storage behind each `ANIMATE_SLOT_ENV_KEYS` parameter, indexed by slot.
+fn animate_slot_env_param(track: &Track, param: usize) -> &[AtomicU32; 4] {
+    match param {
+        0 => &track.animate_slot_amp_attack,
+        1 => &track.animate_slot_amp_decay,
+        2 => &track.animate_slot_amp_sustain,
+        3 => &track.animate_slot_amp_release,
+        4 => &track.animate_slot_filter_env_attack,
+        5 => &track.animate_slot_filter_env_decay,
+        6 => &track.animate_slot_filter_env_sustain,
+        7 => &track.animate_slot_filter_env_release,
+        _ => &track.animate_slot_filter_env_amount,
+    }
+}
+
+fn animate_slot_env_range(param: usize) -> (f32, f32) {
+    match param {
+        2 | 6 => (0.0, 1.0),
+        8 => (-1.0, 1.0),
+        _ => (0.0, ANIMATE_ENV_TIME_MAX),
+    }
+}
+
+/// Advances an ADSR envelope by one sample. Stages are 0 = idle, 1 = attack, 2 = decay,
+/// 3 = sustain, 4 = release; `coefs` are the attack, decay, and release pole coefficients.
+fn adsr_tick(stage: &mut u32, level: &mut f32, coefs: [f32; 3], sustain: f32) {
+    match *stage {
+        1 => {
+            *level = *level * coefs[0] + 1.1 * (1.0 - coefs[0]);
+            if *level >= 1.0 {
+                *level = 1.0;
+                *stage = 2;
+            }
+        }
+        2 => {
+            *level = *level * coefs[1] + sustain * (1.0 - coefs[1]);
+            if (*level - sustain).abs() < 0.001 {
+                *level = sustain;
+                *stage = 3;
+            }
+        }
+        3 => *level = sustain,
+        4 => {
+            *level *= coefs[2];
+            if *level < 0.0001 {
+                *level = 0.0;
+                *stage = 0;
+            }
+        }
+        _ => *level = 0.0,
+    }
+}
+
+/// One sample of a four-pole zero-delay-feedback ladder lowpass (Moog style). `resonance`
+/// (0..1) sets the feedback up to self-oscillation, with the input boosted to make up the
+/// passband loss; `drive` (0..1) pushes harder into the tanh stage, which keeps the loop
+/// bounded while the cutoff is modulated.
+fn ladder_filter_tick(
+    state: &mut [f32; 4],
+    input: f32,
+    cutoff_hz: f32,
+    resonance: f32,
+    drive: f32,
+    sr: f32,
+) -> f32 {
+    let fc = cutoff_hz.clamp(10.0, sr * 0.45);
+    let g = (std::f32::consts::PI * fc / sr).tan();
+    let stage_gain = g / (1.0 + g);
+    let k = 4.0 * resonance.clamp(0.0, 1.0);
+    let drive_gain = 1.0 + 4.0 * drive.clamp(0.0, 1.0);
+    let feedback = (stage_gain.powi(3) * state[0]
+        + stage_gain.powi(2) * state[1]
+        + stage_gain * state[2]
+        + state[3])
+        / (1.0 + g);
+    let x = input * drive_gain * (1.0 + k);
+    let mut y = ((x - k * feedback) / (1.0 + k * stage_gain.powi(4))).tanh();
+    for s in state.iter_mut() {
+        let v = (y - *s) * stage_gain;
+        y = v + *s;
+        *s = y + v;
+    }
+    y / drive_gain.sqrt()
+}
+
+/// Block-local copy of an Animate vector path, with the time (seconds) each point is
+/// reached.
+#[derive(Clone, Copy)]
+struct VectorPath {
+    len: usize,
+    x: [f32; ANIMATE_VECTOR_PATH_POINTS],
+    y: [f32; ANIMATE_VECTOR_PATH_POINTS],
+    at: [f32; ANIMATE_VECTOR_PATH_POINTS],
+    loop_range: Option<(f32, f32)>,
+}
+
+impl VectorPath {
+    fn load(track: &Track) -> Self {
+        let f = |a: &AtomicU32| f32::from_bits(a.load(Ordering::Relaxed));
+        let len = (track.animate_vector_path_len.load(Ordering::Relaxed) as usize)
+            .min(ANIMATE_VECTOR_PATH_POINTS);
+        let mut path = Self {
+            len,
+            x: [0.5; ANIMATE_VECTOR_PATH_POINTS],
+            y: [0.5; ANIMATE_VECTOR_PATH_POINTS],
+            at: [0.0; ANIMATE_VECTOR_PATH_POINTS],
+            loop_range: None,
+        };
+        let mut at = 0.0f32;
+        for i in 0..len {
+            if i > 0 {
+                at += f(&track.animate_vector_path_time[i]).max(0.0);
+            }
+            path.x[i] = f(&track.animate_vector_path_x[i]).clamp(0.0, 1.0);
+            path.y[i] = f(&track.animate_vector_path_y[i]).clamp(0.0, 1.0);
+            path.at[i] = at;
+        }
+        if track.animate_vector_path_loop.load(Ordering::Relaxed) && len > 1 {
+            let start =
+                (track.animate_vector_path_loop_start.load(Ordering::Relaxed) as usize).min(len - 1);
+            let end =
+                (track.animate_vector_path_loop_end.load(Ordering::Relaxed) as usize).min(len - 1);
+            if end > start && path.at[end] > path.at[start] {
+                path.loop_range = Some((path.at[start], path.at[end]));
+            }
+        }
+        path
+    }
+
+    /// Folds a playback position back into the loop once it passes the loop end.
+    fn wrap(&self, pos: f32) -> f32 {
+        match self.loop_range {
+            Some((start, end)) if pos >= end => start + (pos - end) % (end - start),
+            _ => pos,
+        }
+    }
+
+    /// Pad position at `pos` seconds, holding the last point once the path ends.
+    fn position(&self, pos: f32) -> (f32, f32) {
+        if self.len == 0 {
+            return (0.5, 0.5);
+        }
+        for i in 1..self.len {
+            if pos < self.at[i] {
+                let span = self.at[i] - self.at[i - 1];
+                let t = if span > 0.0 {
+                    ((pos - self.at[i - 1]) / span).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                return (
+                    self.x[i - 1] + (self.x[i] - self.x[i - 1]) * t,
+                    self.y[i - 1] + (self.y[i] - self.y[i - 1]) * t,
+                );
+            }
+        }
+        (self.x[self.len - 1], self.y[self.len - 1])
+    }
+}
+
+fn push_vector_path_point(track: &Track, index: usize, x: f32, y: f32, time: f32) {
+    track.animate_vector_path_x[index].store(x.to_bits(), Ordering::Relaxed);
+    track.animate_vector_path_y[index].store(y.to_bits(), Ordering::Relaxed);
+    track.animate_vector_path_time[index].store(time.to_bits(), Ordering::Relaxed);
+}
+
+/// Records the XY pad into the vector path while armed and the transport runs. A point
+/// is added once the pad has moved and at least `ANIMATE_VECTOR_PATH_REC_INTERVAL` has
+/// passed; after a pause, a hold point keeps the pad still until the move starts.
+fn record_vector_path(track: &Track, num_samples: usize, sr: f32, tempo: f32) {
+    let mut len = (track.animate_vector_path_len.load(Ordering::Relaxed) as usize)
+        .min(ANIMATE_VECTOR_PATH_POINTS);
+    let x = f32::from_bits(track.animate_vector_x.load(Ordering::Relaxed)).clamp(0.0, 1.0);
+    let y = f32::from_bits(track.animate_vector_y.load(Ordering::Relaxed)).clamp(0.0, 1.0);
+    let mut elapsed = track
+        .animate_vector_path_rec_elapsed
+        .load(Ordering::Relaxed)
+        .saturating_add(num_samples as u32);
+    if len == 0 {
+        push_vector_path_point(track, 0, x, y, 0.0);
+        track.animate_vector_path_tempo.store(tempo.to_bits(), Ordering::Relaxed);
+        len = 1;
+        elapsed = 0;
+    } else if len < ANIMATE_VECTOR_PATH_POINTS {
+        let last_x = f32::from_bits(track.animate_vector_path_x[len - 1].load(Ordering::Relaxed));
+        let last_y = f32::from_bits(track.animate_vector_path_y[len - 1].load(Ordering::Relaxed));
+        let moved = (x - last_x).abs().max((y - last_y).abs()) > ANIMATE_VECTOR_PATH_REC_THRESHOLD;
+        let interval = ANIMATE_VECTOR_PATH_REC_INTERVAL * sr;
+        let elapsed_samples = elapsed as f32;
+        if moved && elapsed_samples >= interval {
+            if elapsed_samples >= 2.0 * interval && len + 1 < ANIMATE_VECTOR_PATH_POINTS {
+                push_vector_path_point(track, len, last_x, last_y, (elapsed_samples - interval) / sr);
+                len += 1;
+                push_vector_path_point(track, len, x, y, interval / sr);
+            } else {
+                push_vector_path_point(track, len, x, y, elapsed_samples / sr);
+            }
+            len += 1;
+            elapsed = 0;
+        }
+    } else {
+        // Path is full: stop recording and play it back as if Rec had been turned off.
+        track.animate_vector_path_recording.store(false, Ordering::Relaxed);
+        track.animate_vector_path_enabled.store(true, Ordering::Relaxed);
+        track
+            .animate_vector_path_loop_end
+            .store((len - 1) as u32, Ordering::Relaxed);
+    }
+    track.animate_vector_path_len.store(len as u32, Ordering::Relaxed);
+    track.animate_vector_path_rec_elapsed.store(elapsed, Ordering::Relaxed);
+}
+
+/// Whether an Animate voice gate is still held (triggered or sustaining).
+fn animate_voice_held(stage: u32) -> bool {
+    stage == 1 || stage == 2
+}
+
+/// Block-local copy of the Animate voice pool. Every note source (sequencer lanes,
+/// external MIDI and the keybed) starts and stops notes through it, so they share the
+/// track's polyphony, voice mode and stealing policy. `stages` are the voice gates.
+struct AnimateVoices {
+    mode: u32,
+    polyphony: usize,
+    steal: u32,
+    unison: usize,
+    /// Unison spread either side of the note, in semitones.
+    detune: f32,
+    glide_coef: f32,
+    note: [i32; ANIMATE_VOICES],
+    source: [u32; ANIMATE_VOICES],
+    velocity: [f32; ANIMATE_VOICES],
+    age: [u32; ANIMATE_VOICES],
+    pitch: [f32; ANIMATE_VOICES],
+    offset: [f32; ANIMATE_VOICES],
+    gate: [u32; ANIMATE_VOICES],
+    counter: u32,
+    last_pitch: f32,
+}
+
+impl AnimateVoices {
+    fn load(track: &Track, sr: f32) -> Self {
+        let f = |a: &AtomicU32| f32::from_bits(a.load(Ordering::Relaxed));
+        let glide = f(&track.animate_glide).clamp(0.0, ANIMATE_GLIDE_MAX);
+        Self {
+            mode: track
+                .animate_voice_mode
+                .load(Ordering::Relaxed)
+                .min(ANIMATE_VOICE_MODES.len() as u32 - 1),
+            polyphony: (track.animate_polyphony.load(Ordering::Relaxed) as usize)
+                .clamp(1, ANIMATE_VOICES),
+            steal: track.animate_steal_mode.load(Ordering::Relaxed),
+            unison: track
+                .animate_unison_voices
+                .load(Ordering::Relaxed)
+                .clamp(2, ANIMATE_UNISON_MAX) as usize,
+            detune: f(&track.animate_unison_detune).clamp(0.0, 1.0) * ANIMATE_UNISON_DETUNE_CENTS
+                / 100.0,
+            glide_coef: if glide > 0.001 {
+                (-1.0 / (glide * sr)).exp()
+            } else {
+                0.0
+            },
+            note: std::array::from_fn(|v| track.animate_voice_note[v].load(Ordering::Relaxed)),
+            source: std::array::from_fn(|v| track.animate_voice_source[v].load(Ordering::Relaxed)),
+            velocity: std::array::from_fn(|v| f(&track.animate_voice_velocity[v])),
+            age: std::array::from_fn(|v| track.animate_voice_age[v].load(Ordering::Relaxed)),
+            pitch: std::array::from_fn(|v| f(&track.animate_voice_pitch[v])),
+            offset: std::array::from_fn(|v| f(&track.animate_voice_detune[v])),
+            gate: std::array::from_fn(|v| track.animate_voice_gate[v].load(Ordering::Relaxed)),
+            counter: track.animate_voice_counter.load(Ordering::Relaxed),
+            last_pitch: f(&track.animate_last_pitch),
+        }
+    }
+
+    fn store(&self, track: &Track) {
+        for v in 0..ANIMATE_VOICES {
+            track.animate_voice_note[v].store(self.note[v], Ordering::Relaxed);
+            track.animate_voice_source[v].store(self.source[v], Ordering::Relaxed);
+            track.animate_voice_velocity[v].store(self.velocity[v].to_bits(), Ordering::Relaxed);
+            track.animate_voice_age[v].store(self.age[v], Ordering::Relaxed);
+            track.animate_voice_pitch[v].store(self.pitch[v].to_bits(), Ordering::Relaxed);
+            track.animate_voice_detune[v].store(self.offset[v].to_bits(), Ordering::Relaxed);
+            track.animate_voice_gate[v].store(self.gate[v], Ordering::Relaxed);
+        }
+        track.animate_voice_counter.store(self.counter, Ordering::Relaxed);
+        track
+            .animate_last_pitch
+            .store(self.last_pitch.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Starts `note` from `source` and returns the mask of voices that were triggered.
+    /// A non-zero `gate` releases the note after that many samples. Mono and legato play
+    /// on voice 0 (legato only retriggers once the previous note is released); unison
+    /// stacks several detuned voices.
+    fn note_on(
+        &mut self,
+        stages: &mut [u32; ANIMATE_VOICES],
+        levels: &[f32; ANIMATE_VOICES],
+        source: u32,
+        note: i32,
+        velocity: f32,
+        gate: u32,
+    ) -> u32 {
+        let mut triggered = 0u32;
+        if self.mode == ANIMATE_VOICE_MODE_MONO || self.mode == ANIMATE_VOICE_MODE_LEGATO {
+            let tied = self.mode == ANIMATE_VOICE_MODE_LEGATO && animate_voice_held(stages[0]);
+            let from = if stages[0] != 0 { self.pitch[0] } else { self.last_pitch };
+            self.assign(0, source, note, velocity, gate, from);
+            self.offset[0] = 0.0;
+            if !tied {
+                stages[0] = 1;
+                triggered = 1;
+            }
+        } else {
+            let count = if self.mode == ANIMATE_VOICE_MODE_UNISON {
+                self.unison.min(self.polyphony)
+            } else {
+                1
+            };
+            let gain = velocity / (count as f32).sqrt();
+            for k in 0..count {
+                let voice = self.pick(stages, levels, source, note, triggered);
+                let spread = if count > 1 {
+                    2.0 * k as f32 / (count - 1) as f32 - 1.0
+                } else {
+                    0.0
+                };
+                self.assign(voice, source, note, gain, gate, self.last_pitch);
+                self.offset[voice] = spread * self.detune;
+                stages[voice] = 1;
+                triggered |= 1 << voice;
+            }
+        }
+        self.last_pitch = note as f32;
+        triggered
+    }
+
+    /// Releases the held voices of `source`, or only those playing `note`.
+    fn note_off(&mut self, stages: &mut [u32; ANIMATE_VOICES], source: u32, note: Option<i32>) {
+        for (v, stage) in stages.iter_mut().enumerate() {
+            if animate_voice_held(*stage)
+                && self.source[v] == source
+                && note.is_none_or(|note| self.note[v] == note)
+            {
+                *stage = 4;
+            }
+        }
+    }
+
+    /// Whether `source` still holds a voice.
+    fn held(&self, stages: &[u32; ANIMATE_VOICES], source: u32) -> bool {
+        stages
+            .iter()
+            .zip(&self.source)
+            .any(|(stage, voice_source)| animate_voice_held(*stage) && *voice_source == source)
+    }
+
+    /// Moves the held voices of `source` to `note` without retriggering them (a tie).
+    fn retune(&mut self, stages: &[u32; ANIMATE_VOICES], source: u32, note: i32) {
+        for (v, stage) in stages.iter().enumerate() {
+            if animate_voice_held(*stage) && self.source[v] == source {
+                self.note[v] = note;
+                if self.glide_coef == 0.0 {
+                    self.pitch[v] = note as f32;
+                }
+            }
+        }
+        self.last_pitch = note as f32;
+    }
+
+    /// Advances glide and auto-release gates by one sample.
+    fn tick(&mut self, stages: &mut [u32; ANIMATE_VOICES]) {
+        for (v, stage) in stages.iter_mut().enumerate() {
+            if *stage == 0 {
+                continue;
+            }
+            let target = self.note[v] as f32;
+            self.pitch[v] = target + (self.pitch[v] - target) * self.glide_coef;
+            if self.gate[v] > 0 {
+                self.gate[v] -= 1;
+                if self.gate[v] == 0 && animate_voice_held(*stage) {
+                    *stage = 4;
+                }
+            }
+        }
+    }
+
+    fn assign(
+        &mut self,
+        voice: usize,
+        source: u32,
+        note: i32,
+        velocity: f32,
+        gate: u32,
+        from: f32,
+    ) {
+        self.counter = self.counter.wrapping_add(1);
+        self.note[voice] = note;
+        self.source[voice] = source;
+        self.velocity[voice] = velocity;
+        self.age[voice] = self.counter;
+        self.gate[voice] = gate;
+        self.pitch[voice] = if self.glide_coef > 0.0 && from >= 0.0 {
+            from
+        } else {
+            note as f32
+        };
+    }
+
+    /// Voice for a new note within the polyphony limit, skipping `taken`: the voice
+    /// already playing this note, else a free one, else the quietest releasing one,
+    /// else one stolen by the track's policy.
+    fn pick(
+        &self,
+        stages: &[u32; ANIMATE_VOICES],
+        levels: &[f32; ANIMATE_VOICES],
+        source: u32,
+        note: i32,
+        taken: u32,
+    ) -> usize {
+        let pool = || (0..self.polyphony).filter(move |&v| taken & (1 << v) == 0);
+        let quieter = |a: &usize, b: &usize| levels[*a].total_cmp(&levels[*b]);
+        pool()
+            .find(|&v| stages[v] != 0 && self.source[v] == source && self.note[v] == note)
+            .or_else(|| pool().find(|&v| stages[v] == 0))
+            .or_else(|| pool().filter(|&v| stages[v] == 4).min_by(quieter))
+            .or_else(|| {
+                if self.steal == ANIMATE_STEAL_QUIETEST {
+                    pool().min_by(quieter)
+                } else {
+                    pool().min_by_key(|&v| self.age[v])
+                }
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Picks the sample every sample slot plays for the voices in `triggered`
+/// (the key zone and velocity layer of a multisample slot) and resets its
+/// playback position.
+fn animate_reset_sample_starts(
+    track: &Track,
+    animate_library: &AnimateLibrary,
+    voices: &AnimateVoices,
+    sample_start: &[f32; 4],
+    triggered: u32,
+) {
+    if triggered == 0 {
+        return;
+    }
+    let default_root = f32::from_bits(track.animate_sample_default_root.load(Ordering::Relaxed));
+    for slot in 0..4 {
+        if track.animate_slot_types[slot].load(Ordering::Relaxed) != 1 {
+            continue;
+        }
+        let smp_idx = track.animate_slot_samples[slot].load(Ordering::Relaxed) as usize;
+        let multisample = track.animate_slot_multisample[slot].load(Ordering::Relaxed);
+        for voice in 0..ANIMATE_VOICES {
+            if triggered & (1 << voice) == 0 {
+                continue;
+            }
+            let voice_idx = if multisample {
+                animate_library.pick_sample_zone(
+                    smp_idx,
+                    voices.note[voice] as f32,
+                    voices.velocity[voice],
+                    default_root,
+                )
+            } else {
+                smp_idx
+            };
+            track.animate_slot_voice_sample[voice][slot].store(voice_idx as u32, Ordering::Relaxed);
+            let len = animate_library
+                .get_sample_cached(voice_idx)
+                .and_then(|smp| smp.channels.first())
+                .map_or(0, |ch| ch.len());
+            let start = (sample_start[slot] * (len.saturating_sub(1) as f32))
+                .round()
+                .clamp(0.0, (len.saturating_sub(1)) as f32);
+            track.animate_slot_sample_pos[voice][slot].store(start.to_bits(), Ordering::Relaxed);
+        }
+    }
+}
+
+/// Parameter values for `step`: each locked parameter takes its lock, the rest keep
+/// `base`, so a lock only lasts while its step is current.
+fn animate_step_locks(
+    track: &Track,
+    step: Option<usize>,
+    base: &[f32; ANIMATE_LOCK_PARAMS],
+) -> [f32; ANIMATE_LOCK_PARAMS] {
+    let mut values = *base;
+    if let Some(step) = step.filter(|step| *step < ANIMATE_SEQ_STEPS) {
+        for param in 0..ANIMATE_LOCK_PARAMS {
+            if track.animate_step_lock_enabled[step][param].load(Ordering::Relaxed) {
+                let (min, max) = animate_lock_range(param);
+                values[param] =
+                    f32::from_bits(track.animate_step_lock_values[step][param].load(Ordering::Relaxed))
+                        .clamp(min, max);
+            }
+        }
+    }
+    values
+}
+
+fn midi_pitch_frequency(pitch: f32) -> f32 {
+    (440.0 * 2.0_f32.powf((pitch - 69.0) / 12.0)).max(1.0)
+}
+
+fn midi_note_name(note: u32) -> String {
+    const NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+    format!("{}{}", NAMES[(note % 12) as usize], note as i32 / 12 - 1)
+}
+
 fn next_mosaic_rng(state: &mut u32) -> u32 {
     let mut x = *state;
     x ^= x << 13;
@@ -5726,10 +9283,35 @@ fn syndrm_rand_filter_type(state: &mut u32) -> u32 {
     idx.min(max - 1)
 }
 
+/// Lane bitmask for a single SynDRM lane index (0 = kick, 1 = snare, 2.. = voices).
+fn syndrm_lane_mask(lane: u32) -> u16 {
+    1 << lane.min(SYNDRM_LANES as u32 - 1)
+}
+
+fn syndrm_voice_lane_bit(voice: usize) -> u16 {
+    1 << (voice + 2)
+}
+
+
+fn syndrm_lane_step_active(track: &Track, lane: usize, step: usize) -> bool {
+    match lane {
+        0 => track.kick_sequencer_grid[step].load(Ordering::Relaxed),
+        1 => track.snare_sequencer_grid[step].load(Ordering::Relaxed),
+        _ => track.syndrm_voice_grid[lane - 2][step].load(Ordering::Relaxed),
+    }
+}
+
+fn syndrm_reset_step_trigger(track: &Track, lane: usize, step: usize) {
+    track.syndrm_step_velocity[lane][step].store(1.0f32.to_bits(), Ordering::Relaxed);
+    track.syndrm_step_probability[lane][step].store(1.0f32.to_bits(), Ordering::Relaxed);
+    track.syndrm_step_micro[lane][step].store(0.0f32.to_bits(), Ordering::Relaxed);
+    track.syndrm_step_ratchet[lane][step].store(1, Ordering::Relaxed);
+}
+
 fn syndrm_randomize_steps(
     track: &Track,
     rng_state: &mut u32,
-    lanes: u8,
+    lanes: u16,
     start: usize,
     len: usize,
 ) {
@@ -5741,13 +9323,19 @@ fn syndrm_randomize_steps(
         if (lanes & 0b10) != 0 {
             track.snare_sequencer_grid[step].store(syndrm_rand_bool(rng_state), Ordering::Relaxed);
         }
+        for voice in 0..SYNDRM_VOICES {
+            if (lanes & syndrm_voice_lane_bit(voice)) != 0 {
+                track.syndrm_voice_grid[voice][step]
+                    .store(syndrm_rand_bool(rng_state), Ordering::Relaxed);
+            }
+        }
     }
 }
 
 fn syndrm_randomize_params(
     track: &Track,
     rng_state: &mut u32,
-    lanes: u8,
+    lanes: u16,
     start: usize,
     len: usize,
 ) {
@@ -5793,12 +9381,21 @@ fn syndrm_randomize_params(
             track.snare_step_filter_resonance[step]
                 .store(syndrm_rand_unit(rng_state).to_bits(), Ordering::Relaxed);
         }
+        for voice in 0..SYNDRM_VOICES {
+            if (lanes & syndrm_voice_lane_bit(voice)) != 0 {
+                track.syndrm_voice_step_override_enabled[voice][step]
+                    .store(true, Ordering::Relaxed);
+                for param in track.syndrm_voice_step_params[voice][step].iter() {
+                    param.store(syndrm_rand_unit(rng_state).to_bits(), Ordering::Relaxed);
+                }
+            }
+        }
     }
 }
 
 fn syndrm_randomize_apply(
     track: &Track,
-    lanes: u8,
+    lanes: u16,
     start: usize,
     len: usize,
     randomize_steps: bool,
@@ -5814,7 +9411,7 @@ fn syndrm_randomize_apply(
     track.syndrm_rng_state.store(rng_state, Ordering::Relaxed);
 }
 
-fn syndrm_clear_steps(track: &Track, lanes: u8, start: usize, len: usize) {
+fn syndrm_clear_steps(track: &Track, lanes: u16, start: usize, len: usize) {
     let end = (start + len).min(SYNDRM_STEPS);
     for step in start..end {
         if (lanes & 0b01) != 0 {
@@ -5823,10 +9420,15 @@ fn syndrm_clear_steps(track: &Track, lanes: u8, start: usize, len: usize) {
         if (lanes & 0b10) != 0 {
             track.snare_sequencer_grid[step].store(false, Ordering::Relaxed);
         }
+        for voice in 0..SYNDRM_VOICES {
+            if (lanes & syndrm_voice_lane_bit(voice)) != 0 {
+                track.syndrm_voice_grid[voice][step].store(false, Ordering::Relaxed);
+            }
+        }
     }
 }
 
-fn syndrm_clear_params(track: &Track, lanes: u8, start: usize, len: usize) {
+fn syndrm_clear_params(track: &Track, lanes: u16, start: usize, len: usize) {
     let end = (start + len).min(SYNDRM_STEPS);
     let kick_pitch = f32::from_bits(track.kick_pitch.load(Ordering::Relaxed));
     let kick_decay = f32::from_bits(track.kick_decay.load(Ordering::Relaxed));
@@ -5875,12 +9477,29 @@ fn syndrm_clear_params(track: &Track, lanes: u8, start: usize, len: usize) {
             track.snare_step_filter_resonance[step]
                 .store(snare_filter_resonance.to_bits(), Ordering::Relaxed);
         }
+        for voice in 0..SYNDRM_VOICES {
+            if (lanes & syndrm_voice_lane_bit(voice)) != 0 {
+                track.syndrm_voice_step_override_enabled[voice][step]
+                    .store(false, Ordering::Relaxed);
+                for p in 0..SYNDRM_VOICE_PARAMS {
+                    track.syndrm_voice_step_params[voice][step][p].store(
+                        track.syndrm_voice_params[voice][p].load(Ordering::Relaxed),
+                        Ordering::Relaxed,
+                    );
+                }
+            }
+        }
+        for lane in 0..SYNDRM_LANES {
+            if (lanes & syndrm_lane_mask(lane as u32)) != 0 {
+                syndrm_reset_step_trigger(track, lane, step);
+            }
+        }
     }
 }
 
 fn syndrm_clear_apply(
     track: &Track,
-    lanes: u8,
+    lanes: u16,
     start: usize,
     len: usize,
     clear_steps: bool,
@@ -6026,6 +9645,129 @@ fn ring_quantize_freq(freq: f32, scale_mode: u32) -> f32 {
     440.0 * 2.0f32.powf((quant_note - 69.0) / 12.0)
 }
 
+/// Stops every track and clears any pending count-in.
+fn stop_transport(tracks: &[Track; NUM_TRACKS]) {
+    for track in tracks.iter() {
+        track.is_playing.store(false, Ordering::Relaxed);
+        track.pending_play.store(false, Ordering::Relaxed);
+        track.count_in_remaining.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Starts every track again from where it stopped, without rewinding.
+fn resume_transport(tracks: &[Track; NUM_TRACKS]) {
+    for track in tracks.iter() {
+        track.pending_play.store(false, Ordering::Relaxed);
+        track.count_in_remaining.store(0, Ordering::Relaxed);
+        track.is_playing.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Rewinds every track to its start position and starts playback, optionally
+/// after a metronome count-in.
+fn start_transport(
+    tracks: &[Track; NUM_TRACKS],
+    tempo: f32,
+    count_in_ticks: u32,
+    use_count_in: bool,
+) {
+    for track in tracks.iter() {
+        let loop_enabled = track.loop_enabled.load(Ordering::Relaxed);
+        let loop_mode = track.loop_mode.load(Ordering::Relaxed);
+        let loop_start_norm =
+            f32::from_bits(track.loop_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
+        let rotate_norm =
+            f32::from_bits(track.tape_rotate.load(Ordering::Relaxed)).clamp(0.0, 1.0);
+        let reverse_active = track.tape_reverse.load(Ordering::Relaxed) || loop_mode == 3;
+        let loop_start = if loop_enabled {
+            if let Some(samples) = track.samples.try_lock() {
+                let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
+                let rotate_offset = (rotate_norm * len as f32) as usize;
+                if reverse_active {
+                    let base_end = ((1.0 - loop_start_norm) * len as f32) as usize;
+                    let loop_end = (base_end + rotate_offset).min(len);
+                    let mut loop_len =
+                        (f32::from_bits(track.loop_length.load(Ordering::Relaxed)) * len as f32)
+                            as usize;
+                    if loop_len == 0 {
+                        loop_len = loop_end.max(1);
+                    }
+                    loop_end.saturating_sub(loop_len) as f32
+                } else {
+                    let base_start = (loop_start_norm * len as f32) as usize;
+                    ((base_start + rotate_offset) % len.max(1)) as f32
+                }
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+        let trigger_start_norm =
+            f32::from_bits(track.trigger_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
+        let trigger_start = if let Some(samples) = track.samples.try_lock() {
+            let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
+            let start_norm = if reverse_active {
+                (1.0 - trigger_start_norm).clamp(0.0, 0.999)
+            } else {
+                trigger_start_norm
+            };
+            (start_norm * len as f32) as f32
+        } else {
+            0.0
+        };
+        let direction = if loop_mode == 3 { -1 } else { 1 };
+        track.loop_dir.store(direction, Ordering::Relaxed);
+        if loop_mode == 4 {
+            if let Some(samples) = track.samples.try_lock() {
+                let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
+                let loop_len =
+                    (f32::from_bits(track.loop_length.load(Ordering::Relaxed)) * len as f32)
+                        as usize;
+                let loop_len = loop_len.max(1);
+                let loop_end = (loop_start as usize + loop_len).min(len).max(1);
+                let loop_start_usize = loop_start as usize;
+                if loop_end > loop_start_usize {
+                    let rand_pos =
+                        loop_start_usize + fastrand::usize(..(loop_end - loop_start_usize));
+                    track.play_pos.store((rand_pos as f32).to_bits(), Ordering::Relaxed);
+                } else {
+                    track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
+                }
+            } else {
+                track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
+            }
+        } else {
+            track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
+        }
+        track
+            .loop_start_last
+            .store(loop_start as u32, Ordering::Relaxed);
+        let mut direction = if loop_mode == 3 { -1 } else { 1 };
+        if track.tape_reverse.load(Ordering::Relaxed) {
+            direction *= -1;
+        }
+        let start_pos = f32::from_bits(track.play_pos.load(Ordering::Relaxed));
+        reset_tape_keylock(track, start_pos, direction);
+        track.tape_slice_playing.store(false, Ordering::Relaxed);
+        track.tape_slice_step.store(-1, Ordering::Relaxed);
+        track.debug_logged.store(false, Ordering::Relaxed);
+        if use_count_in {
+            let sr = track.sample_rate.load(Ordering::Relaxed).max(1);
+            let count_in_samples = count_in_samples(tempo, sr, count_in_ticks);
+            track
+                .count_in_remaining
+                .store(count_in_samples, Ordering::Relaxed);
+            track.pending_play.store(true, Ordering::Relaxed);
+            track.is_playing.store(false, Ordering::Relaxed);
+        } else {
+            track.pending_play.store(false, Ordering::Relaxed);
+            track.count_in_remaining.store(0, Ordering::Relaxed);
+            track.is_playing.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
 fn count_in_samples(tempo: f32, sample_rate: u32, ticks: u32) -> u32 {
     if ticks == 0 {
         return 0;
@@ -6053,6 +9795,370 @@ fn build_time_labels(duration_secs: f32) -> Vec<SharedString> {
         .collect()
 }
 
+/// Restarts keylock grains at `start_pos`, e.g. after a trigger or jump.
+fn reset_tape_keylock(track: &Track, start_pos: f32, direction: i32) {
+    let grain_b = if track.tape_keylock_mode.load(Ordering::Relaxed) == 0 {
+        start_pos + direction as f32 * KEYLOCK_GRAIN_HOP as f32
+    } else {
+        start_pos
+    };
+    track
+        .keylock_phase
+        .store(0.0f32.to_bits(), Ordering::Relaxed);
+    track
+        .keylock_grain_a
+        .store(start_pos.to_bits(), Ordering::Relaxed);
+    track.keylock_grain_b.store(grain_b.to_bits(), Ordering::Relaxed);
+    track
+        .keylock_xfade
+        .store(1.0f32.to_bits(), Ordering::Relaxed);
+    track
+        .keylock_onset
+        .store((-1.0f32).to_bits(), Ordering::Relaxed);
+    track
+        .keylock_onset_last
+        .store((-1.0f32).to_bits(), Ordering::Relaxed);
+}
+
+/// WSOLA time-stretch for the Tonal and Rhythmic keylock modes and the tape
+/// pitch shift.
+///
+/// Two read heads play the tape at the head rate (its original pitch unless
+/// pitch is shifted). Every hop the outgoing
+/// head fades into a new one placed near the playhead, at the offset whose
+/// waveform best matches what the outgoing head is about to play. Rhythmic
+/// mode also starts a grain exactly on each transient and keeps grains from
+/// reaching one early, so drum hits are neither smeared nor doubled.
+struct TapeStretch {
+    mode: u32,
+    rate: f32,
+    phase: f32,
+    head_a: f32,
+    head_b: f32,
+    xfade: f32,
+    onset: f32,
+    onset_last: f32,
+    loop_start: usize,
+    loop_end: usize,
+    loop_active: bool,
+    bounded: bool,
+    num_samples: usize,
+}
+
+impl TapeStretch {
+    fn load(
+        track: &Track,
+        loop_start: usize,
+        loop_end: usize,
+        loop_active: bool,
+        bounded: bool,
+        num_samples: usize,
+    ) -> Self {
+        Self {
+            mode: track.tape_keylock_mode.load(Ordering::Relaxed),
+            rate: 1.0,
+            phase: f32::from_bits(track.keylock_phase.load(Ordering::Relaxed)),
+            head_a: f32::from_bits(track.keylock_grain_a.load(Ordering::Relaxed)),
+            head_b: f32::from_bits(track.keylock_grain_b.load(Ordering::Relaxed)),
+            xfade: f32::from_bits(track.keylock_xfade.load(Ordering::Relaxed)).max(1.0),
+            onset: f32::from_bits(track.keylock_onset.load(Ordering::Relaxed)),
+            onset_last: f32::from_bits(track.keylock_onset_last.load(Ordering::Relaxed)),
+            loop_start,
+            loop_end,
+            loop_active,
+            bounded,
+            num_samples,
+        }
+    }
+
+    fn store(&self, track: &Track) {
+        track
+            .keylock_phase
+            .store(self.phase.to_bits(), Ordering::Relaxed);
+        track
+            .keylock_grain_a
+            .store(self.head_a.to_bits(), Ordering::Relaxed);
+        track
+            .keylock_grain_b
+            .store(self.head_b.to_bits(), Ordering::Relaxed);
+        track
+            .keylock_xfade
+            .store(self.xfade.to_bits(), Ordering::Relaxed);
+        track
+            .keylock_onset
+            .store(self.onset.to_bits(), Ordering::Relaxed);
+        track
+            .keylock_onset_last
+            .store(self.onset_last.to_bits(), Ordering::Relaxed);
+    }
+
+    fn hop(&self) -> usize {
+        if self.mode == 2 {
+            KEYLOCK_RHYTHMIC_HOP
+        } else {
+            KEYLOCK_TONAL_HOP
+        }
+    }
+
+    fn looping(&self) -> bool {
+        self.loop_active && self.loop_end > self.loop_start
+    }
+
+    /// Starts both heads at `pos` with no crossfade pending.
+    fn reset(&mut self, pos: f32) {
+        self.phase = 0.0;
+        self.head_a = pos;
+        self.head_b = pos;
+        self.xfade = 1.0;
+        self.onset = -1.0;
+        self.onset_last = -1.0;
+    }
+
+    fn wrap(&self, pos: f32) -> f32 {
+        if self.looping() {
+            let start = self.loop_start as f32;
+            start + (pos - start).rem_euclid((self.loop_end - self.loop_start) as f32)
+        } else {
+            pos.clamp(0.0, self.num_samples.saturating_sub(1) as f32)
+        }
+    }
+
+    /// Distance from `from` to `to` in the playback direction, wrapped around
+    /// the loop when looping.
+    fn ahead(&self, from: f32, to: f32, direction: i32) -> f32 {
+        let dist = (to - from) * direction as f32;
+        if self.looping() {
+            dist.rem_euclid((self.loop_end - self.loop_start) as f32)
+        } else {
+            dist
+        }
+    }
+
+    /// Mono mix at an integer position, used for matching and onset detection.
+    fn mono(&self, samples: &[Vec<f32>], idx: isize) -> f32 {
+        let idx = if self.looping() {
+            let start = self.loop_start as isize;
+            start + (idx - start).rem_euclid((self.loop_end - self.loop_start) as isize)
+        } else {
+            idx.clamp(0, self.num_samples as isize - 1)
+        } as usize;
+        let channels = samples.len().min(2);
+        samples[..channels].iter().map(|ch| ch[idx]).sum::<f32>() / channels as f32
+    }
+
+    /// Finds the grain start within `target + lo..=target + hi` whose waveform
+    /// best continues the one at `natural`, by normalized cross-correlation.
+    fn search(
+        &self,
+        samples: &[Vec<f32>],
+        natural: f32,
+        target: f32,
+        direction: i32,
+        lo: isize,
+        hi: isize,
+    ) -> f32 {
+        let d = direction as isize;
+        let natural = natural.round() as isize;
+        let target = target.round() as isize;
+        let len = (self.hop() / 2) as isize;
+        let score = |offset: isize, stride: usize| {
+            let mut dot = 0.0f32;
+            let mut energy = 1.0e-9f32;
+            for k in (0..len).step_by(stride) {
+                let reference = self.mono(samples, natural + d * k);
+                let candidate = self.mono(samples, target + offset + d * k);
+                dot += reference * candidate;
+                energy += candidate * candidate;
+            }
+            dot / energy.sqrt()
+        };
+        let mut best = 0isize.clamp(lo, hi);
+        let mut best_score = score(best, 4);
+        for offset in (lo..=hi).step_by(4) {
+            let value = score(offset, 4);
+            if value > best_score {
+                best_score = value;
+                best = offset;
+            }
+        }
+        let coarse = best;
+        best_score = score(coarse, 1);
+        for offset in (coarse - 3).max(lo)..=(coarse + 3).min(hi) {
+            let value = score(offset, 1);
+            if value > best_score {
+                best_score = value;
+                best = offset;
+            }
+        }
+        self.wrap((target + best) as f32)
+    }
+
+    /// First transient within `span` samples ahead of `from`: a block whose
+    /// energy jumps well above the blocks before it.
+    fn find_onset(
+        &self,
+        samples: &[Vec<f32>],
+        from: f32,
+        direction: i32,
+        span: f32,
+    ) -> Option<f32> {
+        let d = direction as isize;
+        let block = KEYLOCK_ONSET_BLOCK as isize;
+        let start = from.round() as isize;
+        let energy = |pos: isize| {
+            (0..block)
+                .map(|k| {
+                    let v = self.mono(samples, pos + d * k);
+                    v * v
+                })
+                .sum::<f32>()
+                / block as f32
+        };
+        let mut history = [0.0f32; 4];
+        for (j, slot) in history.iter_mut().enumerate() {
+            *slot = energy(start - d * block * (4 - j as isize));
+        }
+        let blocks = (span / block as f32).ceil().max(1.0) as isize;
+        for j in 0..blocks {
+            let pos = start + d * block * j;
+            let e = energy(pos);
+            let base = history.iter().sum::<f32>() / history.len() as f32;
+            if e > KEYLOCK_ONSET_FLOOR && e > base * KEYLOCK_ONSET_RATIO {
+                let onset = self.wrap(pos as f32);
+                if self.onset_last < 0.0
+                    || (onset - self.onset_last).abs() >= KEYLOCK_ONSET_REFRACTORY
+                {
+                    return Some(onset);
+                }
+            }
+            history[j as usize % history.len()] = e;
+        }
+        None
+    }
+
+    /// Starts a new grain when one is due, given where the playhead is now.
+    /// `rate` is how fast the heads read the tape (1.0 = original pitch).
+    fn tick(
+        &mut self,
+        samples: &[Vec<f32>],
+        timeline: f32,
+        direction: i32,
+        speed: f32,
+        rate: f32,
+    ) {
+        let hop = self.hop() as f32;
+        let speed = speed.abs();
+        self.rate = rate.abs();
+        let reach = hop * 2.0 * self.rate.max(0.25);
+        if self.mode == 2 && self.onset >= 0.0 {
+            let ahead = self.ahead(timeline, self.onset, direction);
+            if ahead <= speed {
+                self.head_a = self.head_b;
+                self.head_b = self.onset;
+                self.phase = 0.0;
+                self.xfade = KEYLOCK_ONSET_XFADE;
+                self.onset_last = self.onset;
+                self.onset = -1.0;
+                return;
+            }
+            if ahead > hop * 10.0 {
+                self.onset = -1.0;
+            }
+        }
+        if self.phase < hop {
+            return;
+        }
+
+        let search = (hop / 2.0) as isize;
+        let mut target = timeline;
+        let (mut lo, mut hi) = (-search, search);
+        if self.mode == 2 {
+            if self.onset < 0.0 {
+                let lead = self.ahead(timeline, self.head_b, direction).min(hop * 6.0);
+                let span = (hop * (speed + 2.0)).max(lead + reach) + KEYLOCK_ONSET_BLOCK as f32;
+                if let Some(onset) = self.find_onset(samples, timeline, direction, span) {
+                    self.onset = onset;
+                }
+            }
+            // Let the tail of the last hit play through rather than jumping
+            // back into its attack.
+            let onset_near =
+                self.onset >= 0.0 && self.ahead(self.head_b, self.onset, direction) < reach;
+            if self.onset_last >= 0.0 && speed > 0.05 && !onset_near {
+                let floor = self.onset_last + direction as f32 * hop * self.rate.max(0.25);
+                let behind = self.ahead(target, floor, direction);
+                if behind > 0.0 && behind <= reach {
+                    self.head_a = self.head_b;
+                    self.phase = 0.0;
+                    self.xfade = hop;
+                    return;
+                }
+            }
+            // Hold the next grain short of an upcoming hit until the playhead
+            // reaches it.
+            if self.onset >= 0.0 && self.ahead(target, self.onset, direction) < reach {
+                target = self.onset - direction as f32 * reach;
+                if direction >= 0 {
+                    hi = 0;
+                } else {
+                    lo = 0;
+                }
+            }
+        }
+        if self.bounded {
+            let margin = reach + search as f32;
+            let start = self.loop_start as f32;
+            let end = self.loop_end.saturating_sub(1) as f32;
+            if end - start > margin * 2.0 {
+                target = if direction >= 0 {
+                    target.clamp(start, end - margin)
+                } else {
+                    target.clamp(start + margin, end)
+                };
+            }
+        }
+        let next = self.search(samples, self.head_b, target, direction, lo, hi);
+        self.head_a = self.head_b;
+        self.head_b = next;
+        self.phase = 0.0;
+        self.xfade = hop;
+    }
+
+    fn read(&self, samples: &[Vec<f32>], channel: usize) -> f32 {
+        let sample_b = sample_at_linear(
+            samples,
+            channel,
+            self.head_b,
+            self.loop_start,
+            self.loop_end,
+            self.loop_active,
+            self.num_samples,
+        );
+        if self.phase >= self.xfade {
+            return sample_b;
+        }
+        let sample_a = sample_at_linear(
+            samples,
+            channel,
+            self.head_a,
+            self.loop_start,
+            self.loop_end,
+            self.loop_active,
+            self.num_samples,
+        );
+        let fade = self.phase / self.xfade;
+        sample_a * (1.0 - fade) + sample_b * fade
+    }
+
+    /// Moves both heads one output sample at `rate`.
+    fn advance(&mut self, direction: i32, rate: f32) {
+        let step = direction as f32 * rate.abs();
+        self.head_a = self.wrap(self.head_a + step);
+        self.head_b = self.wrap(self.head_b + step);
+        self.phase += 1.0;
+    }
+}
+
 fn sample_at_linear(
     samples: &[Vec<f32>],
     channel: usize,
@@ -6424,14 +10530,24 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
 
     params.insert("level".to_string(), f(&track.level));
     params.insert("muted".to_string(), b(&track.is_muted));
+    params.insert("swing_override".to_string(), b(&track.swing_override));
+    params.insert("swing".to_string(), f(&track.swing));
+    params.insert("midi_channel".to_string(), u(&track.midi_channel));
     params.insert("tape_speed".to_string(), f(&track.tape_speed));
     params.insert("tape_rate_mode".to_string(), u(&track.tape_rate_mode));
     params.insert("tape_rotate".to_string(), f(&track.tape_rotate));
     params.insert("tape_glide".to_string(), f(&track.tape_glide));
+    params.insert("tape_pitch".to_string(), f(&track.tape_pitch));
+    params.insert("tape_fine".to_string(), f(&track.tape_fine));
     params.insert("tape_sos".to_string(), f(&track.tape_sos));
     params.insert("tape_reverse".to_string(), b(&track.tape_reverse));
     params.insert("tape_freeze".to_string(), b(&track.tape_freeze));
     params.insert("tape_keylock".to_string(), b(&track.tape_keylock));
+    params.insert("tape_keylock_mode".to_string(), u(&track.tape_keylock_mode));
+    params.insert("tape_slice_mode".to_string(), u(&track.tape_slice_mode));
+    for i in 0..TAPE_SLICE_STEPS {
+        params.insert(format!("tape_slice_order_{}", i), u(&track.tape_slice_order[i]));
+    }
     params.insert("tape_monitor".to_string(), b(&track.tape_monitor));
     params.insert("tape_overdub".to_string(), b(&track.tape_overdub));
     params.insert("loop_start".to_string(), f(&track.loop_start));
@@ -6487,6 +10603,7 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
         params.insert(format!("animate_slot_fine_{}", i), f(&track.animate_slot_fine[i]));
         params.insert(format!("animate_slot_level_{}", i), f(&track.animate_slot_level[i]));
         params.insert(format!("animate_slot_pan_{}", i), f(&track.animate_slot_pan[i]));
+        params.insert(format!("animate_slot_wt_position_{}", i), f(&track.animate_slot_wt_position[i]));
         params.insert(format!("animate_slot_wt_lfo_amount_{}", i), f(&track.animate_slot_wt_lfo_amount[i]));
         params.insert(format!("animate_slot_wt_lfo_shape_{}", i), u(&track.animate_slot_wt_lfo_shape[i]));
         params.insert(format!("animate_slot_wt_lfo_rate_{}", i), f(&track.animate_slot_wt_lfo_rate[i]));
@@ -6495,13 +10612,43 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
         params.insert(format!("animate_slot_sample_start_{}", i), f(&track.animate_slot_sample_start[i]));
         params.insert(format!("animate_slot_loop_start_{}", i), f(&track.animate_slot_loop_start[i]));
         params.insert(format!("animate_slot_loop_end_{}", i), f(&track.animate_slot_loop_end[i]));
+        params.insert(format!("animate_slot_multisample_{}", i), b(&track.animate_slot_multisample[i]));
         params.insert(format!("animate_slot_filter_type_{}", i), u(&track.animate_slot_filter_type[i]));
         params.insert(format!("animate_slot_filter_cutoff_{}", i), f(&track.animate_slot_filter_cutoff[i]));
         params.insert(format!("animate_slot_filter_resonance_{}", i), f(&track.animate_slot_filter_resonance[i]));
+        params.insert(format!("animate_slot_filter_drive_{}", i), f(&track.animate_slot_filter_drive[i]));
+        for (param, key) in ANIMATE_SLOT_ENV_KEYS.iter().enumerate() {
+            params.insert(
+                format!("animate_slot_{}_{}", key, i),
+                f(&animate_slot_env_param(track, param)[i]),
+            );
+        }
     }
 
+    params.insert(
+        "animate_sample_default_root".to_string(),
+        f(&track.animate_sample_default_root),
+    );
     params.insert("animate_vector_x".to_string(), f(&track.animate_vector_x));
     params.insert("animate_vector_y".to_string(), f(&track.animate_vector_y));
+    params.insert("animate_vector_path_enabled".to_string(), b(&track.animate_vector_path_enabled));
+    params.insert("animate_vector_path_sync".to_string(), b(&track.animate_vector_path_sync));
+    params.insert("animate_vector_path_loop".to_string(), b(&track.animate_vector_path_loop));
+    params.insert("animate_vector_path_loop_start".to_string(), u(&track.animate_vector_path_loop_start));
+    params.insert("animate_vector_path_loop_end".to_string(), u(&track.animate_vector_path_loop_end));
+    params.insert("animate_vector_path_len".to_string(), u(&track.animate_vector_path_len));
+    params.insert("animate_vector_path_tempo".to_string(), f(&track.animate_vector_path_tempo));
+    for i in 0..ANIMATE_VECTOR_PATH_POINTS {
+        params.insert(format!("animate_vector_path_x_{}", i), f(&track.animate_vector_path_x[i]));
+        params.insert(format!("animate_vector_path_y_{}", i), f(&track.animate_vector_path_y[i]));
+        params.insert(format!("animate_vector_path_time_{}", i), f(&track.animate_vector_path_time[i]));
+    }
+    params.insert("animate_voice_mode".to_string(), u(&track.animate_voice_mode));
+    params.insert("animate_polyphony".to_string(), u(&track.animate_polyphony));
+    params.insert("animate_steal_mode".to_string(), u(&track.animate_steal_mode));
+    params.insert("animate_unison_voices".to_string(), u(&track.animate_unison_voices));
+    params.insert("animate_unison_detune".to_string(), f(&track.animate_unison_detune));
+    params.insert("animate_glide".to_string(), f(&track.animate_glide));
     params.insert("animate_lfo_x_waveform".to_string(), u(&track.animate_lfo_x_waveform));
     params.insert("animate_lfo_x_sync".to_string(), b(&track.animate_lfo_x_sync));
     params.insert("animate_lfo_x_division".to_string(), u(&track.animate_lfo_x_division));
@@ -6512,6 +10659,12 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
     params.insert("animate_lfo_y_division".to_string(), u(&track.animate_lfo_y_division));
     params.insert("animate_lfo_y_rate".to_string(), f(&track.animate_lfo_y_rate));
     params.insert("animate_lfo_y_amount".to_string(), f(&track.animate_lfo_y_amount));
+    params.insert("animate_scale".to_string(), u(&track.animate_scale));
+    params.insert("animate_root_note".to_string(), u(&track.animate_root_note));
+    params.insert("animate_page".to_string(), u(&track.animate_page));
+    params.insert("animate_edit_lane".to_string(), u(&track.animate_edit_lane));
+    params.insert("animate_edit_step".to_string(), u(&track.animate_edit_step));
+    params.insert("animate_lock_param".to_string(), u(&track.animate_lock_param));
 
     params.insert("kick_pitch".to_string(), f(&track.kick_pitch));
     params.insert("kick_decay".to_string(), f(&track.kick_decay));
@@ -6596,6 +10749,14 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
             f(&track.snare_step_filter_resonance[i]),
         );
     }
+    for (voice, voice_key) in SYNDRM_VOICE_KEYS.iter().enumerate() {
+        for (p, param_key) in SYNDRM_VOICE_PARAM_KEYS.iter().enumerate() {
+            params.insert(
+                format!("syndrm_{}_{}", voice_key, param_key),
+                f(&track.syndrm_voice_params[voice][p]),
+            );
+        }
+    }
 
     params.insert("void_base_freq".to_string(), f(&track.void_base_freq));
     params.insert("void_chaos_depth".to_string(), f(&track.void_chaos_depth));
@@ -6607,193 +10768,734 @@ fn capture_track_params(track: &Track, params: &mut HashMap<String, f32>) {
     params.insert("void_enabled".to_string(), b(&track.void_enabled));
 }
 
-fn apply_track_params(track: &Track, params: &HashMap<String, f32>) {
-    let sf = |a: &AtomicU32, name: &str| {
-        if let Some(&v) = params.get(name) {
-            a.store(v.to_bits(), Ordering::Relaxed);
+/// Indices for a `TrackParamField` that addresses a single atomic.
+const NO_INDEX: [usize; 3] = [0; 3];
+
+/// A track atomic that a `.trk` param key sets, reached through an accessor and its
+/// indices so a key can be resolved once and then written directly.
+#[derive(Clone, Copy)]
+enum TrackParamField {
+    Float(fn(&Track, [usize; 3]) -> &AtomicU32, [usize; 3]),
+    Uint(fn(&Track, [usize; 3]) -> &AtomicU32, [usize; 3]),
+    Bool(fn(&Track, [usize; 3]) -> &AtomicBool, [usize; 3]),
+}
+
+impl TrackParamField {
+    fn store(self, track: &Track, value: f32) {
+        match self {
+            Self::Float(field, index) => {
+                field(track, index).store(value.to_bits(), Ordering::Relaxed)
+            }
+            Self::Uint(field, index) => field(track, index).store(value as u32, Ordering::Relaxed),
+            Self::Bool(field, index) => field(track, index).store(value > 0.5, Ordering::Relaxed),
         }
-    };
-    let sb = |a: &AtomicBool, name: &str| {
-        if let Some(&v) = params.get(name) {
-            a.store(v > 0.5, Ordering::Relaxed);
+    }
+}
+
+/// Every field `param` sets, so MIDI CC can write it without looking the key up.
+fn resolve_track_param(param: &str) -> Vec<TrackParamField> {
+    let mut fields = Vec::new();
+    visit_track_params(|name, field| {
+        if name == param {
+            fields.push(field);
         }
-    };
-    let su = |a: &AtomicU32, name: &str| {
-        if let Some(&v) = params.get(name) {
-            a.store(v as u32, Ordering::Relaxed);
+    });
+    fields
+}
+
+/// Writes every `.trk` param present in `params` into the track.
+fn apply_track_params(track: &Track, params: &HashMap<String, f32>) {
+    visit_track_params(|name, field| {
+        if let Some(&value) = params.get(name) {
+            field.store(track, value);
         }
-    };
+    });
+}
 
-    sf(&track.level, "level");
-    sb(&track.is_muted, "muted");
-    sf(&track.tape_speed, "tape_speed");
-    sf(&track.tape_speed_smooth, "tape_speed");
-    su(&track.tape_rate_mode, "tape_rate_mode");
-    sf(&track.tape_rotate, "tape_rotate");
-    sf(&track.tape_glide, "tape_glide");
-    sf(&track.tape_sos, "tape_sos");
-    sb(&track.tape_reverse, "tape_reverse");
-    sb(&track.tape_freeze, "tape_freeze");
-    sb(&track.tape_keylock, "tape_keylock");
-    sb(&track.tape_monitor, "tape_monitor");
-    sb(&track.tape_overdub, "tape_overdub");
-    sf(&track.loop_start, "loop_start");
-    sf(&track.trigger_start, "trigger_start");
-    sf(&track.loop_length, "loop_length");
-    sf(&track.loop_xfade, "loop_xfade");
-    sb(&track.loop_enabled, "loop_enabled");
-    su(&track.loop_mode, "loop_mode");
-    su(&track.granular_type, "granular_type");
-    sf(&track.mosaic_pitch, "mosaic_pitch");
-    sf(&track.mosaic_rate, "mosaic_rate");
-    sf(&track.mosaic_size, "mosaic_size");
-    sf(&track.mosaic_contour, "mosaic_contour");
-    sf(&track.mosaic_warp, "mosaic_warp");
-    sf(&track.mosaic_spray, "mosaic_spray");
-    sf(&track.mosaic_pattern, "mosaic_pattern");
-    sf(&track.mosaic_wet, "mosaic_wet");
-    sf(&track.mosaic_spatial, "mosaic_spatial");
-    sf(&track.mosaic_detune, "mosaic_detune");
-    sf(&track.mosaic_rand_rate, "mosaic_rand_rate");
-    sf(&track.mosaic_rand_size, "mosaic_rand_size");
-    sf(&track.mosaic_sos, "mosaic_sos");
-    sb(&track.mosaic_enabled, "mosaic_enabled");
-    sf(&track.ring_cutoff, "ring_cutoff");
-    sf(&track.ring_resonance, "ring_resonance");
-    sf(&track.ring_decay, "ring_decay");
-    su(&track.ring_decay_mode, "ring_decay_mode");
-    sf(&track.ring_pitch, "ring_pitch");
-    sf(&track.ring_tone, "ring_tone");
-    sf(&track.ring_tilt, "ring_tilt");
-    sf(&track.ring_slope, "ring_slope");
-    sf(&track.ring_wet, "ring_wet");
-    sf(&track.ring_detune, "ring_detune");
-    sf(&track.ring_waves, "ring_waves");
-    sf(&track.ring_waves_rate, "ring_waves_rate");
-    su(&track.ring_waves_rate_mode, "ring_waves_rate_mode");
-    sf(&track.ring_noise, "ring_noise");
-    sf(&track.ring_noise_rate, "ring_noise_rate");
-    su(&track.ring_noise_rate_mode, "ring_noise_rate_mode");
-    su(&track.ring_scale, "ring_scale");
-    sb(&track.ring_enabled, "ring_enabled");
-    sb(&track.g8_enabled, "g8_enabled");
-    su(&track.g8_rate_index, "g8_rate_index");
+/// Calls `visit` with each `.trk` param key and the track field it sets. A key can
+/// set more than one field.
+fn visit_track_params(mut visit: impl FnMut(&str, TrackParamField)) {
+    use TrackParamField::{Bool as B, Float as F, Uint as U};
+
+    visit("level", F(|t, _| &t.level, NO_INDEX));
+    visit("muted", B(|t, _| &t.is_muted, NO_INDEX));
+    visit("swing_override", B(|t, _| &t.swing_override, NO_INDEX));
+    visit("swing", F(|t, _| &t.swing, NO_INDEX));
+    visit("midi_channel", U(|t, _| &t.midi_channel, NO_INDEX));
+    visit("tape_speed", F(|t, _| &t.tape_speed, NO_INDEX));
+    visit("tape_speed", F(|t, _| &t.tape_speed_smooth, NO_INDEX));
+    visit("tape_rate_mode", U(|t, _| &t.tape_rate_mode, NO_INDEX));
+    visit("tape_rotate", F(|t, _| &t.tape_rotate, NO_INDEX));
+    visit("tape_glide", F(|t, _| &t.tape_glide, NO_INDEX));
+    visit("tape_pitch", F(|t, _| &t.tape_pitch, NO_INDEX));
+    visit("tape_fine", F(|t, _| &t.tape_fine, NO_INDEX));
+    visit("tape_sos", F(|t, _| &t.tape_sos, NO_INDEX));
+    visit("tape_reverse", B(|t, _| &t.tape_reverse, NO_INDEX));
+    visit("tape_freeze", B(|t, _| &t.tape_freeze, NO_INDEX));
+    visit("tape_keylock", B(|t, _| &t.tape_keylock, NO_INDEX));
+    visit(
+        "tape_keylock_mode",
+        U(|t, _| &t.tape_keylock_mode, NO_INDEX),
+    );
+    visit("tape_slice_mode", U(|t, _| &t.tape_slice_mode, NO_INDEX));
+    for i in 0..TAPE_SLICE_STEPS {
+        visit(
+            &format!("tape_slice_order_{}", i),
+            U(|t, [i, ..]| &t.tape_slice_order[i], [i, 0, 0]),
+        );
+    }
+    visit("tape_monitor", B(|t, _| &t.tape_monitor, NO_INDEX));
+    visit("tape_overdub", B(|t, _| &t.tape_overdub, NO_INDEX));
+    visit("loop_start", F(|t, _| &t.loop_start, NO_INDEX));
+    visit("trigger_start", F(|t, _| &t.trigger_start, NO_INDEX));
+    visit("loop_length", F(|t, _| &t.loop_length, NO_INDEX));
+    visit("loop_xfade", F(|t, _| &t.loop_xfade, NO_INDEX));
+    visit("loop_enabled", B(|t, _| &t.loop_enabled, NO_INDEX));
+    visit("loop_mode", U(|t, _| &t.loop_mode, NO_INDEX));
+    visit("granular_type", U(|t, _| &t.granular_type, NO_INDEX));
+    visit("mosaic_pitch", F(|t, _| &t.mosaic_pitch, NO_INDEX));
+    visit("mosaic_rate", F(|t, _| &t.mosaic_rate, NO_INDEX));
+    visit("mosaic_size", F(|t, _| &t.mosaic_size, NO_INDEX));
+    visit("mosaic_contour", F(|t, _| &t.mosaic_contour, NO_INDEX));
+    visit("mosaic_warp", F(|t, _| &t.mosaic_warp, NO_INDEX));
+    visit("mosaic_spray", F(|t, _| &t.mosaic_spray, NO_INDEX));
+    visit("mosaic_pattern", F(|t, _| &t.mosaic_pattern, NO_INDEX));
+    visit("mosaic_wet", F(|t, _| &t.mosaic_wet, NO_INDEX));
+    visit("mosaic_spatial", F(|t, _| &t.mosaic_spatial, NO_INDEX));
+    visit("mosaic_detune", F(|t, _| &t.mosaic_detune, NO_INDEX));
+    visit("mosaic_rand_rate", F(|t, _| &t.mosaic_rand_rate, NO_INDEX));
+    visit("mosaic_rand_size", F(|t, _| &t.mosaic_rand_size, NO_INDEX));
+    visit("mosaic_sos", F(|t, _| &t.mosaic_sos, NO_INDEX));
+    visit("mosaic_enabled", B(|t, _| &t.mosaic_enabled, NO_INDEX));
+    visit("ring_cutoff", F(|t, _| &t.ring_cutoff, NO_INDEX));
+    visit("ring_resonance", F(|t, _| &t.ring_resonance, NO_INDEX));
+    visit("ring_decay", F(|t, _| &t.ring_decay, NO_INDEX));
+    visit("ring_decay_mode", U(|t, _| &t.ring_decay_mode, NO_INDEX));
+    visit("ring_pitch", F(|t, _| &t.ring_pitch, NO_INDEX));
+    visit("ring_tone", F(|t, _| &t.ring_tone, NO_INDEX));
+    visit("ring_tilt", F(|t, _| &t.ring_tilt, NO_INDEX));
+    visit("ring_slope", F(|t, _| &t.ring_slope, NO_INDEX));
+    visit("ring_wet", F(|t, _| &t.ring_wet, NO_INDEX));
+    visit("ring_detune", F(|t, _| &t.ring_detune, NO_INDEX));
+    visit("ring_waves", F(|t, _| &t.ring_waves, NO_INDEX));
+    visit("ring_waves_rate", F(|t, _| &t.ring_waves_rate, NO_INDEX));
+    visit(
+        "ring_waves_rate_mode",
+        U(|t, _| &t.ring_waves_rate_mode, NO_INDEX),
+    );
+    visit("ring_noise", F(|t, _| &t.ring_noise, NO_INDEX));
+    visit("ring_noise_rate", F(|t, _| &t.ring_noise_rate, NO_INDEX));
+    visit(
+        "ring_noise_rate_mode",
+        U(|t, _| &t.ring_noise_rate_mode, NO_INDEX),
+    );
+    visit("ring_scale", U(|t, _| &t.ring_scale, NO_INDEX));
+    visit("ring_enabled", B(|t, _| &t.ring_enabled, NO_INDEX));
+    visit("g8_enabled", B(|t, _| &t.g8_enabled, NO_INDEX));
+    visit("g8_rate_index", U(|t, _| &t.g8_rate_index, NO_INDEX));
     for i in 0..32 {
-        sf(&track.g8_steps[i], &format!("g8_step_{}", i));
+        visit(
+            &format!("g8_step_{}", i),
+            F(|t, [i, ..]| &t.g8_steps[i], [i, 0, 0]),
+        );
     }
 
     for i in 0..4 {
-        su(&track.animate_slot_types[i], &format!("animate_slot_type_{}", i));
-        su(&track.animate_slot_wavetables[i], &format!("animate_slot_wavetable_{}", i));
-        su(&track.animate_slot_samples[i], &format!("animate_slot_sample_{}", i));
-        sf(&track.animate_slot_coarse[i], &format!("animate_slot_coarse_{}", i));
-        sf(&track.animate_slot_fine[i], &format!("animate_slot_fine_{}", i));
-        sf(&track.animate_slot_level[i], &format!("animate_slot_level_{}", i));
-        sf(&track.animate_slot_pan[i], &format!("animate_slot_pan_{}", i));
-        sf(&track.animate_slot_wt_lfo_amount[i], &format!("animate_slot_wt_lfo_amount_{}", i));
-        su(&track.animate_slot_wt_lfo_shape[i], &format!("animate_slot_wt_lfo_shape_{}", i));
-        sf(&track.animate_slot_wt_lfo_rate[i], &format!("animate_slot_wt_lfo_rate_{}", i));
-        sb(&track.animate_slot_wt_lfo_sync[i], &format!("animate_slot_wt_lfo_sync_{}", i));
-        su(&track.animate_slot_wt_lfo_division[i], &format!("animate_slot_wt_lfo_division_{}", i));
-        sf(&track.animate_slot_sample_start[i], &format!("animate_slot_sample_start_{}", i));
-        sf(&track.animate_slot_loop_start[i], &format!("animate_slot_loop_start_{}", i));
-        sf(&track.animate_slot_loop_end[i], &format!("animate_slot_loop_end_{}", i));
-        su(&track.animate_slot_filter_type[i], &format!("animate_slot_filter_type_{}", i));
-        sf(&track.animate_slot_filter_cutoff[i], &format!("animate_slot_filter_cutoff_{}", i));
-        sf(&track.animate_slot_filter_resonance[i], &format!("animate_slot_filter_resonance_{}", i));
-    }
-
-    sf(&track.animate_vector_x, "animate_vector_x");
-    sf(&track.animate_vector_y, "animate_vector_y");
-    su(&track.animate_lfo_x_waveform, "animate_lfo_x_waveform");
-    sb(&track.animate_lfo_x_sync, "animate_lfo_x_sync");
-    su(&track.animate_lfo_x_division, "animate_lfo_x_division");
-    sf(&track.animate_lfo_x_rate, "animate_lfo_x_rate");
-    sf(&track.animate_lfo_x_amount, "animate_lfo_x_amount");
-    su(&track.animate_lfo_y_waveform, "animate_lfo_y_waveform");
-    sb(&track.animate_lfo_y_sync, "animate_lfo_y_sync");
-    su(&track.animate_lfo_y_division, "animate_lfo_y_division");
-    sf(&track.animate_lfo_y_rate, "animate_lfo_y_rate");
-    sf(&track.animate_lfo_y_amount, "animate_lfo_y_amount");
-
-    sf(&track.kick_pitch, "kick_pitch");
-    sf(&track.kick_decay, "kick_decay");
-    sf(&track.kick_attack, "kick_attack");
-    sf(&track.kick_pitch_env_amount, "kick_pitch_env_amount");
-    sf(&track.kick_drive, "kick_drive");
-    sf(&track.kick_level, "kick_level");
-    su(&track.kick_filter_type, "kick_filter_type");
-    sf(&track.kick_filter_cutoff, "kick_filter_cutoff");
-    sf(&track.kick_filter_resonance, "kick_filter_resonance");
-    sb(&track.kick_filter_pre_drive, "kick_filter_pre_drive");
-    sf(&track.snare_tone, "snare_tone");
-    sf(&track.snare_decay, "snare_decay");
-    sf(&track.snare_snappy, "snare_snappy");
-    sf(&track.snare_attack, "snare_attack");
-    sf(&track.snare_drive, "snare_drive");
-    sf(&track.snare_level, "snare_level");
-    su(&track.snare_filter_type, "snare_filter_type");
-    sf(&track.snare_filter_cutoff, "snare_filter_cutoff");
-    sf(&track.snare_filter_resonance, "snare_filter_resonance");
-    sb(&track.snare_filter_pre_drive, "snare_filter_pre_drive");
-    su(&track.syndrm_page, "syndrm_page");
-    su(&track.syndrm_edit_lane, "syndrm_edit_lane");
-    su(&track.syndrm_edit_step, "syndrm_edit_step");
-    sb(&track.syndrm_step_hold, "syndrm_step_hold");
+        visit(
+            &format!("animate_slot_type_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_types[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wavetable_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_wavetables[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_sample_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_samples[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_coarse_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_coarse[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_fine_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_fine[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_level_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_level[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_pan_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_pan[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_position_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_wt_position[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_lfo_amount_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_wt_lfo_amount[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_lfo_shape_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_wt_lfo_shape[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_lfo_rate_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_wt_lfo_rate[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_lfo_sync_{}", i),
+            B(|t, [i, ..]| &t.animate_slot_wt_lfo_sync[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_wt_lfo_division_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_wt_lfo_division[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_sample_start_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_sample_start[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_loop_start_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_loop_start[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_loop_end_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_loop_end[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_multisample_{}", i),
+            B(|t, [i, ..]| &t.animate_slot_multisample[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_filter_type_{}", i),
+            U(|t, [i, ..]| &t.animate_slot_filter_type[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_filter_cutoff_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_filter_cutoff[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_filter_resonance_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_filter_resonance[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_slot_filter_drive_{}", i),
+            F(|t, [i, ..]| &t.animate_slot_filter_drive[i], [i, 0, 0]),
+        );
+        for (param, key) in ANIMATE_SLOT_ENV_KEYS.iter().enumerate() {
+            visit(
+                &format!("animate_slot_{}_{}", key, i),
+                F(
+                    |t, [p, i, _]| &animate_slot_env_param(t, p)[i],
+                    [param, i, 0],
+                ),
+            );
+        }
+    }
+
+    visit(
+        "animate_sample_default_root",
+        F(|t, _| &t.animate_sample_default_root, NO_INDEX),
+    );
+    visit("animate_vector_x", F(|t, _| &t.animate_vector_x, NO_INDEX));
+    visit("animate_vector_y", F(|t, _| &t.animate_vector_y, NO_INDEX));
+    visit(
+        "animate_vector_path_enabled",
+        B(|t, _| &t.animate_vector_path_enabled, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_sync",
+        B(|t, _| &t.animate_vector_path_sync, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_loop",
+        B(|t, _| &t.animate_vector_path_loop, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_loop_start",
+        U(|t, _| &t.animate_vector_path_loop_start, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_loop_end",
+        U(|t, _| &t.animate_vector_path_loop_end, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_len",
+        U(|t, _| &t.animate_vector_path_len, NO_INDEX),
+    );
+    visit(
+        "animate_vector_path_tempo",
+        F(|t, _| &t.animate_vector_path_tempo, NO_INDEX),
+    );
+    for i in 0..ANIMATE_VECTOR_PATH_POINTS {
+        visit(
+            &format!("animate_vector_path_x_{}", i),
+            F(|t, [i, ..]| &t.animate_vector_path_x[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_vector_path_y_{}", i),
+            F(|t, [i, ..]| &t.animate_vector_path_y[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("animate_vector_path_time_{}", i),
+            F(|t, [i, ..]| &t.animate_vector_path_time[i], [i, 0, 0]),
+        );
+    }
+    visit(
+        "animate_voice_mode",
+        U(|t, _| &t.animate_voice_mode, NO_INDEX),
+    );
+    visit(
+        "animate_polyphony",
+        U(|t, _| &t.animate_polyphony, NO_INDEX),
+    );
+    visit(
+        "animate_steal_mode",
+        U(|t, _| &t.animate_steal_mode, NO_INDEX),
+    );
+    visit(
+        "animate_unison_voices",
+        U(|t, _| &t.animate_unison_voices, NO_INDEX),
+    );
+    visit(
+        "animate_unison_detune",
+        F(|t, _| &t.animate_unison_detune, NO_INDEX),
+    );
+    visit("animate_glide", F(|t, _| &t.animate_glide, NO_INDEX));
+    visit(
+        "animate_lfo_x_waveform",
+        U(|t, _| &t.animate_lfo_x_waveform, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_x_sync",
+        B(|t, _| &t.animate_lfo_x_sync, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_x_division",
+        U(|t, _| &t.animate_lfo_x_division, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_x_rate",
+        F(|t, _| &t.animate_lfo_x_rate, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_x_amount",
+        F(|t, _| &t.animate_lfo_x_amount, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_y_waveform",
+        U(|t, _| &t.animate_lfo_y_waveform, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_y_sync",
+        B(|t, _| &t.animate_lfo_y_sync, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_y_division",
+        U(|t, _| &t.animate_lfo_y_division, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_y_rate",
+        F(|t, _| &t.animate_lfo_y_rate, NO_INDEX),
+    );
+    visit(
+        "animate_lfo_y_amount",
+        F(|t, _| &t.animate_lfo_y_amount, NO_INDEX),
+    );
+    visit("animate_scale", U(|t, _| &t.animate_scale, NO_INDEX));
+    visit(
+        "animate_root_note",
+        U(|t, _| &t.animate_root_note, NO_INDEX),
+    );
+    visit("animate_page", U(|t, _| &t.animate_page, NO_INDEX));
+    visit(
+        "animate_edit_lane",
+        U(|t, _| &t.animate_edit_lane, NO_INDEX),
+    );
+    visit(
+        "animate_edit_step",
+        U(|t, _| &t.animate_edit_step, NO_INDEX),
+    );
+    visit(
+        "animate_lock_param",
+        U(|t, _| &t.animate_lock_param, NO_INDEX),
+    );
+
+    visit("kick_pitch", F(|t, _| &t.kick_pitch, NO_INDEX));
+    visit("kick_decay", F(|t, _| &t.kick_decay, NO_INDEX));
+    visit("kick_attack", F(|t, _| &t.kick_attack, NO_INDEX));
+    visit(
+        "kick_pitch_env_amount",
+        F(|t, _| &t.kick_pitch_env_amount, NO_INDEX),
+    );
+    visit("kick_drive", F(|t, _| &t.kick_drive, NO_INDEX));
+    visit("kick_level", F(|t, _| &t.kick_level, NO_INDEX));
+    visit("kick_filter_type", U(|t, _| &t.kick_filter_type, NO_INDEX));
+    visit(
+        "kick_filter_cutoff",
+        F(|t, _| &t.kick_filter_cutoff, NO_INDEX),
+    );
+    visit(
+        "kick_filter_resonance",
+        F(|t, _| &t.kick_filter_resonance, NO_INDEX),
+    );
+    visit(
+        "kick_filter_pre_drive",
+        B(|t, _| &t.kick_filter_pre_drive, NO_INDEX),
+    );
+    visit("snare_tone", F(|t, _| &t.snare_tone, NO_INDEX));
+    visit("snare_decay", F(|t, _| &t.snare_decay, NO_INDEX));
+    visit("snare_snappy", F(|t, _| &t.snare_snappy, NO_INDEX));
+    visit("snare_attack", F(|t, _| &t.snare_attack, NO_INDEX));
+    visit("snare_drive", F(|t, _| &t.snare_drive, NO_INDEX));
+    visit("snare_level", F(|t, _| &t.snare_level, NO_INDEX));
+    visit(
+        "snare_filter_type",
+        U(|t, _| &t.snare_filter_type, NO_INDEX),
+    );
+    visit(
+        "snare_filter_cutoff",
+        F(|t, _| &t.snare_filter_cutoff, NO_INDEX),
+    );
+    visit(
+        "snare_filter_resonance",
+        F(|t, _| &t.snare_filter_resonance, NO_INDEX),
+    );
+    visit(
+        "snare_filter_pre_drive",
+        B(|t, _| &t.snare_filter_pre_drive, NO_INDEX),
+    );
+    visit("syndrm_page", U(|t, _| &t.syndrm_page, NO_INDEX));
+    visit("syndrm_edit_lane", U(|t, _| &t.syndrm_edit_lane, NO_INDEX));
+    visit("syndrm_edit_step", U(|t, _| &t.syndrm_edit_step, NO_INDEX));
+    visit("syndrm_step_hold", B(|t, _| &t.syndrm_step_hold, NO_INDEX));
     for i in 0..SYNDRM_STEPS {
-        sb(&track.kick_step_override_enabled[i], &format!("syndrm_kick_step_override_{}", i));
-        sf(&track.kick_step_pitch[i], &format!("syndrm_kick_step_pitch_{}", i));
-        sf(&track.kick_step_decay[i], &format!("syndrm_kick_step_decay_{}", i));
-        sf(&track.kick_step_attack[i], &format!("syndrm_kick_step_attack_{}", i));
-        sf(&track.kick_step_drive[i], &format!("syndrm_kick_step_drive_{}", i));
-        sf(&track.kick_step_level[i], &format!("syndrm_kick_step_level_{}", i));
-        su(&track.kick_step_filter_type[i], &format!("syndrm_kick_step_filter_type_{}", i));
-        sf(
-            &track.kick_step_filter_cutoff[i],
+        visit(
+            &format!("syndrm_kick_step_override_{}", i),
+            B(|t, [i, ..]| &t.kick_step_override_enabled[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_pitch_{}", i),
+            F(|t, [i, ..]| &t.kick_step_pitch[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_decay_{}", i),
+            F(|t, [i, ..]| &t.kick_step_decay[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_attack_{}", i),
+            F(|t, [i, ..]| &t.kick_step_attack[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_drive_{}", i),
+            F(|t, [i, ..]| &t.kick_step_drive[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_level_{}", i),
+            F(|t, [i, ..]| &t.kick_step_level[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_filter_type_{}", i),
+            U(|t, [i, ..]| &t.kick_step_filter_type[i], [i, 0, 0]),
+        );
+        visit(
             &format!("syndrm_kick_step_filter_cutoff_{}", i),
+            F(|t, [i, ..]| &t.kick_step_filter_cutoff[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_kick_step_filter_resonance_{}", i),
+            F(|t, [i, ..]| &t.kick_step_filter_resonance[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_snare_step_override_{}", i),
+            B(|t, [i, ..]| &t.snare_step_override_enabled[i], [i, 0, 0]),
         );
-        sf(
-            &track.kick_step_filter_resonance[i],
-            &format!("syndrm_kick_step_filter_resonance_{}", i),
+        visit(
+            &format!("syndrm_snare_step_tone_{}", i),
+            F(|t, [i, ..]| &t.snare_step_tone[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_snare_step_decay_{}", i),
+            F(|t, [i, ..]| &t.snare_step_decay[i], [i, 0, 0]),
         );
-        sb(&track.snare_step_override_enabled[i], &format!("syndrm_snare_step_override_{}", i));
-        sf(&track.snare_step_tone[i], &format!("syndrm_snare_step_tone_{}", i));
-        sf(&track.snare_step_decay[i], &format!("syndrm_snare_step_decay_{}", i));
-        sf(
-            &track.snare_step_snappy[i],
+        visit(
             &format!("syndrm_snare_step_snappy_{}", i),
+            F(|t, [i, ..]| &t.snare_step_snappy[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_snare_step_attack_{}", i),
+            F(|t, [i, ..]| &t.snare_step_attack[i], [i, 0, 0]),
+        );
+        visit(
+            &format!("syndrm_snare_step_drive_{}", i),
+            F(|t, [i, ..]| &t.snare_step_drive[i], [i, 0, 0]),
         );
-        sf(&track.snare_step_attack[i], &format!("syndrm_snare_step_attack_{}", i));
-        sf(&track.snare_step_drive[i], &format!("syndrm_snare_step_drive_{}", i));
-        sf(&track.snare_step_level[i], &format!("syndrm_snare_step_level_{}", i));
-        su(
-            &track.snare_step_filter_type[i],
+        visit(
+            &format!("syndrm_snare_step_level_{}", i),
+            F(|t, [i, ..]| &t.snare_step_level[i], [i, 0, 0]),
+        );
+        visit(
             &format!("syndrm_snare_step_filter_type_{}", i),
+            U(|t, [i, ..]| &t.snare_step_filter_type[i], [i, 0, 0]),
         );
-        sf(
-            &track.snare_step_filter_cutoff[i],
+        visit(
             &format!("syndrm_snare_step_filter_cutoff_{}", i),
+            F(|t, [i, ..]| &t.snare_step_filter_cutoff[i], [i, 0, 0]),
         );
-        sf(
-            &track.snare_step_filter_resonance[i],
+        visit(
             &format!("syndrm_snare_step_filter_resonance_{}", i),
+            F(|t, [i, ..]| &t.snare_step_filter_resonance[i], [i, 0, 0]),
         );
     }
+    for (voice, voice_key) in SYNDRM_VOICE_KEYS.iter().enumerate() {
+        for (p, param_key) in SYNDRM_VOICE_PARAM_KEYS.iter().enumerate() {
+            visit(
+                &format!("syndrm_{}_{}", voice_key, param_key),
+                F(|t, [v, p, _]| &t.syndrm_voice_params[v][p], [voice, p, 0]),
+            );
+        }
+    }
+
+    visit("void_base_freq", F(|t, _| &t.void_base_freq, NO_INDEX));
+    visit("void_chaos_depth", F(|t, _| &t.void_chaos_depth, NO_INDEX));
+    visit("void_entropy", F(|t, _| &t.void_entropy, NO_INDEX));
+    visit("void_feedback", F(|t, _| &t.void_feedback, NO_INDEX));
+    visit("void_diffusion", F(|t, _| &t.void_diffusion, NO_INDEX));
+    visit("void_mod_rate", F(|t, _| &t.void_mod_rate, NO_INDEX));
+    visit("void_level", F(|t, _| &t.void_level, NO_INDEX));
+    visit("void_enabled", B(|t, _| &t.void_enabled, NO_INDEX));
+}
+
+/// Default CC range for a track param; most controls are normalized 0..1.
+fn midi_cc_default_range(param: &str) -> (f32, f32) {
+    let env_param = ANIMATE_SLOT_ENV_KEYS.iter().position(|key| {
+        param
+            .strip_prefix("animate_slot_")
+            .and_then(|rest| rest.rsplit_once('_'))
+            .is_some_and(|(name, _)| name == *key)
+    });
+    if let Some(env_param) = env_param {
+        return animate_slot_env_range(env_param);
+    }
+    match param {
+        "swing" => (SWING_MIN, SWING_MAX),
+        "loop_length" => (0.01, 1.0),
+        "loop_xfade" => (0.0, 0.5),
+        "tape_speed" => (-4.0, 4.0),
+        "tape_pitch" => (-24.0, 24.0),
+        "tape_fine" => (-100.0, 100.0),
+        "void_base_freq" => (20.0, 200.0),
+        "void_feedback" => (0.0, 0.98),
+        "void_mod_rate" => (0.01, 10.0),
+        "animate_glide" => (0.0, 2.0),
+        "midi_channel" => (0.0, 16.0),
+        "loop_mode" => (0.0, 5.0),
+        "tape_rate_mode" => (0.0, 3.0),
+        "tape_keylock_mode" => (0.0, 2.0),
+        "tape_slice_mode" => (0.0, 2.0),
+        "kick_filter_type" | "snare_filter_type" => (0.0, (SYNDRM_FILTER_TYPES - 1) as f32),
+        _ if param.starts_with("animate_slot_coarse_") => (-24.0, 24.0),
+        _ if param.starts_with("animate_slot_fine_") => (-100.0, 100.0),
+        _ if param.starts_with("animate_slot_pan_") => (-1.0, 1.0),
+        _ if param.starts_with("animate_slot_filter_type_") => (0.0, ANIMATE_FILTER_MOOG as f32),
+        _ => (0.0, 1.0),
+    }
+}
+
+/// Shapes a normalized CC value: 0 = linear, 1 = exponential, 2 = logarithmic, 3 = S-curve.
+fn midi_cc_curve(value: f32, curve: u32) -> f32 {
+    let x = value.clamp(0.0, 1.0);
+    match curve {
+        1 => x * x,
+        2 => x.sqrt(),
+        3 => x * x * (3.0 - 2.0 * x),
+        _ => x,
+    }
+}
+
+/// Binds a pending learn target or applies a CC to every mapping listening for it.
+fn handle_midi_cc(
+    tracks: &Arc<[Track; NUM_TRACKS]>,
+    midi_cc: &MidiCcState,
+    channel: u8,
+    cc: u8,
+    value: f32,
+) {
+    if midi_cc.learn_armed.load(Ordering::Relaxed) {
+        let Some((track, param)) = midi_cc.learn_target.lock().take() else {
+            return;
+        };
+        let (min, max) = midi_cc_default_range(&param);
+        let fields = resolve_track_param(&param);
+        let mut mappings = midi_cc.mappings.lock();
+        mappings.retain(|m| !(m.track == track && m.param == param));
+        mappings.push(MidiCcMapping {
+            channel,
+            cc,
+            track,
+            param,
+            min,
+            max,
+            curve: 0,
+            fields,
+        });
+        midi_cc.mappings_changed(&mappings);
+        midi_cc.learn_armed.store(false, Ordering::Relaxed);
+        return;
+    }
+
+    let mappings = midi_cc.mappings.lock();
+    for mapping in mappings
+        .iter()
+        .filter(|m| m.channel == channel && m.cc == cc && m.track < NUM_TRACKS)
+    {
+        let shaped = midi_cc_curve(value, mapping.curve);
+        let mut target = mapping.min + (mapping.max - mapping.min) * shaped;
+        if mapping.param.ends_with("_type")
+            || mapping.param.ends_with("_mode")
+            || mapping.param.starts_with("animate_slot_filter_type_")
+        {
+            target = target.round();
+        }
+        for field in mapping.fields.iter() {
+            field.store(&tracks[mapping.track], target);
+        }
+    }
+}
+
+/// Appends the Animate per-step locks and note overrides to the `.trk` lists.
+fn capture_animate_steps(track: &Track, track_data: &mut TrackData) {
+    for step in track.animate_step_lock_enabled.iter() {
+        for enabled in step.iter() {
+            track_data
+                .animate_step_lock_enabled
+                .push(enabled.load(Ordering::Relaxed));
+        }
+    }
+    for step in track.animate_step_lock_values.iter() {
+        for value in step.iter() {
+            track_data
+                .animate_step_lock_values
+                .push(f32::from_bits(value.load(Ordering::Relaxed)));
+        }
+    }
+    for lane in track.animate_step_note_override.iter() {
+        for enabled in lane.iter() {
+            track_data
+                .animate_step_note_override
+                .push(enabled.load(Ordering::Relaxed));
+        }
+    }
+    for lane in track.animate_step_note.iter() {
+        for note in lane.iter() {
+            track_data.animate_step_note.push(note.load(Ordering::Relaxed));
+        }
+    }
+}
+
+/// Restores the Animate per-step locks and note overrides from the `.trk` lists.
+fn apply_animate_steps(track: &Track, track_data: &TrackData) {
+    for i in 0..ANIMATE_SEQ_STEPS {
+        for param in 0..ANIMATE_LOCK_PARAMS {
+            let idx = i * ANIMATE_LOCK_PARAMS + param;
+            if let Some(&enabled) = track_data.animate_step_lock_enabled.get(idx) {
+                track.animate_step_lock_enabled[i][param].store(enabled, Ordering::Relaxed);
+            }
+            if let Some(&value) = track_data.animate_step_lock_values.get(idx) {
+                track.animate_step_lock_values[i][param].store(value.to_bits(), Ordering::Relaxed);
+            }
+        }
+    }
+    for lane in 0..ANIMATE_SEQ_VOICES {
+        for i in 0..ANIMATE_SEQ_STEPS {
+            let idx = lane * ANIMATE_SEQ_STEPS + i;
+            if let Some(&enabled) = track_data.animate_step_note_override.get(idx) {
+                track.animate_step_note_override[lane][i].store(enabled, Ordering::Relaxed);
+            }
+            if let Some(&note) = track_data.animate_step_note.get(idx) {
+                track.animate_step_note[lane][i].store(note, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Appends the SynDRM per-step voice params and hit settings to the `.trk` lists.
+fn capture_syndrm_steps(track: &Track, track_data: &mut TrackData) {
+    for voice in 0..SYNDRM_VOICES {
+        for i in 0..SYNDRM_STEPS {
+            track_data
+                .syndrm_voice_step_override
+                .push(track.syndrm_voice_step_override_enabled[voice][i].load(Ordering::Relaxed));
+            for value in track.syndrm_voice_step_params[voice][i].iter() {
+                track_data
+                    .syndrm_voice_step_params
+                    .push(f32::from_bits(value.load(Ordering::Relaxed)));
+            }
+        }
+    }
+    for lane in 0..SYNDRM_LANES {
+        for i in 0..SYNDRM_STEPS {
+            let f = |value: &AtomicU32| f32::from_bits(value.load(Ordering::Relaxed));
+            track_data
+                .syndrm_step_velocity
+                .push(f(&track.syndrm_step_velocity[lane][i]));
+            track_data
+                .syndrm_step_probability
+                .push(f(&track.syndrm_step_probability[lane][i]));
+            track_data
+                .syndrm_step_micro
+                .push(f(&track.syndrm_step_micro[lane][i]));
+            track_data
+                .syndrm_step_ratchet
+                .push(track.syndrm_step_ratchet[lane][i].load(Ordering::Relaxed));
+        }
+    }
+}
 
-    sf(&track.void_base_freq, "void_base_freq");
-    sf(&track.void_chaos_depth, "void_chaos_depth");
-    sf(&track.void_entropy, "void_entropy");
-    sf(&track.void_feedback, "void_feedback");
-    sf(&track.void_diffusion, "void_diffusion");
-    sf(&track.void_mod_rate, "void_mod_rate");
-    sf(&track.void_level, "void_level");
-    sb(&track.void_enabled, "void_enabled");
+/// Restores the SynDRM per-step voice params and hit settings from the `.trk` lists.
+fn apply_syndrm_steps(track: &Track, track_data: &TrackData) {
+    for voice in 0..SYNDRM_VOICES {
+        for i in 0..SYNDRM_STEPS {
+            let idx = voice * SYNDRM_STEPS + i;
+            if let Some(&enabled) = track_data.syndrm_voice_step_override.get(idx) {
+                track.syndrm_voice_step_override_enabled[voice][i]
+                    .store(enabled, Ordering::Relaxed);
+            }
+            for p in 0..SYNDRM_VOICE_PARAMS {
+                let idx = idx * SYNDRM_VOICE_PARAMS + p;
+                if let Some(&value) = track_data.syndrm_voice_step_params.get(idx) {
+                    track.syndrm_voice_step_params[voice][i][p]
+                        .store(value.to_bits(), Ordering::Relaxed);
+                }
+            }
+        }
+    }
+    for lane in 0..SYNDRM_LANES {
+        for i in 0..SYNDRM_STEPS {
+            let idx = lane * SYNDRM_STEPS + i;
+            let sf = |dst: &AtomicU32, src: &[f32]| {
+                if let Some(&value) = src.get(idx) {
+                    dst.store(value.to_bits(), Ordering::Relaxed);
+                }
+            };
+            sf(&track.syndrm_step_velocity[lane][i], &track_data.syndrm_step_velocity);
+            sf(&track.syndrm_step_probability[lane][i], &track_data.syndrm_step_probability);
+            sf(&track.syndrm_step_micro[lane][i], &track_data.syndrm_step_micro);
+            if let Some(&ratchet) = track_data.syndrm_step_ratchet.get(idx) {
+                track.syndrm_step_ratchet[lane][i].store(ratchet, Ordering::Relaxed);
+            }
+        }
+    }
 }
 
 fn save_project(
     tracks: &Arc<[Track; NUM_TRACKS]>,
     global_tempo: f32,
     params: &Arc<TLBX1Params>,
+    midi_cc: &MidiCcState,
     title: &str,
     description: &str,
     project_dir: &PathBuf,
@@ -6811,16 +11513,20 @@ fn save_project(
             params: HashMap::new(),
             sequence: Vec::new(),
             sample_path: None,
+            ..Default::default()
         };
 
         capture_track_params(track, &mut track_data.params);
 
         if track_data.engine_type == 2 {
-            let grid = track.animate_sequencer_grid.clone();
-            for j in 0..160 {
-                track_data.sequence.push(grid[j].load(Ordering::Relaxed));
+            for lane_grid in track.animate_sequencer_grid.iter() {
+                for j in 0..ANIMATE_SEQ_STEPS {
+                    track_data.sequence.push(lane_grid[j].load(Ordering::Relaxed));
+                }
             }
+            capture_animate_steps(track, &mut track_data);
         } else if track_data.engine_type == 3 {
+            capture_syndrm_steps(track, &mut track_data);
             let grid = track.kick_sequencer_grid.clone();
             for j in 0..SYNDRM_STEPS {
                 track_data.sequence.push(grid[j].load(Ordering::Relaxed));
@@ -6829,6 +11535,11 @@ fn save_project(
             for j in 0..SYNDRM_STEPS {
                 track_data.sequence.push(snare_grid[j].load(Ordering::Relaxed));
             }
+            for voice_grid in track.syndrm_voice_grid.iter() {
+                for j in 0..SYNDRM_STEPS {
+                    track_data.sequence.push(voice_grid[j].load(Ordering::Relaxed));
+                }
+            }
         }
 
         if let Some(path) = track.sample_path.lock().as_ref() {
@@ -6855,7 +11566,9 @@ fn save_project(
         master_gain: params.gain.value(),
         master_filter: params.master_filter.value(),
         master_comp: params.master_comp.value(),
+        swing: params.swing.value(),
         tracks: track_file_names,
+        midi_cc_mappings: midi_cc.mappings.lock().clone(),
     };
 
     let project_file_name = format!("{}.tlbx", title);
@@ -6871,6 +11584,7 @@ fn load_project(
     global_tempo: &Arc<AtomicU32>,
     _params: &Arc<TLBX1Params>,
     pending_project_params: &Arc<Mutex<Option<PendingProjectParams>>>,
+    midi_cc: &MidiCcState,
     path: &PathBuf,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let project_dir = path.parent().ok_or("Invalid project path")?;
@@ -6882,7 +11596,9 @@ fn load_project(
         gain: project.master_gain,
         master_filter: project.master_filter,
         master_comp: project.master_comp,
+        swing: project.swing,
     });
+    midi_cc.set_mappings(project.midi_cc_mappings);
 
     for (track_idx, track_file_name) in project.tracks.iter().enumerate() {
         if track_idx >= NUM_TRACKS {
@@ -6896,15 +11612,42 @@ fn load_project(
         let track_json = fs::read_to_string(track_path)?;
         let track_data: TrackData = serde_json::from_str(&track_json)?;
 
-        track.engine_type.store(track_data.engine_type, Ordering::Relaxed);
+        // Start from defaults so nothing the file leaves out survives from the last project.
+        reset_track_for_engine(track, track_data.engine_type);
         apply_track_params(track, &track_data.params);
+        apply_animate_steps(track, &track_data);
+        apply_syndrm_steps(track, &track_data);
+        // Projects saved before root detection played every sample on A4.
+        if !track_data.params.contains_key("animate_sample_default_root") {
+            track
+                .animate_sample_default_root
+                .store(ANIMATE_SAMPLE_LEGACY_ROOT.to_bits(), Ordering::Relaxed);
+        }
+        // Projects saved before keylock modes used the Classic algorithm.
+        if !track_data.params.contains_key("tape_keylock_mode") {
+            track.tape_keylock_mode.store(0, Ordering::Relaxed);
+        }
 
-        if track_data.engine_type == 2 && track_data.sequence.len() == 160 {
-            let grid = track.animate_sequencer_grid.clone();
-            for j in 0..160 {
-                grid[j].store(track_data.sequence[j], Ordering::Relaxed);
+        if track_data.engine_type == 2
+            && (track_data.sequence.len() == ANIMATE_SEQ_STEPS * ANIMATE_SEQ_VOICES
+                || track_data.sequence.len() == 160)
+        {
+            // Older projects stored a single 16-step page per lane.
+            let lane_steps = track_data.sequence.len() / ANIMATE_SEQ_VOICES;
+            for (lane, lane_grid) in track.animate_sequencer_grid.iter().enumerate() {
+                for j in 0..ANIMATE_SEQ_STEPS {
+                    let active = if j < lane_steps {
+                        track_data.sequence[lane * lane_steps + j]
+                    } else {
+                        false
+                    };
+                    lane_grid[j].store(active, Ordering::Relaxed);
+                }
             }
-        } else if track_data.engine_type == 3 && track_data.sequence.len() == SYNDRM_STEPS * 2 {
+        } else if track_data.engine_type == 3
+            && (track_data.sequence.len() == SYNDRM_STEPS * SYNDRM_LANES
+                || track_data.sequence.len() == SYNDRM_STEPS * 2)
+        {
             let grid = track.kick_sequencer_grid.clone();
             for j in 0..SYNDRM_STEPS {
                 grid[j].store(track_data.sequence[j], Ordering::Relaxed);
@@ -6913,6 +11656,14 @@ fn load_project(
             for j in 0..SYNDRM_STEPS {
                 snare_grid[j].store(track_data.sequence[j + SYNDRM_STEPS], Ordering::Relaxed);
             }
+            // Two-lane projects predate the extra voices; their grids load empty.
+            for (voice, voice_grid) in track.syndrm_voice_grid.iter().enumerate() {
+                let offset = (voice + 2) * SYNDRM_STEPS;
+                for j in 0..SYNDRM_STEPS {
+                    let active = track_data.sequence.get(offset + j).copied().unwrap_or(false);
+                    voice_grid[j].store(active, Ordering::Relaxed);
+                }
+            }
         } else if track_data.engine_type == 3 && track_data.sequence.len() == 32 {
             let grid = track.kick_sequencer_grid.clone();
             for j in 0..16 {
@@ -6931,119 +11682,657 @@ fn load_project(
 
         track.is_playing.store(false, Ordering::Relaxed);
         track.is_recording.store(false, Ordering::Relaxed);
+        track.record_generation.fetch_add(1, Ordering::AcqRel);
         track.play_pos.store(0.0f32.to_bits(), Ordering::Relaxed);
 
-        let mut samples = track.samples.lock();
-        let mut summary = track.waveform_summary.lock();
-        let mut sample_path = track.sample_path.lock();
-        
-        if let Some(rel_path) = &track_data.sample_path {
+        // Load and analyse before taking the lock the audio thread reads through.
+        let loaded = track_data.sample_path.as_ref().and_then(|rel_path| {
             let abs_path = project_dir.join(rel_path);
             match load_audio_file(&abs_path) {
                 Ok((new_samples, sample_rate)) => {
-                    *samples = new_samples;
-                    *sample_path = Some(abs_path);
-                    track.sample_rate.store(sample_rate, Ordering::Relaxed);
-                    if !samples.is_empty() {
-                        calculate_waveform_summary(&samples[0], &mut summary);
-                    }
+                    let flux = tape_onset_flux(&new_samples);
+                    let slices = detect_tape_slices(&new_samples, &flux, sample_rate);
+                    let tempo = detect_tape_tempo(&new_samples, &flux, sample_rate);
+                    Some((abs_path, new_samples, sample_rate, slices, tempo))
                 }
                 Err(err) => {
                     nih_log!("Failed to load sample for track {}: {:?}", track_idx, err);
-                    *samples = vec![vec![]; 2];
-                    *summary = vec![0.0; WAVEFORM_SUMMARY_SIZE];
-                    *sample_path = None;
-                    track.sample_rate.store(44_100, Ordering::Relaxed);
+                    None
+                }
+            }
+        });
+
+        let mut samples = track.samples.lock();
+        let mut summary = track.waveform_summary.lock();
+        let mut sample_path = track.sample_path.lock();
+
+        if let Some((abs_path, new_samples, sample_rate, slices, tempo)) = loaded {
+            *samples = new_samples;
+            *sample_path = Some(abs_path);
+            track.sample_rate.store(sample_rate, Ordering::Relaxed);
+            if !samples.is_empty() {
+                calculate_waveform_summary(&samples[0], &mut summary);
+            }
+            // The saved slice order is applied with the track params.
+            store_tape_slices(track, &slices, false);
+            store_tape_tempo(track, tempo);
+        } else {
+            *samples = vec![vec![]; 2];
+            *summary = vec![0.0; WAVEFORM_SUMMARY_SIZE];
+            *sample_path = None;
+            track.sample_rate.store(44_100, Ordering::Relaxed);
+            store_tape_slices(track, &[], true);
+            store_tape_tempo(track, None);
+        }
+    }
+    Ok(())
+}
+
+fn export_project_as_zip(
+    tracks: &Arc<[Track; NUM_TRACKS]>,
+    global_tempo: f32,
+    params: &Arc<TLBX1Params>,
+    midi_cc: &MidiCcState,
+    title: &str,
+    description: &str,
+    zip_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = fs::File::create(zip_path)?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .unix_permissions(0o755);
+
+    let mut track_file_names = Vec::new();
+    for i in 1..=NUM_TRACKS {
+        track_file_names.push(format!("{}.trk", i));
+    }
+
+    let project_data = ProjectData {
+        title: title.to_string(),
+        description: description.to_string(),
+        bpm: global_tempo,
+        master_gain: params.gain.value(),
+        master_filter: params.master_filter.value(),
+        master_comp: params.master_comp.value(),
+        swing: params.swing.value(),
+        tracks: track_file_names,
+        midi_cc_mappings: midi_cc.mappings.lock().clone(),
+    };
+
+    let project_json = serde_json::to_string_pretty(&project_data)?;
+    zip.start_file(format!("{}.tlbx", title), options)?;
+    zip.write_all(project_json.as_bytes())?;
+
+    for (i, track) in tracks.iter().enumerate() {
+        let mut track_data = TrackData {
+            engine_type: track.engine_type.load(Ordering::Relaxed),
+            params: HashMap::new(),
+            sequence: Vec::new(),
+            sample_path: None,
+            ..Default::default()
+        };
+
+        capture_track_params(track, &mut track_data.params);
+
+        if track_data.engine_type == 2 {
+            for lane_grid in track.animate_sequencer_grid.iter() {
+                for j in 0..ANIMATE_SEQ_STEPS {
+                    track_data.sequence.push(lane_grid[j].load(Ordering::Relaxed));
+                }
+            }
+            capture_animate_steps(track, &mut track_data);
+        } else if track_data.engine_type == 3 {
+            capture_syndrm_steps(track, &mut track_data);
+            let grid = track.kick_sequencer_grid.clone();
+            for j in 0..SYNDRM_STEPS {
+                track_data.sequence.push(grid[j].load(Ordering::Relaxed));
+            }
+            let snare_grid = track.snare_sequencer_grid.clone();
+            for j in 0..SYNDRM_STEPS {
+                track_data.sequence.push(snare_grid[j].load(Ordering::Relaxed));
+            }
+            for voice_grid in track.syndrm_voice_grid.iter() {
+                for j in 0..SYNDRM_STEPS {
+                    track_data.sequence.push(voice_grid[j].load(Ordering::Relaxed));
+                }
+            }
+        }
+
+        if let Some(path) = track.sample_path.lock().as_ref() {
+            if let Some(file_name) = path.file_name() {
+                let rel_sample_path = format!("samples/{}", file_name.to_string_lossy());
+                track_data.sample_path = Some(rel_sample_path.clone());
+
+                if path.exists() {
+                    zip.start_file(rel_sample_path, options)?;
+                    let sample_bytes = fs::read(path)?;
+                    zip.write_all(&sample_bytes)?;
+                }
+            }
+        }
+
+        let track_json = serde_json::to_string_pretty(&track_data)?;
+        zip.start_file(format!("{}.trk", i + 1), options)?;
+        zip.write_all(track_json.as_bytes())?;
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Copies everything a render needs from a live track into a fresh one.
+fn snapshot_track_for_render(src: &Track, dst: &Track) {
+    let engine_type = src.engine_type.load(Ordering::Relaxed);
+    reset_track_for_engine(dst, engine_type);
+
+    let mut params = HashMap::new();
+    capture_track_params(src, &mut params);
+    apply_track_params(dst, &params);
+
+    for lane in 0..ANIMATE_SEQ_VOICES {
+        for j in 0..ANIMATE_SEQ_STEPS {
+            dst.animate_sequencer_grid[lane][j].store(
+                src.animate_sequencer_grid[lane][j].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+    }
+    for j in 0..ANIMATE_SEQ_STEPS {
+        for param in 0..ANIMATE_LOCK_PARAMS {
+            dst.animate_step_lock_enabled[j][param].store(
+                src.animate_step_lock_enabled[j][param].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            dst.animate_step_lock_values[j][param].store(
+                src.animate_step_lock_values[j][param].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+    }
+    for lane in 0..ANIMATE_SEQ_VOICES {
+        for j in 0..ANIMATE_SEQ_STEPS {
+            dst.animate_step_note_override[lane][j].store(
+                src.animate_step_note_override[lane][j].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            dst.animate_step_note[lane][j].store(
+                src.animate_step_note[lane][j].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+    }
+    let copy = |dst: &AtomicU32, src: &AtomicU32| {
+        dst.store(src.load(Ordering::Relaxed), Ordering::Relaxed);
+    };
+    for voice in 0..SYNDRM_VOICES {
+        for j in 0..SYNDRM_STEPS {
+            dst.syndrm_voice_step_override_enabled[voice][j].store(
+                src.syndrm_voice_step_override_enabled[voice][j].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            for p in 0..SYNDRM_VOICE_PARAMS {
+                copy(
+                    &dst.syndrm_voice_step_params[voice][j][p],
+                    &src.syndrm_voice_step_params[voice][j][p],
+                );
+            }
+        }
+    }
+    for lane in 0..SYNDRM_LANES {
+        for j in 0..SYNDRM_STEPS {
+            copy(&dst.syndrm_step_velocity[lane][j], &src.syndrm_step_velocity[lane][j]);
+            copy(&dst.syndrm_step_probability[lane][j], &src.syndrm_step_probability[lane][j]);
+            copy(&dst.syndrm_step_micro[lane][j], &src.syndrm_step_micro[lane][j]);
+            copy(&dst.syndrm_step_ratchet[lane][j], &src.syndrm_step_ratchet[lane][j]);
+        }
+    }
+    for j in 0..SYNDRM_STEPS {
+        dst.kick_sequencer_grid[j]
+            .store(src.kick_sequencer_grid[j].load(Ordering::Relaxed), Ordering::Relaxed);
+        dst.snare_sequencer_grid[j]
+            .store(src.snare_sequencer_grid[j].load(Ordering::Relaxed), Ordering::Relaxed);
+        for voice in 0..SYNDRM_VOICES {
+            dst.syndrm_voice_grid[voice][j].store(
+                src.syndrm_voice_grid[voice][j].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+    }
+
+    let samples = src.samples.lock().clone();
+    let has_samples = !samples.is_empty() && !samples[0].is_empty();
+    *dst.samples.lock() = samples;
+    dst.sample_rate
+        .store(src.sample_rate.load(Ordering::Relaxed), Ordering::Relaxed);
+    // Tape tracks only play when they have material; the other engines follow the transport.
+    dst.is_playing.store(has_samples, Ordering::Relaxed);
+}
+
+fn write_render_sample<W: std::io::Write + std::io::Seek>(
+    writer: &mut hound::WavWriter<W>,
+    sample: f32,
+    bit_depth: u16,
+) -> Result<(), hound::Error> {
+    match bit_depth {
+        16 => writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16),
+        24 => writer.write_sample((sample.clamp(-1.0, 1.0) * 8_388_607.0).round() as i32),
+        _ => writer.write_sample(sample),
+    }
+}
+
+/// Bounces the master output (and optionally per-track stems) to WAV faster than real time.
+///
+/// The live tracks are snapshotted into a private engine instance, so rendering never
+/// disturbs playback on the audio thread.
+fn render_offline(
+    tracks: &Arc<[Track; NUM_TRACKS]>,
+    animate_library: &Arc<AnimateLibrary>,
+    global_tempo: f32,
+    params: &Arc<TLBX1Params>,
+    options: RenderOptions,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempo = if global_tempo.is_finite() {
+        global_tempo.clamp(20.0, 240.0)
+    } else {
+        120.0
+    };
+    let sample_rate = options.sample_rate.clamp(8_000, 192_000);
+    let seconds = match options.length {
+        RenderLength::Bars(bars) => bars.max(1) as f32 * 4.0 * 60.0 / tempo,
+        RenderLength::Seconds(seconds) => seconds.max(0.1),
+    };
+    let total_samples = (seconds * sample_rate as f32).round() as usize;
+
+    let mut renderer = TLBX1::with_library(Arc::clone(animate_library));
+    renderer.sample_rate.store(sample_rate, Ordering::Relaxed);
+    renderer
+        .global_tempo
+        .store(tempo.to_bits(), Ordering::Relaxed);
+    renderer.follow_host_tempo.store(false, Ordering::Relaxed);
+    renderer.track_buffer = vec![vec![0.0; RENDER_BLOCK_SIZE]; 2];
+    renderer.render_master = Some(PendingProjectParams {
+        gain: params.gain.value(),
+        master_filter: params.master_filter.value(),
+        master_comp: params.master_comp.value(),
+        swing: params.swing.value(),
+    });
+    if options.stems {
+        renderer.render_stems = vec![vec![vec![0.0; RENDER_BLOCK_SIZE]; 2]; NUM_TRACKS];
+    }
+    for (src, dst) in tracks.iter().zip(renderer.tracks.iter()) {
+        snapshot_track_for_render(src, dst);
+        // The render can't wait on the loader thread, so decode anything missing here.
+        if dst.engine_type.load(Ordering::Relaxed) == 2 {
+            for request in animate_library.track_load_requests(dst) {
+                animate_library.load_now(request);
+            }
+        }
+    }
+
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate,
+        bits_per_sample: match options.bit_depth {
+            16 => 16,
+            24 => 24,
+            _ => 32,
+        },
+        sample_format: if options.bit_depth == 16 || options.bit_depth == 24 {
+            hound::SampleFormat::Int
+        } else {
+            hound::SampleFormat::Float
+        },
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    let mut stem_writers = Vec::new();
+    if options.stems {
+        let stem_base = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| "render".to_string());
+        for track_idx in 0..NUM_TRACKS {
+            let stem_path = path.with_file_name(format!("{}_track{}.wav", stem_base, track_idx + 1));
+            stem_writers.push(hound::WavWriter::create(stem_path, spec)?);
+        }
+    }
+
+    let mut block = vec![vec![0.0f32; RENDER_BLOCK_SIZE]; 2];
+    let mut rendered = 0;
+    while rendered < total_samples {
+        let len = (total_samples - rendered).min(RENDER_BLOCK_SIZE);
+        let mut output: Vec<&mut [f32]> = block
+            .iter_mut()
+            .map(|channel| {
+                channel[..len].fill(0.0);
+                &mut channel[..len]
+            })
+            .collect();
+        renderer.process_block(
+            &mut output,
+            None,
+            sample_rate as f32,
+            HostTransport::default(),
+        );
+
+        for i in 0..len {
+            for channel in output.iter() {
+                write_render_sample(&mut writer, channel[i], spec.bits_per_sample)?;
+            }
+        }
+        for (stem, stem_writer) in renderer.render_stems.iter().zip(stem_writers.iter_mut()) {
+            for i in 0..len {
+                for channel in stem.iter() {
+                    write_render_sample(stem_writer, channel[i], spec.bits_per_sample)?;
                 }
             }
-        } else {
-            *samples = vec![vec![]; 2];
-            *summary = vec![0.0; WAVEFORM_SUMMARY_SIZE];
-            *sample_path = None;
-            track.sample_rate.store(44_100, Ordering::Relaxed);
         }
+        rendered += len;
     }
-    Ok(())
-}
-
-fn export_project_as_zip(
-    tracks: &Arc<[Track; NUM_TRACKS]>,
-    global_tempo: f32,
-    params: &Arc<TLBX1Params>,
-    title: &str,
-    description: &str,
-    zip_path: &Path,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let file = fs::File::create(zip_path)?;
-    let mut zip = zip::ZipWriter::new(file);
-    let options = zip::write::SimpleFileOptions::default()
-        .compression_method(zip::CompressionMethod::Deflated)
-        .unix_permissions(0o755);
 
-    let mut track_file_names = Vec::new();
-    for i in 1..=NUM_TRACKS {
-        track_file_names.push(format!("{}.trk", i));
+    writer.finalize()?;
+    for stem_writer in stem_writers {
+        stem_writer.finalize()?;
     }
+    Ok(())
+}
 
-    let project_data = ProjectData {
-        title: title.to_string(),
-        description: description.to_string(),
-        bpm: global_tempo,
-        master_gain: params.gain.value(),
-        master_filter: params.master_filter.value(),
-        master_comp: params.master_comp.value(),
-        tracks: track_file_names,
-    };
+/// Folder scanned for the user's own Animate wavetables, alongside the factory set.
+fn user_wavetables_dir() -> PathBuf {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default())
+        .join("TLBX-1")
+        .join("Wavetables")
+}
 
-    let project_json = serde_json::to_string_pretty(&project_data)?;
-    zip.start_file(format!("{}.tlbx", title), options)?;
-    zip.write_all(project_json.as_bytes())?;
+fn master_recordings_dir() -> PathBuf {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default())
+        .join("TLBX-1")
+        .join("Recordings")
+}
 
-    for (i, track) in tracks.iter().enumerate() {
-        let mut track_data = TrackData {
-            engine_type: track.engine_type.load(Ordering::Relaxed),
-            params: HashMap::new(),
-            sequence: Vec::new(),
-            sample_path: None,
-        };
+/// Formats the current UTC time as `YYYYMMDD-HHMMSS` for recording file names.
+fn recording_timestamp() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let days = secs.div_euclid(86_400);
+    let day_secs = secs.rem_euclid(86_400);
+    // Civil-from-days conversion (proleptic Gregorian calendar).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        day_secs / 3_600,
+        (day_secs / 60) % 60,
+        day_secs % 60
+    )
+}
 
-        capture_track_params(track, &mut track_data.params);
+/// Arms the master recorder and spawns the thread that drains it to a timestamped WAV.
+fn start_master_recording(recorder: &Arc<MasterRecorder>) {
+    // A previous take is still flushing; leave the ring to it.
+    if recorder.writer_active.load(Ordering::Acquire) {
+        return;
+    }
+    let dir = master_recordings_dir();
+    if let Err(err) = fs::create_dir_all(&dir) {
+        nih_log!("Failed to create recordings folder {:?}: {:?}", dir, err);
+        return;
+    }
+    let path = dir.join(format!("master_{}.wav", recording_timestamp()));
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: recorder.sample_rate.load(Ordering::Relaxed).max(1),
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = match hound::WavWriter::create(&path, spec) {
+        Ok(writer) => writer,
+        Err(err) => {
+            nih_log!("Failed to start master recording: {:?}", err);
+            return;
+        }
+    };
 
-        if track_data.engine_type == 2 {
-            let grid = track.animate_sequencer_grid.clone();
-            for j in 0..160 {
-                track_data.sequence.push(grid[j].load(Ordering::Relaxed));
+    recorder.read_pos.store(0, Ordering::Relaxed);
+    recorder.write_pos.store(0, Ordering::Relaxed);
+    recorder.dropped_frames.store(0, Ordering::Relaxed);
+    recorder.writer_active.store(true, Ordering::Release);
+    recorder.armed.store(true, Ordering::Release);
+
+    let recorder = Arc::clone(recorder);
+    std::thread::spawn(move || {
+        let mut scratch = Vec::with_capacity(MASTER_RECORD_RING_FRAMES * 2);
+        let mut result: Result<(), hound::Error> = Ok(());
+        loop {
+            // Read the flag before draining so the last frames pushed before disarm are kept.
+            let still_armed = recorder.armed.load(Ordering::Acquire);
+            scratch.clear();
+            recorder.pop_frames(&mut scratch);
+            for sample in scratch.iter() {
+                if let Err(err) = writer.write_sample(*sample) {
+                    result = Err(err);
+                    break;
+                }
             }
-        } else if track_data.engine_type == 3 {
-            let grid = track.kick_sequencer_grid.clone();
-            for j in 0..SYNDRM_STEPS {
-                track_data.sequence.push(grid[j].load(Ordering::Relaxed));
+            if result.is_err() || !still_armed {
+                break;
             }
-            let snare_grid = track.snare_sequencer_grid.clone();
-            for j in 0..SYNDRM_STEPS {
-                track_data.sequence.push(snare_grid[j].load(Ordering::Relaxed));
+            if scratch.is_empty() {
+                std::thread::sleep(std::time::Duration::from_millis(MASTER_RECORD_POLL_MS));
             }
         }
-
-        if let Some(path) = track.sample_path.lock().as_ref() {
-            if let Some(file_name) = path.file_name() {
-                let rel_sample_path = format!("samples/{}", file_name.to_string_lossy());
-                track_data.sample_path = Some(rel_sample_path.clone());
-
-                if path.exists() {
-                    zip.start_file(rel_sample_path, options)?;
-                    let sample_bytes = fs::read(path)?;
-                    zip.write_all(&sample_bytes)?;
-                }
+        recorder.armed.store(false, Ordering::Release);
+        let result = result.and_then(|_| writer.finalize());
+        let dropped = recorder.dropped_frames.load(Ordering::Relaxed);
+        match result {
+            Ok(()) if dropped > 0 => {
+                nih_log!("Saved master recording {:?} ({} frames dropped)", path, dropped)
             }
+            Ok(()) => nih_log!("Saved master recording: {:?}", path),
+            Err(err) => nih_log!("Failed to write master recording: {:?}", err),
         }
+        recorder.writer_active.store(false, Ordering::Release);
+    });
+}
 
-        let track_json = serde_json::to_string_pretty(&track_data)?;
-        zip.start_file(format!("{}.trk", i + 1), options)?;
-        zip.write_all(track_json.as_bytes())?;
+/// Combo entries for a MIDI clock port picker: the "off" entry, then the ports.
+fn midi_clock_port_model(off_label: &str, ports: &[String]) -> ModelRc<SharedString> {
+    ModelRc::new(VecModel::from(
+        std::iter::once(SharedString::from(off_label))
+            .chain(ports.iter().map(|port| SharedString::from(port.as_str())))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn available_midi_inputs() -> Vec<String> {
+    match midir::MidiInput::new("TLBX-1") {
+        Ok(input) => input
+            .ports()
+            .iter()
+            .filter_map(|port| input.port_name(port).ok())
+            .collect(),
+        Err(_) => Vec::new(),
     }
+}
 
-    zip.finish()?;
+fn available_midi_outputs() -> Vec<String> {
+    match midir::MidiOutput::new("TLBX-1") {
+        Ok(output) => output
+            .ports()
+            .iter()
+            .filter_map(|port| output.port_name(port).ok())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Opens a MIDI input as the clock source. Clock ticks set the tempo and song
+/// position; Start/Continue/Stop drive the transport while clock follow is on.
+fn connect_midi_clock_input(
+    port_index: usize,
+    clock: &Arc<MidiClock>,
+    tracks: &Arc<[Track; NUM_TRACKS]>,
+) -> Result<midir::MidiInputConnection<()>, String> {
+    let mut input = midir::MidiInput::new("TLBX-1 Clock In").map_err(|err| err.to_string())?;
+    input.ignore(midir::Ignore::SysexAndActiveSense);
+    let ports = input.ports();
+    let port = ports
+        .get(port_index)
+        .ok_or_else(|| "MIDI input not found".to_string())?
+        .clone();
+
+    let clock = Arc::clone(clock);
+    let tracks = Arc::clone(tracks);
+    let mut last_tick_us: Option<u64> = None;
+    let mut tick_interval_us = 0.0f64;
+    input
+        .connect(
+            &port,
+            "tlbx1-clock-in",
+            move |stamp, message, _| {
+                let Some(&status) = message.first() else {
+                    return;
+                };
+                if !clock.follow.load(Ordering::Relaxed) {
+                    last_tick_us = None;
+                    return;
+                }
+                match status {
+                    0xF8 => {
+                        if let Some(last) = last_tick_us {
+                            let interval = stamp.saturating_sub(last) as f64;
+                            // Anything slower than 20 BPM is a gap in the clock, not a tempo.
+                            let max_interval = 60_000_000.0 / (20.0 * MIDI_CLOCK_PPQN as f64);
+                            if interval > 0.0 && interval <= max_interval {
+                                tick_interval_us = if tick_interval_us > 0.0 {
+                                    tick_interval_us * 0.9 + interval * 0.1
+                                } else {
+                                    interval
+                                };
+                                let tempo =
+                                    60_000_000.0 / (tick_interval_us * MIDI_CLOCK_PPQN as f64);
+                                clock.tempo.store(
+                                    (tempo as f32).clamp(20.0, 240.0).to_bits(),
+                                    Ordering::Relaxed,
+                                );
+                            }
+                        }
+                        last_tick_us = Some(stamp);
+                        if clock.running.load(Ordering::Relaxed) {
+                            clock.tick_position.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                    0xFA => {
+                        clock.tick_position.store(0, Ordering::Relaxed);
+                        clock.running.store(true, Ordering::Relaxed);
+                        let tempo = f32::from_bits(clock.tempo.load(Ordering::Relaxed));
+                        start_transport(&tracks, tempo.max(20.0), 0, false);
+                    }
+                    0xFB => {
+                        // Continue picks up at the song position pointer; no rewind.
+                        clock.running.store(true, Ordering::Relaxed);
+                        resume_transport(&tracks);
+                    }
+                    0xFC => {
+                        clock.running.store(false, Ordering::Relaxed);
+                        stop_transport(&tracks);
+                    }
+                    0xF2 if message.len() >= 3 => {
+                        // Song position pointer counts sixteenth notes.
+                        let sixteenths =
+                            (message[1] & 0x7F) as i64 | (((message[2] & 0x7F) as i64) << 7);
+                        clock.tick_position.store(
+                            sixteenths * MIDI_CLOCK_TICKS_PER_STEP as i64,
+                            Ordering::Relaxed,
+                        );
+                    }
+                    _ => {}
+                }
+            },
+            (),
+        )
+        .map_err(|err| err.to_string())
+}
+
+/// Spawns the clock out thread for a MIDI output, retiring any previous one.
+///
+/// Ticks are paced by a timer for even spacing and pulled back in line with the
+/// audio thread's play position whenever the two drift a full step apart.
+fn start_midi_clock_output(
+    port_index: usize,
+    clock: &Arc<MidiClock>,
+    global_tempo: &Arc<AtomicU32>,
+) -> Result<(), String> {
+    let output = midir::MidiOutput::new("TLBX-1 Clock Out").map_err(|err| err.to_string())?;
+    let ports = output.ports();
+    let port = ports
+        .get(port_index)
+        .ok_or_else(|| "MIDI output not found".to_string())?
+        .clone();
+    let mut connection = output
+        .connect(&port, "tlbx1-clock-out")
+        .map_err(|err| err.to_string())?;
+
+    let generation = clock.out_generation.fetch_add(1, Ordering::AcqRel) + 1;
+    let clock = Arc::clone(clock);
+    let global_tempo = Arc::clone(global_tempo);
+    std::thread::spawn(move || {
+        let mut was_playing = false;
+        let mut sent_ticks = 0u64;
+        let mut next_tick = Instant::now();
+        while clock.out_generation.load(Ordering::Acquire) == generation {
+            let playing = clock.out_playing.load(Ordering::Acquire);
+            if playing != was_playing {
+                let _ = connection.send(&[if playing { 0xFA } else { 0xFC }]);
+                was_playing = playing;
+                sent_ticks = 0;
+                next_tick = Instant::now();
+            }
+            if playing {
+                let tempo =
+                    f32::from_bits(global_tempo.load(Ordering::Relaxed)).clamp(20.0, 240.0);
+                let interval = std::time::Duration::from_secs_f64(
+                    60.0 / (tempo as f64 * MIDI_CLOCK_PPQN as f64),
+                );
+                let played = clock.out_ticks.load(Ordering::Relaxed);
+                let now = Instant::now();
+                if played > sent_ticks + MIDI_CLOCK_TICKS_PER_STEP
+                    || now > next_tick + interval
+                {
+                    next_tick = now;
+                }
+                if now >= next_tick && sent_ticks < played + MIDI_CLOCK_TICKS_PER_STEP {
+                    let _ = connection.send(&[0xF8]);
+                    sent_ticks += 1;
+                    next_tick += interval;
+                }
+            }
+            std::thread::sleep(std::time::Duration::from_micros(500));
+        }
+        if was_playing {
+            let _ = connection.send(&[0xFC]);
+        }
+        connection.close();
+    });
     Ok(())
 }
 
@@ -7108,6 +12397,7 @@ struct SlintEditor {
     master_meters: Arc<MasterMeters>,
     visualizer: Arc<VisualizerState>,
     global_tempo: Arc<AtomicU32>,
+    host_sample_rate: Arc<AtomicU32>,
     follow_host_tempo: Arc<AtomicBool>,
     metronome_enabled: Arc<AtomicBool>,
     metronome_count_in_ticks: Arc<AtomicU32>,
@@ -7116,6 +12406,9 @@ struct SlintEditor {
     async_executor: AsyncExecutor<TLBX1>,
     pending_project_params: Arc<Mutex<Option<PendingProjectParams>>>,
     animate_library: Arc<AnimateLibrary>,
+    master_recorder: Arc<MasterRecorder>,
+    midi_cc: Arc<MidiCcState>,
+    midi_clock: Arc<MidiClock>,
 }
 
 impl Editor for SlintEditor {
@@ -7129,6 +12422,7 @@ impl Editor for SlintEditor {
         let master_meters = self.master_meters.clone();
         let visualizer = self.visualizer.clone();
         let global_tempo = self.global_tempo.clone();
+        let host_sample_rate = self.host_sample_rate.clone();
         let follow_host_tempo = self.follow_host_tempo.clone();
         let metronome_enabled = self.metronome_enabled.clone();
         let metronome_count_in_ticks = self.metronome_count_in_ticks.clone();
@@ -7137,6 +12431,9 @@ impl Editor for SlintEditor {
         let async_executor = self.async_executor.clone();
         let pending_project_params = self.pending_project_params.clone();
         let animate_library = self.animate_library.clone();
+        let master_recorder = self.master_recorder.clone();
+        let midi_cc = self.midi_cc.clone();
+        let midi_clock = self.midi_clock.clone();
 
         let initial_size = default_window_size();
         let window_handle = baseview::Window::open_parented(
@@ -7157,6 +12454,7 @@ impl Editor for SlintEditor {
                     master_meters,
                     visualizer,
                     global_tempo,
+                    host_sample_rate,
                     follow_host_tempo,
                     metronome_enabled,
                     metronome_count_in_ticks,
@@ -7165,6 +12463,9 @@ impl Editor for SlintEditor {
                     async_executor,
                     pending_project_params,
                     animate_library,
+                    master_recorder,
+                    midi_cc,
+                    midi_clock,
                 )
             },
         );
@@ -7217,6 +12518,8 @@ struct SlintWindow {
     slint_window: std::rc::Rc<MinimalSoftwareWindow>,
     ui: Box<TLBX1UI>,
     waveform_model: std::rc::Rc<VecModel<f32>>,
+    tape_slice_markers_model: std::rc::Rc<VecModel<f32>>,
+    tape_slice_order_model: std::rc::Rc<VecModel<i32>>,
     oscilloscope_model: std::rc::Rc<VecModel<f32>>,
     spectrum_model: std::rc::Rc<VecModel<f32>>,
     vectorscope_x_model: std::rc::Rc<VecModel<f32>>,
@@ -7235,7 +12538,11 @@ struct SlintWindow {
     current_path: Arc<Mutex<PathBuf>>,
     _library_folders_model: std::rc::Rc<VecModel<SharedString>>,
     current_folder_content_model: std::rc::Rc<VecModel<BrowserEntry>>,
-    _animate_library: Arc<AnimateLibrary>,
+    animate_library: Arc<AnimateLibrary>,
+    master_recorder: Arc<MasterRecorder>,
+    midi_cc: Arc<MidiCcState>,
+    midi_mappings_model: std::rc::Rc<VecModel<MidiMappingRow>>,
+    midi_cc_revision: u32,
 }
 
 impl SlintWindow {
@@ -7248,6 +12555,7 @@ impl SlintWindow {
         master_meters: Arc<MasterMeters>,
         visualizer: Arc<VisualizerState>,
         global_tempo: Arc<AtomicU32>,
+        host_sample_rate: Arc<AtomicU32>,
         follow_host_tempo: Arc<AtomicBool>,
         metronome_enabled: Arc<AtomicBool>,
         metronome_count_in_ticks: Arc<AtomicU32>,
@@ -7256,11 +12564,16 @@ impl SlintWindow {
         async_executor: AsyncExecutor<TLBX1>,
         pending_project_params: Arc<Mutex<Option<PendingProjectParams>>>,
         animate_library: Arc<AnimateLibrary>,
+        master_recorder: Arc<MasterRecorder>,
+        midi_cc: Arc<MidiCcState>,
+        midi_clock: Arc<MidiClock>,
     ) -> Self {
         ensure_slint_platform();
         let (slint_window, ui) = create_slint_ui();
         let waveform_model =
             std::rc::Rc::new(VecModel::from(vec![0.0; WAVEFORM_SUMMARY_SIZE]));
+        let tape_slice_markers_model = std::rc::Rc::new(VecModel::from(Vec::<f32>::new()));
+        let tape_slice_order_model = std::rc::Rc::new(VecModel::from(Vec::<i32>::new()));
         let oscilloscope_model =
             std::rc::Rc::new(VecModel::from(vec![0.0; OSCILLOSCOPE_SAMPLES]));
         let spectrum_model = std::rc::Rc::new(VecModel::from(vec![0.0; SPECTRUM_BINS]));
@@ -7270,6 +12583,8 @@ impl SlintWindow {
             std::rc::Rc::new(VecModel::from(vec![0.0; VECTORSCOPE_POINTS]));
         let video_frame_cache = vec![None; NUM_TRACKS];
         ui.set_waveform(ModelRc::from(waveform_model.clone()));
+        ui.set_tape_slice_markers(ModelRc::from(tape_slice_markers_model.clone()));
+        ui.set_tape_slice_order(ModelRc::from(tape_slice_order_model.clone()));
         ui.set_oscilloscope(ModelRc::from(oscilloscope_model.clone()));
         ui.set_spectrum(ModelRc::from(spectrum_model.clone()));
         ui.set_vectorscope_x(ModelRc::from(vectorscope_x_model.clone()));
@@ -7340,6 +12655,7 @@ impl SlintWindow {
             &params,
             &tracks,
             &global_tempo,
+            &host_sample_rate,
             &follow_host_tempo,
             &metronome_enabled,
             &metronome_count_in_ticks,
@@ -7357,8 +12673,13 @@ impl SlintWindow {
             &library_folders_model,
             &current_folder_content_model,
             &animate_library,
+            &master_recorder,
+            &midi_cc,
+            &midi_clock,
         );
 
+        let midi_mappings_model = std::rc::Rc::new(VecModel::default());
+        ui.set_midi_mappings(ModelRc::from(midi_mappings_model.clone()));
         ui.set_library_folders(ModelRc::from(library_folders_model.clone()));
         ui.set_current_folder_content(ModelRc::from(current_folder_content_model.clone()));
 
@@ -7384,6 +12705,8 @@ impl SlintWindow {
             slint_window,
             ui,
             waveform_model,
+            tape_slice_markers_model,
+            tape_slice_order_model,
             oscilloscope_model,
             spectrum_model,
             vectorscope_x_model,
@@ -7402,7 +12725,50 @@ impl SlintWindow {
             current_path,
             _library_folders_model: library_folders_model,
             current_folder_content_model,
-            _animate_library: animate_library,
+            animate_library,
+            master_recorder,
+            midi_cc,
+            midi_mappings_model,
+            midi_cc_revision: u32::MAX,
+        }
+    }
+
+    /// Shows the MIDI learn state and keeps the mapping list in sync with the shared state.
+    fn update_midi_learn(&mut self) {
+        let armed = self.midi_cc.learn_armed.load(Ordering::Relaxed);
+        let target = self.midi_cc.learn_target.lock().clone();
+        let status = if !armed {
+            "MIDI learn off".to_string()
+        } else if let Some((track, param)) = target {
+            format!("Move a hardware control to bind Track {} {}", track + 1, param)
+        } else {
+            "Touch a control to learn".to_string()
+        };
+        self.ui.set_midi_learn_armed(armed);
+        self.ui.set_midi_learn_status(SharedString::from(status));
+
+        let revision = self.midi_cc.revision.load(Ordering::Relaxed);
+        if revision != self.midi_cc_revision {
+            self.midi_cc_revision = revision;
+            let rows: Vec<MidiMappingRow> = self
+                .midi_cc
+                .mappings
+                .lock()
+                .iter()
+                .map(|m| MidiMappingRow {
+                    label: SharedString::from(format!(
+                        "Ch {} CC {} -> Track {} {}",
+                        m.channel as u32 + 1,
+                        m.cc,
+                        m.track + 1,
+                        m.param
+                    )),
+                    min: SharedString::from(format!("{}", m.min)),
+                    max: SharedString::from(format!("{}", m.max)),
+                    curve: m.curve as i32,
+                })
+                .collect();
+            self.midi_mappings_model.set_vec(rows);
         }
     }
 
@@ -7435,6 +12801,7 @@ impl SlintWindow {
         let gain = self.params.gain.unmodulated_normalized_value();
         let master_filter = self.params.master_filter.unmodulated_normalized_value();
         let master_comp = self.params.master_comp.unmodulated_normalized_value();
+        let swing = self.params.swing.unmodulated_normalized_value();
         let track_level =
             f32::from_bits(self.tracks[track_idx].level.load(Ordering::Relaxed));
         let meter_left =
@@ -7466,6 +12833,10 @@ impl SlintWindow {
             f32::from_bits(self.tracks[track_idx].tape_rotate.load(Ordering::Relaxed));
         let tape_glide =
             f32::from_bits(self.tracks[track_idx].tape_glide.load(Ordering::Relaxed));
+        let tape_pitch =
+            f32::from_bits(self.tracks[track_idx].tape_pitch.load(Ordering::Relaxed));
+        let tape_fine =
+            f32::from_bits(self.tracks[track_idx].tape_fine.load(Ordering::Relaxed));
         let tape_sos =
             f32::from_bits(self.tracks[track_idx].tape_sos.load(Ordering::Relaxed));
         let tape_reverse =
@@ -7474,6 +12845,12 @@ impl SlintWindow {
             self.tracks[track_idx].tape_freeze.load(Ordering::Relaxed);
         let tape_keylock =
             self.tracks[track_idx].tape_keylock.load(Ordering::Relaxed);
+        let tape_keylock_mode =
+            self.tracks[track_idx].tape_keylock_mode.load(Ordering::Relaxed);
+        let tape_slice_mode =
+            self.tracks[track_idx].tape_slice_mode.load(Ordering::Relaxed);
+        let tape_detected_bpm =
+            f32::from_bits(self.tracks[track_idx].tape_detected_bpm.load(Ordering::Relaxed));
         let tape_monitor =
             self.tracks[track_idx].tape_monitor.load(Ordering::Relaxed);
         let tape_overdub =
@@ -7600,6 +12977,13 @@ impl SlintWindow {
             f32::from_bits(self.tracks[track_idx].animate_slot_pan[2].load(Ordering::Relaxed)),
             f32::from_bits(self.tracks[track_idx].animate_slot_pan[3].load(Ordering::Relaxed)),
         ];
+        let animate_slot_wt_position: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(
+                self.tracks[track_idx]
+                    .animate_slot_wt_position[slot]
+                    .load(Ordering::Relaxed),
+            )
+        });
         let animate_slot_wt_lfo_amount = [
             f32::from_bits(
                 self.tracks[track_idx]
@@ -7752,18 +13136,30 @@ impl SlintWindow {
                     .load(Ordering::Relaxed),
             ),
         ];
-        let animate_slot_a_filter_type =
-            self.tracks[track_idx].animate_slot_filter_type[0].load(Ordering::Relaxed);
-        let animate_slot_a_filter_cutoff = f32::from_bits(
-            self.tracks[track_idx]
-                .animate_slot_filter_cutoff[0]
-                .load(Ordering::Relaxed),
-        );
-        let animate_slot_a_filter_resonance = f32::from_bits(
-            self.tracks[track_idx]
-                .animate_slot_filter_resonance[0]
-                .load(Ordering::Relaxed),
-        );
+        let animate_slot_filter_type: [u32; 4] = std::array::from_fn(|slot| {
+            self.tracks[track_idx].animate_slot_filter_type[slot].load(Ordering::Relaxed)
+        });
+        let animate_slot_filter_cutoff: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(
+                self.tracks[track_idx]
+                    .animate_slot_filter_cutoff[slot]
+                    .load(Ordering::Relaxed),
+            )
+        });
+        let animate_slot_filter_resonance: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(
+                self.tracks[track_idx]
+                    .animate_slot_filter_resonance[slot]
+                    .load(Ordering::Relaxed),
+            )
+        });
+        let animate_slot_filter_drive: [f32; 4] = std::array::from_fn(|slot| {
+            f32::from_bits(
+                self.tracks[track_idx]
+                    .animate_slot_filter_drive[slot]
+                    .load(Ordering::Relaxed),
+            )
+        });
         let animate_vector_x =
             f32::from_bits(self.tracks[track_idx].animate_vector_x.load(Ordering::Relaxed));
         let animate_vector_y =
@@ -7790,12 +13186,75 @@ impl SlintWindow {
             f32::from_bits(self.tracks[track_idx].animate_lfo_y_amount.load(Ordering::Relaxed));
         let animate_sequencer_current_step =
             self.tracks[track_idx].animate_sequencer_step.load(Ordering::Relaxed);
+        let track_midi_channel = self.tracks[track_idx].midi_channel.load(Ordering::Relaxed);
 
-        let mut animate_sequencer_grid = Vec::with_capacity(160);
-        for i in 0..160 {
-            animate_sequencer_grid
-                .push(self.tracks[track_idx].animate_sequencer_grid[i].load(Ordering::Relaxed));
+        let mut animate_sequencer_grid =
+            Vec::with_capacity(ANIMATE_SEQ_VOICES * ANIMATE_SEQ_STEPS);
+        for lane_grid in self.tracks[track_idx].animate_sequencer_grid.iter() {
+            for i in 0..ANIMATE_SEQ_STEPS {
+                animate_sequencer_grid.push(lane_grid[i].load(Ordering::Relaxed));
+            }
         }
+        let animate_page = self.tracks[track_idx].animate_page.load(Ordering::Relaxed) as i32;
+        let animate_edit_lane =
+            (self.tracks[track_idx].animate_edit_lane.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_VOICES - 1);
+        let animate_edit_step =
+            (self.tracks[track_idx].animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+        let animate_scale = self.tracks[track_idx].animate_scale.load(Ordering::Relaxed);
+        let animate_root_note = self.tracks[track_idx].animate_root_note.load(Ordering::Relaxed);
+        let animate_voice_mode = self.tracks[track_idx].animate_voice_mode.load(Ordering::Relaxed);
+        let animate_polyphony = self.tracks[track_idx].animate_polyphony.load(Ordering::Relaxed);
+        let animate_steal_mode = self.tracks[track_idx].animate_steal_mode.load(Ordering::Relaxed);
+        let animate_unison_voices =
+            self.tracks[track_idx].animate_unison_voices.load(Ordering::Relaxed);
+        let animate_unison_detune = f32::from_bits(
+            self.tracks[track_idx]
+                .animate_unison_detune
+                .load(Ordering::Relaxed),
+        );
+        let animate_glide =
+            f32::from_bits(self.tracks[track_idx].animate_glide.load(Ordering::Relaxed));
+        let animate_step_note_override = self.tracks[track_idx].animate_step_note_override
+            [animate_edit_lane][animate_edit_step]
+            .load(Ordering::Relaxed);
+        let animate_step_note = self.tracks[track_idx].animate_step_note[animate_edit_lane]
+            [animate_edit_step]
+            .load(Ordering::Relaxed);
+        let animate_lock_param = (self.tracks[track_idx].animate_lock_param.load(Ordering::Relaxed)
+            as usize)
+            .min(ANIMATE_LOCK_PARAMS - 1);
+        let animate_lock_base = animate_lock_base(&self.tracks[track_idx]);
+        let animate_step_lock_enabled = self.tracks[track_idx].animate_step_lock_enabled
+            [animate_edit_step][animate_lock_param]
+            .load(Ordering::Relaxed);
+        let animate_step_lock_value = if animate_step_lock_enabled {
+            f32::from_bits(
+                self.tracks[track_idx].animate_step_lock_values[animate_edit_step]
+                    [animate_lock_param]
+                    .load(Ordering::Relaxed),
+            )
+        } else {
+            animate_lock_base[animate_lock_param]
+        };
+        let (animate_lock_min, animate_lock_max) = animate_lock_range(animate_lock_param);
+        let animate_step_locked: Vec<bool> = (0..ANIMATE_SEQ_STEPS)
+            .map(|step| {
+                self.tracks[track_idx].animate_step_lock_enabled[step]
+                    .iter()
+                    .any(|lock| lock.load(Ordering::Relaxed))
+            })
+            .collect();
+        let animate_lane_notes: Vec<SharedString> = (0..ANIMATE_SEQ_VOICES)
+            .map(|lane| {
+                SharedString::from(midi_note_name(animate_lane_note(
+                    animate_scale,
+                    animate_root_note,
+                    lane,
+                )))
+            })
+            .collect();
 
         let kick_pitch =
             f32::from_bits(self.tracks[track_idx].kick_pitch.load(Ordering::Relaxed));
@@ -7856,11 +13315,50 @@ impl SlintWindow {
         let syndrm_edit_step = self.tracks[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as i32;
         let syndrm_step_hold = self.tracks[track_idx].syndrm_step_hold.load(Ordering::Relaxed);
         let edit_step = syndrm_edit_step.clamp(0, (SYNDRM_STEPS - 1) as i32) as usize;
+        let edit_voice = (syndrm_edit_lane.max(2) as usize - 2).min(SYNDRM_VOICES - 1);
         let syndrm_step_override = if syndrm_edit_lane == 0 {
             self.tracks[track_idx].kick_step_override_enabled[edit_step].load(Ordering::Relaxed)
-        } else {
+        } else if syndrm_edit_lane == 1 {
             self.tracks[track_idx].snare_step_override_enabled[edit_step].load(Ordering::Relaxed)
+        } else {
+            self.tracks[track_idx].syndrm_voice_step_override_enabled[edit_voice][edit_step]
+                .load(Ordering::Relaxed)
         };
+        let mut syndrm_voice_grid = Vec::with_capacity(SYNDRM_VOICES * SYNDRM_STEPS);
+        let mut syndrm_voice_params = Vec::with_capacity(SYNDRM_VOICES * SYNDRM_VOICE_PARAMS);
+        for voice in 0..SYNDRM_VOICES {
+            for i in 0..SYNDRM_STEPS {
+                syndrm_voice_grid.push(
+                    self.tracks[track_idx].syndrm_voice_grid[voice][i].load(Ordering::Relaxed),
+                );
+            }
+            for p in 0..SYNDRM_VOICE_PARAMS {
+                syndrm_voice_params.push(f32::from_bits(
+                    self.tracks[track_idx].syndrm_voice_params[voice][p].load(Ordering::Relaxed),
+                ));
+            }
+        }
+        let edit_lane = (syndrm_edit_lane.max(0) as usize).min(SYNDRM_LANES - 1);
+        let syndrm_step_velocity = f32::from_bits(
+            self.tracks[track_idx].syndrm_step_velocity[edit_lane][edit_step].load(Ordering::Relaxed),
+        );
+        let syndrm_step_probability = f32::from_bits(
+            self.tracks[track_idx].syndrm_step_probability[edit_lane][edit_step]
+                .load(Ordering::Relaxed),
+        );
+        let syndrm_step_micro = f32::from_bits(
+            self.tracks[track_idx].syndrm_step_micro[edit_lane][edit_step].load(Ordering::Relaxed),
+        );
+        let syndrm_step_ratchet =
+            self.tracks[track_idx].syndrm_step_ratchet[edit_lane][edit_step].load(Ordering::Relaxed);
+        let syndrm_step_voice_params: Vec<f32> = (0..SYNDRM_VOICE_PARAMS)
+            .map(|p| {
+                f32::from_bits(
+                    self.tracks[track_idx].syndrm_voice_step_params[edit_voice][edit_step][p]
+                        .load(Ordering::Relaxed),
+                )
+            })
+            .collect();
         let syndrm_step_kick_pitch =
             f32::from_bits(self.tracks[track_idx].kick_step_pitch[edit_step].load(Ordering::Relaxed));
         let syndrm_step_kick_decay =
@@ -7919,6 +13417,36 @@ impl SlintWindow {
             0
         };
 
+        let tape_slice_count = (self.tracks[track_idx].tape_slice_count.load(Ordering::Relaxed)
+            as usize)
+            .min(TAPE_SLICE_MAX);
+        let tape_slice_markers: Vec<f32> = if total_samples > 0 {
+            self.tracks[track_idx].tape_slice_starts[..tape_slice_count]
+                .iter()
+                .map(|start| start.load(Ordering::Relaxed) as f32 / total_samples as f32)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // The row shows rests as -1 and holds as -2.
+        let tape_slice_order: Vec<i32> = self.tracks[track_idx]
+            .tape_slice_order
+            .iter()
+            .map(|slot| match slot.load(Ordering::Relaxed) {
+                TAPE_SLICE_REST => -1,
+                TAPE_SLICE_HOLD => -2,
+                slice => (slice as usize % tape_slice_count.max(1)) as i32,
+            })
+            .collect();
+        let tape_slice_step = self.tracks[track_idx].tape_slice_step.load(Ordering::Relaxed);
+        let tape_slice_active_step = if tape_slice_step >= 0
+            && self.tracks[track_idx].tape_slice_mode.load(Ordering::Relaxed) == 1
+        {
+            tape_slice_step.rem_euclid(TAPE_SLICE_STEPS as i64) as i32
+        } else {
+            -1
+        };
+
         let waveform = if let Some(summary) = self.tracks[track_idx].waveform_summary.try_lock() {
             summary.clone()
         } else {
@@ -8006,6 +13534,7 @@ impl SlintWindow {
         self.ui.set_gain(gain);
         self.ui.set_master_filter(master_filter);
         self.ui.set_master_comp(master_comp);
+        self.ui.set_swing(swing);
         self.ui.set_track_level(track_level);
         self.ui.set_track_muted(track_muted);
         self.ui.set_meter_left(meter_left);
@@ -8021,10 +13550,24 @@ impl SlintWindow {
         self.ui.set_tape_rate_mode(tape_rate_mode as i32);
         self.ui.set_tape_rotate(tape_rotate);
         self.ui.set_tape_glide(tape_glide);
+        self.ui.set_tape_pitch(tape_pitch);
+        self.ui.set_tape_fine(tape_fine);
         self.ui.set_tape_sos(tape_sos);
         self.ui.set_tape_reverse(tape_reverse);
         self.ui.set_tape_freeze(tape_freeze);
         self.ui.set_tape_keylock(tape_keylock);
+        self.ui.set_tape_keylock_mode(tape_keylock_mode as i32);
+        self.ui.set_tape_slice_mode(tape_slice_mode as i32);
+        self.ui.set_tape_tempo_info(SharedString::from(if tape_detected_bpm > 0.0 {
+            format!("{tape_detected_bpm:.1} BPM")
+        } else {
+            "No tempo".to_string()
+        }));
+        self.ui.set_tape_slice_info(SharedString::from(match tape_slice_count {
+            0 => "No slices".to_string(),
+            1 => "1 slice".to_string(),
+            count => format!("{count} slices"),
+        }));
         self.ui.set_tape_monitor(tape_monitor);
         self.ui.set_tape_overdub(tape_overdub);
         self.ui.set_mosaic_pitch(mosaic_pitch);
@@ -8077,6 +13620,9 @@ impl SlintWindow {
         self.ui.set_animate_slot_c_type(animate_slot_types[2] as i32);
         self.ui.set_animate_slot_d_type(animate_slot_types[3] as i32);
 
+        self.ui.set_animate_loading(
+            self.animate_library.pending_loads.load(Ordering::Acquire) > 0,
+        );
         self.ui.set_animate_slot_a_wavetable(animate_slot_wavetables[0] as i32);
         self.ui.set_animate_slot_b_wavetable(animate_slot_wavetables[1] as i32);
         self.ui.set_animate_slot_c_wavetable(animate_slot_wavetables[2] as i32);
@@ -8087,6 +13633,32 @@ impl SlintWindow {
         self.ui.set_animate_slot_c_sample(animate_slot_samples[2] as i32);
         self.ui.set_animate_slot_d_sample(animate_slot_samples[3] as i32);
 
+        let animate_slot_multisample: [bool; 4] = std::array::from_fn(|slot| {
+            self.tracks[track_idx].animate_slot_multisample[slot].load(Ordering::Relaxed)
+        });
+        let sample_default_root = f32::from_bits(
+            self.tracks[track_idx]
+                .animate_sample_default_root
+                .load(Ordering::Relaxed),
+        );
+        let animate_slot_roots: [SharedString; 4] = std::array::from_fn(|slot| {
+            self.animate_library
+                .get_sample_cached(animate_slot_samples[slot] as usize)
+                .map_or(SharedString::from("-"), |smp| {
+                    let root = smp.root.unwrap_or(sample_default_root);
+                    SharedString::from(midi_note_name(root.round().clamp(0.0, 127.0) as u32))
+                })
+        });
+        self.ui.set_animate_slot_a_multisample(animate_slot_multisample[0]);
+        self.ui.set_animate_slot_b_multisample(animate_slot_multisample[1]);
+        self.ui.set_animate_slot_c_multisample(animate_slot_multisample[2]);
+        self.ui.set_animate_slot_d_multisample(animate_slot_multisample[3]);
+        let [root_a, root_b, root_c, root_d] = animate_slot_roots;
+        self.ui.set_animate_slot_a_root(root_a);
+        self.ui.set_animate_slot_b_root(root_b);
+        self.ui.set_animate_slot_c_root(root_c);
+        self.ui.set_animate_slot_d_root(root_d);
+
         self.ui.set_animate_slot_a_coarse(animate_slot_coarse[0]);
         self.ui.set_animate_slot_a_fine(animate_slot_fine[0]);
         self.ui.set_animate_slot_a_level(animate_slot_level[0]);
@@ -8106,6 +13678,8 @@ impl SlintWindow {
         self.ui.set_animate_slot_d_fine(animate_slot_fine[3]);
         self.ui.set_animate_slot_d_level(animate_slot_level[3]);
         self.ui.set_animate_slot_d_pan(animate_slot_pan[3]);
+        self.ui
+            .set_animate_slot_a_wt_position(animate_slot_wt_position[0]);
         self.ui
             .set_animate_slot_a_wt_lfo_amount(animate_slot_wt_lfo_amount[0]);
         self.ui
@@ -8116,6 +13690,8 @@ impl SlintWindow {
             .set_animate_slot_a_wt_lfo_sync(animate_slot_wt_lfo_sync[0]);
         self.ui
             .set_animate_slot_a_wt_lfo_division(animate_slot_wt_lfo_division[0] as i32);
+        self.ui
+            .set_animate_slot_b_wt_position(animate_slot_wt_position[1]);
         self.ui
             .set_animate_slot_b_wt_lfo_amount(animate_slot_wt_lfo_amount[1]);
         self.ui
@@ -8126,6 +13702,8 @@ impl SlintWindow {
             .set_animate_slot_b_wt_lfo_sync(animate_slot_wt_lfo_sync[1]);
         self.ui
             .set_animate_slot_b_wt_lfo_division(animate_slot_wt_lfo_division[1] as i32);
+        self.ui
+            .set_animate_slot_c_wt_position(animate_slot_wt_position[2]);
         self.ui
             .set_animate_slot_c_wt_lfo_amount(animate_slot_wt_lfo_amount[2]);
         self.ui
@@ -8136,6 +13714,8 @@ impl SlintWindow {
             .set_animate_slot_c_wt_lfo_sync(animate_slot_wt_lfo_sync[2]);
         self.ui
             .set_animate_slot_c_wt_lfo_division(animate_slot_wt_lfo_division[2] as i32);
+        self.ui
+            .set_animate_slot_d_wt_position(animate_slot_wt_position[3]);
         self.ui
             .set_animate_slot_d_wt_lfo_amount(animate_slot_wt_lfo_amount[3]);
         self.ui
@@ -8171,14 +13751,90 @@ impl SlintWindow {
         self.ui
             .set_animate_slot_d_loop_end(animate_slot_loop_end[3]);
         self.ui
-            .set_animate_slot_a_filter_type(animate_slot_a_filter_type as i32);
+            .set_animate_slot_a_filter_type(animate_slot_filter_type[0] as i32);
+        self.ui
+            .set_animate_slot_a_filter_cutoff(animate_slot_filter_cutoff[0]);
+        self.ui
+            .set_animate_slot_a_filter_resonance(animate_slot_filter_resonance[0]);
+        self.ui
+            .set_animate_slot_a_filter_drive(animate_slot_filter_drive[0]);
+        self.ui
+            .set_animate_slot_b_filter_type(animate_slot_filter_type[1] as i32);
+        self.ui
+            .set_animate_slot_b_filter_cutoff(animate_slot_filter_cutoff[1]);
+        self.ui
+            .set_animate_slot_b_filter_resonance(animate_slot_filter_resonance[1]);
+        self.ui
+            .set_animate_slot_b_filter_drive(animate_slot_filter_drive[1]);
+        self.ui
+            .set_animate_slot_c_filter_type(animate_slot_filter_type[2] as i32);
+        self.ui
+            .set_animate_slot_c_filter_cutoff(animate_slot_filter_cutoff[2]);
         self.ui
-            .set_animate_slot_a_filter_cutoff(animate_slot_a_filter_cutoff);
+            .set_animate_slot_c_filter_resonance(animate_slot_filter_resonance[2]);
         self.ui
-            .set_animate_slot_a_filter_resonance(animate_slot_a_filter_resonance);
+            .set_animate_slot_c_filter_drive(animate_slot_filter_drive[2]);
+        self.ui
+            .set_animate_slot_d_filter_type(animate_slot_filter_type[3] as i32);
+        self.ui
+            .set_animate_slot_d_filter_cutoff(animate_slot_filter_cutoff[3]);
+        self.ui
+            .set_animate_slot_d_filter_resonance(animate_slot_filter_resonance[3]);
+        self.ui
+            .set_animate_slot_d_filter_drive(animate_slot_filter_drive[3]);
+        let mut animate_slot_env_params = Vec::with_capacity(4 * ANIMATE_SLOT_ENV_PARAMS);
+        for slot in 0..4 {
+            for param in 0..ANIMATE_SLOT_ENV_PARAMS {
+                animate_slot_env_params.push(f32::from_bits(
+                    animate_slot_env_param(&self.tracks[track_idx], param)[slot]
+                        .load(Ordering::Relaxed),
+                ));
+            }
+        }
+        self.ui.set_animate_slot_env_params(ModelRc::from(std::rc::Rc::new(VecModel::from(
+            animate_slot_env_params,
+        ))));
 
         self.ui.set_animate_vector_x(animate_vector_x);
         self.ui.set_animate_vector_y(animate_vector_y);
+        let vector_path = VectorPath::load(&self.tracks[track_idx]);
+        self.ui.set_animate_vector_path_enabled(
+            self.tracks[track_idx]
+                .animate_vector_path_enabled
+                .load(Ordering::Relaxed),
+        );
+        self.ui.set_animate_vector_path_recording(
+            self.tracks[track_idx]
+                .animate_vector_path_recording
+                .load(Ordering::Relaxed),
+        );
+        self.ui.set_animate_vector_path_sync(
+            self.tracks[track_idx]
+                .animate_vector_path_sync
+                .load(Ordering::Relaxed),
+        );
+        self.ui.set_animate_vector_path_loop(
+            self.tracks[track_idx]
+                .animate_vector_path_loop
+                .load(Ordering::Relaxed),
+        );
+        self.ui.set_animate_vector_path_loop_start(
+            self.tracks[track_idx]
+                .animate_vector_path_loop_start
+                .load(Ordering::Relaxed) as i32,
+        );
+        self.ui.set_animate_vector_path_loop_end(
+            self.tracks[track_idx]
+                .animate_vector_path_loop_end
+                .load(Ordering::Relaxed) as i32,
+        );
+        self.ui.set_animate_vector_path_points(vector_path.len as i32);
+        self.ui.set_animate_vector_path_xs(ModelRc::from(std::rc::Rc::new(VecModel::from(
+            vector_path.x[..vector_path.len].to_vec(),
+        ))));
+        self.ui.set_animate_vector_path_ys(ModelRc::from(std::rc::Rc::new(VecModel::from(
+            vector_path.y[..vector_path.len].to_vec(),
+        ))));
         self.ui.set_animate_lfo_x_waveform(animate_lfo_x_waveform as i32);
         self.ui.set_animate_lfo_x_sync(animate_lfo_x_sync);
         self.ui
@@ -8195,10 +13851,41 @@ impl SlintWindow {
 
         self.ui
             .set_animate_sequencer_current_step(animate_sequencer_current_step);
+        self.ui.set_track_midi_channel(track_midi_channel as i32);
         self.ui
             .set_animate_sequencer_grid(ModelRc::from(std::rc::Rc::new(VecModel::from(
                 animate_sequencer_grid,
             ))));
+        self.ui.set_animate_page(animate_page);
+        self.ui.set_animate_edit_lane(animate_edit_lane as i32);
+        self.ui.set_animate_edit_step(animate_edit_step as i32);
+        self.ui.set_animate_scale(animate_scale as i32);
+        self.ui.set_animate_root_note(animate_root_note as i32);
+        self.ui
+            .set_animate_root_note_name(SharedString::from(midi_note_name(animate_root_note)));
+        self.ui.set_animate_voice_mode(animate_voice_mode as i32);
+        self.ui.set_animate_polyphony(animate_polyphony as i32);
+        self.ui.set_animate_steal_mode(animate_steal_mode as i32);
+        self.ui.set_animate_unison_voices(animate_unison_voices as i32);
+        self.ui.set_animate_unison_detune(animate_unison_detune);
+        self.ui.set_animate_glide(animate_glide);
+        self.ui.set_animate_step_note_override(animate_step_note_override);
+        self.ui.set_animate_step_note(animate_step_note as i32);
+        self.ui
+            .set_animate_step_note_name(SharedString::from(midi_note_name(animate_step_note)));
+        self.ui
+            .set_animate_lane_notes(ModelRc::from(std::rc::Rc::new(VecModel::from(
+                animate_lane_notes,
+            ))));
+        self.ui.set_animate_lock_param(animate_lock_param as i32);
+        self.ui.set_animate_step_lock_enabled(animate_step_lock_enabled);
+        self.ui.set_animate_step_lock_value(animate_step_lock_value);
+        self.ui.set_animate_step_lock_min(animate_lock_min);
+        self.ui.set_animate_step_lock_max(animate_lock_max);
+        self.ui
+            .set_animate_step_locked(ModelRc::from(std::rc::Rc::new(VecModel::from(
+                animate_step_locked,
+            ))));
 
         self.ui.set_kick_pitch(kick_pitch);
         self.ui.set_kick_decay(kick_decay);
@@ -8259,6 +13946,22 @@ impl SlintWindow {
             .set_syndrm_step_snare_filter_cutoff(syndrm_step_snare_filter_cutoff);
         self.ui
             .set_syndrm_step_snare_filter_resonance(syndrm_step_snare_filter_resonance);
+        self.ui.set_syndrm_step_velocity(syndrm_step_velocity);
+        self.ui.set_syndrm_step_probability(syndrm_step_probability);
+        self.ui.set_syndrm_step_micro(syndrm_step_micro);
+        self.ui.set_syndrm_step_ratchet(syndrm_step_ratchet as i32);
+        self.ui
+            .set_syndrm_voice_grid(ModelRc::from(std::rc::Rc::new(VecModel::from(
+                syndrm_voice_grid,
+            ))));
+        self.ui
+            .set_syndrm_voice_params(ModelRc::from(std::rc::Rc::new(VecModel::from(
+                syndrm_voice_params,
+            ))));
+        self.ui
+            .set_syndrm_step_voice_params(ModelRc::from(std::rc::Rc::new(VecModel::from(
+                syndrm_step_voice_params,
+            ))));
 
         self.ui.set_void_base_freq(void_base_freq);
         self.ui.set_void_chaos_depth(void_chaos_depth);
@@ -8270,6 +13973,22 @@ impl SlintWindow {
         self.ui.set_void_enabled(void_enabled);
 
         self.ui.set_metronome_enabled(metronome_enabled);
+        self.ui.set_follow_host_transport(
+            self.params.follow_host_transport.load(Ordering::Relaxed),
+        );
+        let track_swing_override =
+            self.tracks[track_idx].swing_override.load(Ordering::Relaxed);
+        let track_swing = f32::from_bits(self.tracks[track_idx].swing.load(Ordering::Relaxed))
+            .clamp(SWING_MIN, SWING_MAX);
+        self.ui.set_track_swing_override(track_swing_override);
+        self.ui.set_track_swing(track_swing);
+        self.ui.set_track_swing_label(SharedString::from(if track_swing_override {
+            format!("Track swing: {track_swing:.0}%")
+        } else {
+            format!("Track swing: global {:.0}%", self.params.swing.value())
+        }));
+        self.ui
+            .set_master_recording(self.master_recorder.armed.load(Ordering::Relaxed));
         self.ui
             .set_metronome_count_in(metronome_count_in_ticks as f32);
         self.ui
@@ -8280,9 +13999,20 @@ impl SlintWindow {
             .set_metronome_count_playback(metronome_count_in_playback);
         self.ui
             .set_metronome_count_record(metronome_count_in_record);
+        let record_buffer_seconds = self.params.record_buffer_seconds.load(Ordering::Relaxed);
+        self.ui
+            .set_record_buffer_seconds(record_buffer_seconds as f32);
+        self.ui
+            .set_record_buffer_label(SharedString::from(format!(
+                "Record buffer: {record_buffer_seconds} s"
+            )));
 
         self.ui.set_playhead_index(playhead_index);
         self.waveform_model.set_vec(waveform);
+        self.tape_slice_markers_model.set_vec(tape_slice_markers);
+        self.tape_slice_order_model.set_vec(tape_slice_order);
+        self.ui.set_tape_slice_count(tape_slice_count as i32);
+        self.ui.set_tape_slice_active_step(tape_slice_active_step);
         self.oscilloscope_model.set_vec(oscilloscope);
         self.spectrum_model.set_vec(spectrum);
         self.vectorscope_x_model.set_vec(vectorscope_x);
@@ -8356,6 +14086,12 @@ impl BaseWindowHandler for SlintWindow {
 
             self.dispatch_slint_event(WindowEvent::WindowActiveChanged(true));
         }
+        {
+            let setter = ParamSetter::new(self.gui_context.as_ref());
+            for (track, track_params) in self.tracks.iter().zip(self.params.tracks.iter()) {
+                track_params.publish_track_changes(track, &setter);
+            }
+        }
         if let Some(pending) = self.pending_project_params.lock().take() {
             let setter = ParamSetter::new(self.gui_context.as_ref());
             setter.begin_set_parameter(&self.params.gain);
@@ -8369,6 +14105,10 @@ impl BaseWindowHandler for SlintWindow {
             setter.begin_set_parameter(&self.params.master_comp);
             setter.set_parameter(&self.params.master_comp, pending.master_comp);
             setter.end_set_parameter(&self.params.master_comp);
+
+            setter.begin_set_parameter(&self.params.swing);
+            setter.set_parameter(&self.params.swing, pending.swing);
+            setter.end_set_parameter(&self.params.swing);
         }
         while let Ok(action) = self.sample_dialog_rx.try_recv() {
             match action {
@@ -8429,9 +14169,14 @@ impl BaseWindowHandler for SlintWindow {
                             description,
                         });
                 }
+                ProjectDialogAction::Render { path, options } => {
+                    self.async_executor
+                        .execute_background(TLBX1Task::RenderAudio { path, options });
+                }
             }
         }
         platform::update_timers_and_animations();
+        self.update_midi_learn();
         self.update_ui_state();
         self.slint_window.request_redraw();
         self.render();
@@ -8588,6 +14333,85 @@ impl BaseWindowHandler for SlintWindow {
     }
 }
 
+/// Runs beside a tape recording: allocates the longer buffers the audio thread asks
+/// for, frees the ones it retires, and finalizes the take once recording stops.
+fn spawn_record_helper(
+    tracks: &Arc<[Track; NUM_TRACKS]>,
+    track_idx: usize,
+    sample_rate: u32,
+    channels: usize,
+) {
+    let tracks = Arc::clone(tracks);
+    let generation = tracks[track_idx].record_generation.load(Ordering::Acquire);
+    std::thread::spawn(move || {
+        let track = &tracks[track_idx];
+        let current = || track.record_generation.load(Ordering::Acquire) == generation;
+        while current()
+            && (track.is_recording.load(Ordering::Relaxed)
+                || track.pending_record.load(Ordering::Relaxed))
+        {
+            let grow_len = track.record_grow_len.swap(0, Ordering::Relaxed);
+            let spare_len = track
+                .record_spare
+                .lock()
+                .first()
+                .map_or(0, |channel| channel.len());
+            if grow_len > spare_len {
+                let grown = vec![vec![0.0; grow_len]; channels];
+                let mut spare = track.record_spare.lock();
+                if spare.first().map_or(0, |channel| channel.len()) < grow_len {
+                    track.record_migrated.store(0, Ordering::Relaxed);
+                    let retired = std::mem::replace(&mut *spare, grown);
+                    drop(spare);
+                    drop(retired);
+                }
+            }
+            std::thread::sleep(std::time::Duration::from_millis(RECORD_GROW_POLL_MS));
+        }
+        if !current() {
+            return;
+        }
+        *track.record_spare.lock() = Vec::new();
+        let dropped = track.record_dropped.swap(0, Ordering::Relaxed);
+        if dropped > 0 {
+            nih_log!(
+                "Recording on track {} dropped {} samples while its buffer grew",
+                track_idx + 1,
+                dropped
+            );
+        }
+
+        let fresh_take = track.record_appending.swap(false, Ordering::Relaxed);
+        let take = {
+            let mut samples = track.samples.lock();
+            if fresh_take {
+                // A fresh take keeps exactly what was recorded, at the rate it was recorded.
+                let len = track.record_pos.load(Ordering::Relaxed);
+                for channel in samples.iter_mut() {
+                    channel.truncate(len);
+                    channel.shrink_to_fit();
+                }
+                track.sample_rate.store(sample_rate, Ordering::Relaxed);
+            }
+            let mut summary = track.waveform_summary.lock();
+            match samples.first() {
+                Some(channel) => calculate_waveform_summary(channel, &mut summary),
+                None => summary.fill(0.0),
+            }
+            // Analyse a copy so the audio thread can keep playing the tape meanwhile.
+            samples.clone()
+        };
+        let sample_rate = track.sample_rate.load(Ordering::Relaxed);
+        let flux = tape_onset_flux(&take);
+        let slices = detect_tape_slices(&take, &flux, sample_rate);
+        let tempo = detect_tape_tempo(&take, &flux, sample_rate);
+        if current() {
+            store_tape_slices(track, &slices, fresh_take);
+            store_tape_tempo(track, tempo);
+        }
+    });
+}
+
 fn spawn_with_stack<F>(f: F)
 where
     F: FnOnce() + Send + 'static,
@@ -8603,6 +14427,7 @@ fn initialize_ui(
     params: &Arc<TLBX1Params>,
     tracks: &Arc<[Track; NUM_TRACKS]>,
     global_tempo: &Arc<AtomicU32>,
+    host_sample_rate: &Arc<AtomicU32>,
     _follow_host_tempo: &Arc<AtomicBool>,
     metronome_enabled: &Arc<AtomicBool>,
     metronome_count_in_ticks: &Arc<AtomicU32>,
@@ -8620,6 +14445,9 @@ fn initialize_ui(
     _library_folders_model: &std::rc::Rc<VecModel<SharedString>>,
     current_folder_content_model: &std::rc::Rc<VecModel<BrowserEntry>>,
     animate_library: &Arc<AnimateLibrary>,
+    master_recorder: &Arc<MasterRecorder>,
+    midi_cc: &Arc<MidiCcState>,
+    midi_clock: &Arc<MidiClock>,
 ) {
     ui.set_output_devices(ModelRc::new(VecModel::from(
         output_devices
@@ -8634,6 +14462,65 @@ fn initialize_ui(
             .collect::<Vec<_>>(),
     )));
 
+    // MIDI clock ports are opened directly, so they only make sense standalone.
+    let clock_standalone = gui_context.plugin_api() == PluginApi::Standalone;
+    if clock_standalone {
+        ui.set_midi_clock_inputs(midi_clock_port_model("Internal", &available_midi_inputs()));
+        ui.set_midi_clock_outputs(midi_clock_port_model("Off", &available_midi_outputs()));
+    } else {
+        ui.set_midi_clock_inputs(midi_clock_port_model("Internal", &[]));
+        ui.set_midi_clock_outputs(midi_clock_port_model("Off", &[]));
+    }
+
+    let ui_clock_in = ui.as_weak();
+    let midi_clock_in = Arc::clone(midi_clock);
+    let tracks_clock = Arc::clone(tracks);
+    let midi_clock_connection: std::rc::Rc<RefCell<Option<midir::MidiInputConnection<()>>>> =
+        Default::default();
+    ui.on_midi_clock_input_selected(move |index| {
+        let Some(ui) = ui_clock_in.upgrade() else { return; };
+        // Dropping the connection closes the port.
+        midi_clock_connection.borrow_mut().take();
+        midi_clock_in.follow.store(false, Ordering::Relaxed);
+        midi_clock_in.running.store(false, Ordering::Relaxed);
+        midi_clock_in.tempo.store(0, Ordering::Relaxed);
+        if index <= 0 {
+            ui.set_settings_status("MIDI clock in: internal tempo".into());
+            return;
+        }
+        match connect_midi_clock_input(index as usize - 1, &midi_clock_in, &tracks_clock) {
+            Ok(connection) => {
+                *midi_clock_connection.borrow_mut() = Some(connection);
+                midi_clock_in.follow.store(true, Ordering::Relaxed);
+                ui.set_settings_status("Following external MIDI clock".into());
+            }
+            Err(err) => {
+                ui.set_midi_clock_input_index(0);
+                ui.set_settings_status(format!("MIDI clock in failed: {}", err).into());
+            }
+        }
+    });
+
+    let ui_clock_out = ui.as_weak();
+    let midi_clock_out = Arc::clone(midi_clock);
+    let global_tempo_clock = Arc::clone(global_tempo);
+    ui.on_midi_clock_output_selected(move |index| {
+        let Some(ui) = ui_clock_out.upgrade() else { return; };
+        if index <= 0 {
+            midi_clock_out.out_generation.fetch_add(1, Ordering::AcqRel);
+            ui.set_settings_status("MIDI clock out: off".into());
+            return;
+        }
+        match start_midi_clock_output(index as usize - 1, &midi_clock_out, &global_tempo_clock) {
+            Ok(()) => ui.set_settings_status("Sending MIDI clock".into()),
+            Err(err) => {
+                midi_clock_out.out_generation.fetch_add(1, Ordering::AcqRel);
+                ui.set_midi_clock_output_index(0);
+                ui.set_settings_status(format!("MIDI clock out failed: {}", err).into());
+            }
+        }
+    });
+
     ui.set_sample_rates(ModelRc::new(VecModel::from(
         sample_rates
             .iter()
@@ -8667,6 +14554,16 @@ fn initialize_ui(
         SharedString::from("Dotted"),
         SharedString::from("Triplet"),
     ])));
+    ui.set_tape_keylock_modes(ModelRc::new(VecModel::from(vec![
+        SharedString::from("Classic"),
+        SharedString::from("Tonal"),
+        SharedString::from("Rhythmic"),
+    ])));
+    ui.set_tape_slice_modes(ModelRc::new(VecModel::from(vec![
+        SharedString::from("Off"),
+        SharedString::from("Steps"),
+        SharedString::from("MIDI"),
+    ])));
     ui.set_ring_decay_modes(ModelRc::new(VecModel::from(vec![
         SharedString::from("Sustain"),
         SharedString::from("Choke"),
@@ -8709,6 +14606,40 @@ fn initialize_ui(
         SharedString::from("Lowpass 12dB"),
         SharedString::from("Highpass"),
         SharedString::from("Bandpass"),
+        SharedString::from("Moog Ladder"),
+    ])));
+    ui.set_animate_scales(ModelRc::new(VecModel::from(
+        ANIMATE_SCALES
+            .iter()
+            .map(|(name, _)| SharedString::from(*name))
+            .collect::<Vec<_>>(),
+    )));
+    ui.set_animate_voice_modes(ModelRc::new(VecModel::from(
+        ANIMATE_VOICE_MODES
+            .iter()
+            .map(|name| SharedString::from(*name))
+            .collect::<Vec<_>>(),
+    )));
+    ui.set_animate_steal_modes(ModelRc::new(VecModel::from(
+        ANIMATE_STEAL_MODES
+            .iter()
+            .map(|name| SharedString::from(*name))
+            .collect::<Vec<_>>(),
+    )));
+    ui.set_animate_lock_params(ModelRc::new(VecModel::from(
+        ANIMATE_LOCK_NAMES
+            .iter()
+            .map(|name| SharedString::from(*name))
+            .collect::<Vec<_>>(),
+    )));
+    let mut midi_channels = vec![SharedString::from("Omni")];
+    midi_channels.extend((1..=16).map(|ch| SharedString::from(ch.to_string())));
+    ui.set_midi_channels(ModelRc::new(VecModel::from(midi_channels)));
+    ui.set_midi_cc_curves(ModelRc::new(VecModel::from(vec![
+        SharedString::from("Linear"),
+        SharedString::from("Exponential"),
+        SharedString::from("Logarithmic"),
+        SharedString::from("S-Curve"),
     ])));
     ui.set_animate_lfo_waveforms(ModelRc::new(VecModel::from(vec![
         SharedString::from("Sine"),
@@ -8728,47 +14659,19 @@ fn initialize_ui(
         SharedString::from("4"),
     ])));
 
-    // Scan for wavetables and samples
-    let mut wavetables = Vec::new();
-    if let Ok(entries) = std::fs::read_dir("src/library/factory/wavetables") {
-        for entry in entries.flatten() {
-            if entry.path().is_dir() {
-                if let Ok(subentries) = std::fs::read_dir(entry.path()) {
-                    for subentry in subentries.flatten() {
-                        if subentry.path().extension().map_or(false, |ext| ext == "wav") {
-                            let label = format!("{}/{}", 
-                                entry.file_name().to_string_lossy(),
-                                subentry.file_name().to_string_lossy());
-                            wavetables.push(SharedString::from(label));
-                        }
-                    }
-                }
-            } else if entry.path().extension().map_or(false, |ext| ext == "wav") {
-                wavetables.push(SharedString::from(entry.file_name().to_string_lossy().to_string()));
-            }
-        }
-    }
+    // Wavetable names come from the library so the list matches its indices.
+    let wavetables: Vec<SharedString> = animate_library
+        .wavetable_names
+        .iter()
+        .map(|name| SharedString::from(name.as_str()))
+        .collect();
     ui.set_animate_wavetables(ModelRc::new(VecModel::from(wavetables)));
 
-    let mut samples = Vec::new();
-    if let Ok(entries) = std::fs::read_dir("src/library/factory/samples") {
-        for entry in entries.flatten() {
-            if entry.path().is_dir() {
-                if let Ok(subentries) = std::fs::read_dir(entry.path()) {
-                    for subentry in subentries.flatten() {
-                        if subentry.path().extension().map_or(false, |ext| ext == "wav" || ext == "mp3") {
-                            let label = format!("{}/{}", 
-                                entry.file_name().to_string_lossy(),
-                                subentry.file_name().to_string_lossy());
-                            samples.push(SharedString::from(label));
-                        }
-                    }
-                }
-            } else if entry.path().extension().map_or(false, |ext| ext == "wav" || ext == "mp3") {
-                samples.push(SharedString::from(entry.file_name().to_string_lossy().to_string()));
-            }
-        }
-    }
+    let samples: Vec<SharedString> = animate_library
+        .sample_names
+        .iter()
+        .map(|name| SharedString::from(name.as_str()))
+        .collect();
     ui.set_animate_samples(ModelRc::new(VecModel::from(samples)));
 
     let output_device_index = current_arg_value("--output-device")
@@ -8899,6 +14802,39 @@ fn initialize_ui(
         });
     });
 
+    let project_dialog_tx_render = project_dialog_tx.clone();
+    ui.on_render_audio_data(move |length, length_unit, bit_depth, sample_rate, stems| {
+        let length = length.max(1);
+        let options = RenderOptions {
+            length: if length_unit == 0 {
+                RenderLength::Bars(length as u32)
+            } else {
+                RenderLength::Seconds(length as f32)
+            },
+            bit_depth: match bit_depth {
+                0 => 16,
+                1 => 24,
+                _ => 32,
+            },
+            sample_rate: match sample_rate {
+                1 => 48_000,
+                2 => 88_200,
+                3 => 96_000,
+                _ => 44_100,
+            },
+            stems,
+        };
+        let project_dialog_tx = project_dialog_tx_render.clone();
+        spawn_with_stack(move || {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("WAV Audio", &["wav"])
+                .save_file()
+            {
+                let _ = project_dialog_tx.send(ProjectDialogAction::Render { path, options });
+            }
+        });
+    });
+
     let pending_engine = Arc::new(Mutex::new(None::<PendingEngineLoad>));
 
     let gui_context_select = Arc::clone(gui_context);
@@ -8951,167 +14887,71 @@ fn initialize_ui(
             } else {
             reset_track_for_engine(&tracks_engine[track_idx], engine_type);
                 if let Some(ui) = ui_engine.upgrade() {
-                    ui.set_engine_loaded(true);
-                }
-            }
-        });
-
-    let gui_context_gain = Arc::clone(gui_context);
-    let params_gain = Arc::clone(params);
-    ui.on_gain_changed(move |value| {
-        let setter = ParamSetter::new(gui_context_gain.as_ref());
-        setter.begin_set_parameter(&params_gain.gain);
-        setter.set_parameter_normalized(&params_gain.gain, value);
-        setter.end_set_parameter(&params_gain.gain);
-    });
-
-    let gui_context_filter = Arc::clone(gui_context);
-    let params_filter = Arc::clone(params);
-    ui.on_master_filter_changed(move |value| {
-        let setter = ParamSetter::new(gui_context_filter.as_ref());
-        setter.begin_set_parameter(&params_filter.master_filter);
-        setter.set_parameter_normalized(&params_filter.master_filter, value);
-        setter.end_set_parameter(&params_filter.master_filter);
-    });
-
-    let gui_context_comp = Arc::clone(gui_context);
-    let params_comp = Arc::clone(params);
-    ui.on_master_comp_changed(move |value| {
-        let setter = ParamSetter::new(gui_context_comp.as_ref());
-        setter.begin_set_parameter(&params_comp.master_comp);
-        setter.set_parameter_normalized(&params_comp.master_comp, value);
-        setter.end_set_parameter(&params_comp.master_comp);
-    });
-
-    let tracks_play = Arc::clone(tracks);
-    let global_tempo_play = Arc::clone(global_tempo);
-    let metronome_enabled_play = Arc::clone(metronome_enabled);
-    let metronome_count_in_ticks_play = Arc::clone(metronome_count_in_ticks);
-    let metronome_count_in_playback_for_play =
-        Arc::clone(metronome_count_in_playback);
-    ui.on_toggle_play(move || {
-        let any_playing = tracks_play
-            .iter()
-            .any(|track| track.is_playing.load(Ordering::Relaxed));
-        let any_pending = tracks_play
-            .iter()
-            .any(|track| track.pending_play.load(Ordering::Relaxed));
-        if any_playing || any_pending {
-            for track in tracks_play.iter() {
-                track.is_playing.store(false, Ordering::Relaxed);
-                track.pending_play.store(false, Ordering::Relaxed);
-                track.count_in_remaining.store(0, Ordering::Relaxed);
-            }
-            return;
-        }
-
-        let tempo = f32::from_bits(global_tempo_play.load(Ordering::Relaxed)).clamp(20.0, 240.0);
-        let count_in_ticks = metronome_count_in_ticks_play.load(Ordering::Relaxed);
-        let use_count_in = metronome_enabled_play.load(Ordering::Relaxed)
-            && metronome_count_in_playback_for_play.load(Ordering::Relaxed)
-            && count_in_ticks > 0;
-        for track in tracks_play.iter() {
-            let loop_enabled = track.loop_enabled.load(Ordering::Relaxed);
-            let loop_mode = track.loop_mode.load(Ordering::Relaxed);
-            let loop_start_norm =
-                f32::from_bits(track.loop_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
-            let rotate_norm =
-                f32::from_bits(track.tape_rotate.load(Ordering::Relaxed)).clamp(0.0, 1.0);
-            let reverse_active = track.tape_reverse.load(Ordering::Relaxed) || loop_mode == 3;
-            let loop_start = if loop_enabled {
-                if let Some(samples) = track.samples.try_lock() {
-                    let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
-                    let rotate_offset = (rotate_norm * len as f32) as usize;
-                    if reverse_active {
-                        let base_end = ((1.0 - loop_start_norm) * len as f32) as usize;
-                        let loop_end = (base_end + rotate_offset).min(len);
-                        let mut loop_len =
-                            (f32::from_bits(track.loop_length.load(Ordering::Relaxed)) * len as f32)
-                                as usize;
-                        if loop_len == 0 {
-                            loop_len = loop_end.max(1);
-                        }
-                        loop_end.saturating_sub(loop_len) as f32
-                    } else {
-                        let base_start = (loop_start_norm * len as f32) as usize;
-                        ((base_start + rotate_offset) % len.max(1)) as f32
-                    }
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            };
-            let trigger_start_norm =
-                f32::from_bits(track.trigger_start.load(Ordering::Relaxed)).clamp(0.0, 0.999);
-            let trigger_start = if let Some(samples) = track.samples.try_lock() {
-                let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
-                let start_norm = if reverse_active {
-                    (1.0 - trigger_start_norm).clamp(0.0, 0.999)
-                } else {
-                    trigger_start_norm
-                };
-                (start_norm * len as f32) as f32
-            } else {
-                0.0
-            };
-            let direction = if loop_mode == 3 { -1 } else { 1 };
-            track.loop_dir.store(direction, Ordering::Relaxed);
-            if loop_mode == 4 {
-                if let Some(samples) = track.samples.try_lock() {
-                    let len = samples.get(0).map(|ch| ch.len()).unwrap_or(0);
-                    let loop_len =
-                        (f32::from_bits(track.loop_length.load(Ordering::Relaxed)) * len as f32)
-                            as usize;
-                    let loop_len = loop_len.max(1);
-                    let loop_end = (loop_start as usize + loop_len).min(len).max(1);
-                    let loop_start_usize = loop_start as usize;
-                    if loop_end > loop_start_usize {
-                        let rand_pos =
-                            loop_start_usize + fastrand::usize(..(loop_end - loop_start_usize));
-                        track.play_pos.store((rand_pos as f32).to_bits(), Ordering::Relaxed);
-                    } else {
-                        track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
-                    }
-                } else {
-                    track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
-                }
-            } else {
-                track.play_pos.store(trigger_start.to_bits(), Ordering::Relaxed);
-            }
-            track
-                .loop_start_last
-                .store(loop_start as u32, Ordering::Relaxed);
-            let mut direction = if loop_mode == 3 { -1 } else { 1 };
-            if track.tape_reverse.load(Ordering::Relaxed) {
-                direction *= -1;
-            }
-            let start_pos = f32::from_bits(track.play_pos.load(Ordering::Relaxed));
-            track
-                .keylock_phase
-                .store(0.0f32.to_bits(), Ordering::Relaxed);
-            track
-                .keylock_grain_a
-                .store(start_pos.to_bits(), Ordering::Relaxed);
-            track.keylock_grain_b.store(
-                (start_pos + direction as f32 * KEYLOCK_GRAIN_HOP as f32).to_bits(),
-                Ordering::Relaxed,
-            );
-            track.debug_logged.store(false, Ordering::Relaxed);
-            if use_count_in {
-                let sr = track.sample_rate.load(Ordering::Relaxed).max(1);
-                let count_in_samples = count_in_samples(tempo, sr, count_in_ticks);
-                track
-                    .count_in_remaining
-                    .store(count_in_samples, Ordering::Relaxed);
-                track.pending_play.store(true, Ordering::Relaxed);
-                track.is_playing.store(false, Ordering::Relaxed);
-            } else {
-                track.pending_play.store(false, Ordering::Relaxed);
-                track.count_in_remaining.store(0, Ordering::Relaxed);
-                track.is_playing.store(true, Ordering::Relaxed);
+                    ui.set_engine_loaded(true);
+                }
             }
+        });
+
+    let gui_context_gain = Arc::clone(gui_context);
+    let params_gain = Arc::clone(params);
+    ui.on_gain_changed(move |value| {
+        let setter = ParamSetter::new(gui_context_gain.as_ref());
+        setter.begin_set_parameter(&params_gain.gain);
+        setter.set_parameter_normalized(&params_gain.gain, value);
+        setter.end_set_parameter(&params_gain.gain);
+    });
+
+    let gui_context_filter = Arc::clone(gui_context);
+    let params_filter = Arc::clone(params);
+    ui.on_master_filter_changed(move |value| {
+        let setter = ParamSetter::new(gui_context_filter.as_ref());
+        setter.begin_set_parameter(&params_filter.master_filter);
+        setter.set_parameter_normalized(&params_filter.master_filter, value);
+        setter.end_set_parameter(&params_filter.master_filter);
+    });
+
+    let gui_context_comp = Arc::clone(gui_context);
+    let params_comp = Arc::clone(params);
+    ui.on_master_comp_changed(move |value| {
+        let setter = ParamSetter::new(gui_context_comp.as_ref());
+        setter.begin_set_parameter(&params_comp.master_comp);
+        setter.set_parameter_normalized(&params_comp.master_comp, value);
+        setter.end_set_parameter(&params_comp.master_comp);
+    });
+
+    let gui_context_swing = Arc::clone(gui_context);
+    let params_swing = Arc::clone(params);
+    ui.on_swing_changed(move |value| {
+        let setter = ParamSetter::new(gui_context_swing.as_ref());
+        setter.begin_set_parameter(&params_swing.swing);
+        setter.set_parameter_normalized(&params_swing.swing, value);
+        setter.end_set_parameter(&params_swing.swing);
+    });
+
+    let tracks_play = Arc::clone(tracks);
+    let global_tempo_play = Arc::clone(global_tempo);
+    let metronome_enabled_play = Arc::clone(metronome_enabled);
+    let metronome_count_in_ticks_play = Arc::clone(metronome_count_in_ticks);
+    let metronome_count_in_playback_for_play =
+        Arc::clone(metronome_count_in_playback);
+    ui.on_toggle_play(move || {
+        let any_playing = tracks_play
+            .iter()
+            .any(|track| track.is_playing.load(Ordering::Relaxed));
+        let any_pending = tracks_play
+            .iter()
+            .any(|track| track.pending_play.load(Ordering::Relaxed));
+        if any_playing || any_pending {
+            stop_transport(&tracks_play);
+            return;
         }
+
+        let tempo = f32::from_bits(global_tempo_play.load(Ordering::Relaxed)).clamp(20.0, 240.0);
+        let count_in_ticks = metronome_count_in_ticks_play.load(Ordering::Relaxed);
+        let use_count_in = metronome_enabled_play.load(Ordering::Relaxed)
+            && metronome_count_in_playback_for_play.load(Ordering::Relaxed)
+            && count_in_ticks > 0;
+        start_transport(&tracks_play, tempo, count_in_ticks, use_count_in);
     });
 
     let tracks_audition = Arc::clone(tracks);
@@ -9207,16 +15047,9 @@ fn initialize_ui(
                 direction *= -1;
             }
             let start_pos = f32::from_bits(track.play_pos.load(Ordering::Relaxed));
-            track
-                .keylock_phase
-                .store(0.0f32.to_bits(), Ordering::Relaxed);
-            track
-                .keylock_grain_a
-                .store(start_pos.to_bits(), Ordering::Relaxed);
-            track.keylock_grain_b.store(
-                (start_pos + direction as f32 * KEYLOCK_GRAIN_HOP as f32).to_bits(),
-                Ordering::Relaxed,
-            );
+            reset_tape_keylock(track, start_pos, direction);
+            track.tape_slice_playing.store(false, Ordering::Relaxed);
+            track.tape_slice_step.store(-1, Ordering::Relaxed);
 
             track.pending_play.store(false, Ordering::Relaxed);
             track.count_in_remaining.store(0, Ordering::Relaxed);
@@ -9239,6 +15072,7 @@ fn initialize_ui(
     let tracks_record = Arc::clone(tracks);
     let params_record = Arc::clone(params);
     let global_tempo_record = Arc::clone(global_tempo);
+    let host_sample_rate_record = Arc::clone(host_sample_rate);
     let metronome_enabled_record = Arc::clone(metronome_enabled);
     let metronome_count_in_ticks_record = Arc::clone(metronome_count_in_ticks);
     let metronome_count_in_record_enabled = Arc::clone(metronome_count_in_record);
@@ -9263,39 +15097,51 @@ fn initialize_ui(
             tracks_record[track_idx]
                 .count_in_remaining
                 .store(0, Ordering::Relaxed);
-            if recording {
-                if let (Some(samples), Some(mut summary)) = (
-                    tracks_record[track_idx].samples.try_lock(),
-                    tracks_record[track_idx].waveform_summary.try_lock(),
-                ) {
-                    if !samples.is_empty() {
-                        calculate_waveform_summary(&samples[0], &mut summary);
-                        tracks_record[track_idx]
-                            .sample_rate
-                            .store(RECORD_MAX_SAMPLE_RATE as u32, Ordering::Relaxed);
-                    }
-                }
-            }
+            // The recording helper thread finalizes the take once it sees the stop.
             return;
         }
 
+        let sr = host_sample_rate_record.load(Ordering::Relaxed).max(1);
         if let Some(mut samples) = tracks_record[track_idx].samples.try_lock() {
-            let overdub = tracks_record[track_idx].tape_overdub.load(Ordering::Relaxed);
+            // Overdubbing needs something to overdub; otherwise start a fresh take.
+            let overdub = tracks_record[track_idx].tape_overdub.load(Ordering::Relaxed)
+                && samples.first().is_some_and(|channel| !channel.is_empty());
             if !overdub {
-                for channel in samples.iter_mut() {
-                    channel.clear();
-                    channel.resize(RECORD_MAX_SAMPLES, 0.0);
-                }
+                let seconds = params_record
+                    .record_buffer_seconds
+                    .load(Ordering::Relaxed)
+                    .clamp(RECORD_BUFFER_MIN_SECONDS, RECORD_BUFFER_MAX_SECONDS);
+                let channels = samples.len().max(2);
+                *samples = vec![vec![0.0; seconds as usize * sr as usize]; channels];
                 *tracks_record[track_idx].sample_path.lock() = None;
                 tracks_record[track_idx]
                     .record_pos
-                    .store(0.0f32.to_bits(), Ordering::Relaxed);
+                    .store(0, Ordering::Relaxed);
             } else {
-                let play_pos = tracks_record[track_idx].play_pos.load(Ordering::Relaxed);
+                let play_pos =
+                    f32::from_bits(tracks_record[track_idx].play_pos.load(Ordering::Relaxed));
                 tracks_record[track_idx]
                     .record_pos
-                    .store(play_pos, Ordering::Relaxed);
+                    .store(play_pos.max(0.0) as usize, Ordering::Relaxed);
             }
+            let channels = samples.len();
+            drop(samples);
+            tracks_record[track_idx]
+                .record_appending
+                .store(!overdub, Ordering::Relaxed);
+            tracks_record[track_idx]
+                .record_grow_len
+                .store(0, Ordering::Relaxed);
+            tracks_record[track_idx]
+                .record_migrated
+                .store(0, Ordering::Relaxed);
+            tracks_record[track_idx]
+                .record_dropped
+                .store(0, Ordering::Relaxed);
+            *tracks_record[track_idx].record_spare.lock() = Vec::new();
+            tracks_record[track_idx]
+                .record_generation
+                .fetch_add(1, Ordering::AcqRel);
             tracks_record[track_idx]
                 .is_playing
                 .store(false, Ordering::Relaxed);
@@ -9307,7 +15153,6 @@ fn initialize_ui(
                 && metronome_count_in_record_enabled.load(Ordering::Relaxed)
                 && count_in_ticks > 0;
             if use_count_in {
-                let sr = tracks_record[track_idx].sample_rate.load(Ordering::Relaxed).max(1);
                 let count_in_samples = count_in_samples(tempo, sr, count_in_ticks);
                 tracks_record[track_idx]
                     .count_in_remaining
@@ -9329,6 +15174,7 @@ fn initialize_ui(
                     .is_recording
                     .store(true, Ordering::Relaxed);
             }
+            spawn_record_helper(&tracks_record, track_idx, sr, channels);
         }
     });
 
@@ -9375,9 +15221,11 @@ fn initialize_ui(
 
     let tracks_level = Arc::clone(tracks);
     let params_level = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_track_level_changed(move |value| {
         let track_idx = params_level.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "level");
             tracks_level[track_idx]
                 .level
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9386,9 +15234,11 @@ fn initialize_ui(
 
     let tracks_mute = Arc::clone(tracks);
     let params_mute = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_track_mute(move || {
         let track_idx = params_mute.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "muted");
             let muted = tracks_mute[track_idx].is_muted.load(Ordering::Relaxed);
             tracks_mute[track_idx]
                 .is_muted
@@ -9396,9 +15246,11 @@ fn initialize_ui(
         }
     });
     let tracks_mute_for = Arc::clone(tracks);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_track_mute_for(move |track: i32| {
         let track_idx = track.max(1) as usize - 1;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "muted");
             let muted = tracks_mute_for[track_idx].is_muted.load(Ordering::Relaxed);
             tracks_mute_for[track_idx]
                 .is_muted
@@ -9408,9 +15260,11 @@ fn initialize_ui(
 
     let tracks_loop = Arc::clone(tracks);
     let params_loop = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_loop_start_changed(move |value| {
         let track_idx = params_loop.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "loop_start");
             tracks_loop[track_idx]
                 .loop_start
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9419,9 +15273,11 @@ fn initialize_ui(
 
     let tracks_trigger = Arc::clone(tracks);
     let params_trigger = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_trigger_start_changed(move |value| {
         let track_idx = params_trigger.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "trigger_start");
             tracks_trigger[track_idx]
                 .trigger_start
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9430,9 +15286,11 @@ fn initialize_ui(
 
     let tracks_loop = Arc::clone(tracks);
     let params_loop = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_loop_length_changed(move |value| {
         let track_idx = params_loop.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "loop_length");
             tracks_loop[track_idx]
                 .loop_length
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9441,9 +15299,11 @@ fn initialize_ui(
 
     let tracks_loop = Arc::clone(tracks);
     let params_loop = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_loop_xfade_changed(move |value| {
         let track_idx = params_loop.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "loop_xfade");
             tracks_loop[track_idx]
                 .loop_xfade
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9452,9 +15312,11 @@ fn initialize_ui(
 
     let tracks_loop = Arc::clone(tracks);
     let params_loop = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_loop_enabled(move || {
         let track_idx = params_loop.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "loop_enabled");
             let enabled = tracks_loop[track_idx].loop_enabled.load(Ordering::Relaxed);
             tracks_loop[track_idx]
                 .loop_enabled
@@ -9464,9 +15326,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_tape_speed_changed(move |value| {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_speed");
             tracks_tape[track_idx]
                 .tape_speed
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9476,6 +15340,86 @@ fn initialize_ui(
         }
     });
 
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    ui.on_tape_fit_bars(move || {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_tape[track_idx];
+            let bpm = f32::from_bits(track.tape_detected_bpm.load(Ordering::Relaxed));
+            let total = track
+                .samples
+                .lock()
+                .first()
+                .map_or(0, |channel| channel.len());
+            if bpm <= 0.0 || total == 0 {
+                return;
+            }
+            let downbeat = (track.tape_downbeat.load(Ordering::Relaxed) as usize).min(total - 1);
+            let bar = 240.0 / bpm * track.sample_rate.load(Ordering::Relaxed).max(1) as f32;
+            let straight = track.tape_rate_mode.load(Ordering::Relaxed) == 1;
+            let available = (total - downbeat) as f32 / bar;
+            let bars = if straight {
+                // Straight divisions only play powers of two up to 16 bars.
+                2.0f32.powi(available.max(1.0).log2().floor().clamp(0.0, 4.0) as i32)
+            } else {
+                available.floor().max(1.0)
+            };
+            let loop_len = (bars * bar).min(total as f32);
+            // Undo the rotate offset and, when playing backwards, the mirrored start
+            // that process applies, so the loop itself begins on the downbeat.
+            let rotate_offset = f32::from_bits(track.tape_rotate.load(Ordering::Relaxed))
+                .clamp(0.0, 1.0)
+                * total as f32;
+            let reverse = track.tape_reverse.load(Ordering::Relaxed)
+                || track.loop_mode.load(Ordering::Relaxed) == 3;
+            let start = if reverse {
+                let loop_end = (downbeat as f32 + loop_len).min(total as f32);
+                1.0 - (loop_end - rotate_offset) / total as f32
+            } else {
+                (downbeat as f32 - rotate_offset).rem_euclid(total as f32) / total as f32
+            };
+            track
+                .loop_start
+                .store(start.clamp(0.0, 0.999).to_bits(), Ordering::Relaxed);
+            track.loop_length.store(
+                (loop_len / total as f32).min(1.0).to_bits(),
+                Ordering::Relaxed,
+            );
+            if straight {
+                // The 1..16 bar divisions are the top five steps of the Speed range.
+                let speed = f32::from_bits(track.tape_speed.load(Ordering::Relaxed));
+                let division = (6.0 + bars.log2()) * 0.4;
+                track
+                    .tape_speed
+                    .store(division.copysign(speed).to_bits(), Ordering::Relaxed);
+                track.tape_sync_requested.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let global_tempo_detected = Arc::clone(global_tempo);
+    ui.on_tape_use_tempo(move || {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let bpm = f32::from_bits(
+                tracks_tape[track_idx]
+                    .tape_detected_bpm
+                    .load(Ordering::Relaxed),
+            );
+            if bpm <= 0.0 {
+                return;
+            }
+            let tempo = bpm.clamp(20.0, 240.0);
+            global_tempo_detected.store(tempo.to_bits(), Ordering::Relaxed);
+            for track in tracks_tape.iter() {
+                track.tape_tempo.store(tempo.to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
     let tracks_tape = Arc::clone(tracks);
     let global_tempo = Arc::clone(global_tempo);
     ui.on_tape_tempo_changed(move |value| {
@@ -9491,12 +15435,120 @@ fn initialize_ui(
         metronome_enabled.store(!enabled, Ordering::Relaxed);
     });
 
+    let params_host_transport = Arc::clone(params);
+    ui.on_toggle_follow_host_transport(move || {
+        let enabled = params_host_transport
+            .follow_host_transport
+            .load(Ordering::Relaxed);
+        params_host_transport
+            .follow_host_transport
+            .store(!enabled, Ordering::Relaxed);
+    });
+
+    let tracks_swing = Arc::clone(tracks);
+    let params_track_swing = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_toggle_track_swing_override(move || {
+        let track_idx = params_track_swing.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "swing_override");
+            let enabled = tracks_swing[track_idx].swing_override.load(Ordering::Relaxed);
+            tracks_swing[track_idx]
+                .swing_override
+                .store(!enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_swing_amount = Arc::clone(tracks);
+    let params_track_swing_amount = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_track_swing_changed(move |value| {
+        let track_idx = params_track_swing_amount
+            .selected_track
+            .value()
+            .saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "swing");
+            tracks_swing_amount[track_idx].swing.store(
+                value.clamp(SWING_MIN, SWING_MAX).to_bits(),
+                Ordering::Relaxed,
+            );
+        }
+    });
+
+    let master_recorder = Arc::clone(master_recorder);
+    ui.on_toggle_master_record(move || {
+        if master_recorder.armed.load(Ordering::Relaxed) {
+            master_recorder.armed.store(false, Ordering::Release);
+        } else {
+            start_master_recording(&master_recorder);
+        }
+    });
+
+    let midi_cc_learn = Arc::clone(midi_cc);
+    ui.on_toggle_midi_learn(move || {
+        let armed = midi_cc_learn.learn_armed.load(Ordering::Relaxed);
+        *midi_cc_learn.learn_target.lock() = None;
+        midi_cc_learn.learn_armed.store(!armed, Ordering::Relaxed);
+    });
+
+    let midi_cc_min = Arc::clone(midi_cc);
+    ui.on_midi_mapping_min_changed(move |index, text| {
+        if let Ok(value) = text.trim().parse::<f32>() {
+            let mut mappings = midi_cc_min.mappings.lock();
+            if let Some(mapping) = mappings.get_mut(index as usize) {
+                mapping.min = value;
+            }
+            midi_cc_min.mappings_changed(&mappings);
+        }
+    });
+
+    let midi_cc_max = Arc::clone(midi_cc);
+    ui.on_midi_mapping_max_changed(move |index, text| {
+        if let Ok(value) = text.trim().parse::<f32>() {
+            let mut mappings = midi_cc_max.mappings.lock();
+            if let Some(mapping) = mappings.get_mut(index as usize) {
+                mapping.max = value;
+            }
+            midi_cc_max.mappings_changed(&mappings);
+        }
+    });
+
+    let midi_cc_curve_select = Arc::clone(midi_cc);
+    ui.on_midi_mapping_curve_changed(move |index, curve| {
+        let mut mappings = midi_cc_curve_select.mappings.lock();
+        if let Some(mapping) = mappings.get_mut(index as usize) {
+            mapping.curve = curve.max(0) as u32;
+        }
+        midi_cc_curve_select.mappings_changed(&mappings);
+    });
+
+    let midi_cc_remove = Arc::clone(midi_cc);
+    ui.on_midi_mapping_removed(move |index| {
+        let mut mappings = midi_cc_remove.mappings.lock();
+        if (index as usize) < mappings.len() {
+            mappings.remove(index as usize);
+        }
+        midi_cc_remove.mappings_changed(&mappings);
+    });
+
     let metronome_count_in_ticks = Arc::clone(metronome_count_in_ticks);
     ui.on_metronome_count_in_changed(move |value| {
         let ticks = value.round().clamp(0.0, METRONOME_COUNT_IN_MAX_TICKS as f32) as u32;
         metronome_count_in_ticks.store(ticks, Ordering::Relaxed);
     });
 
+    let params_record_buffer = Arc::clone(params);
+    ui.on_record_buffer_seconds_changed(move |value| {
+        let seconds = value.round().clamp(
+            RECORD_BUFFER_MIN_SECONDS as f32,
+            RECORD_BUFFER_MAX_SECONDS as f32,
+        ) as u32;
+        params_record_buffer
+            .record_buffer_seconds
+            .store(seconds, Ordering::Relaxed);
+    });
+
     let metronome_count_in_playback_toggle =
         Arc::clone(metronome_count_in_playback);
     ui.on_toggle_metronome_count_playback(move || {
@@ -9513,9 +15565,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_tape_rate_mode_selected(move |index| {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_rate_mode");
             let mode = index.clamp(0, 3) as u32;
             tracks_tape[track_idx]
                 .tape_rate_mode
@@ -9528,9 +15582,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_tape_rotate_changed(move |value| {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_rotate");
             tracks_tape[track_idx]
                 .tape_rotate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9539,9 +15595,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_tape_glide_changed(move |value| {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_glide");
             tracks_tape[track_idx]
                 .tape_glide
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9550,9 +15608,37 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_tape_pitch_changed(move |value| {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_pitch");
+            tracks_tape[track_idx]
+                .tape_pitch
+                .store(value.clamp(-24.0, 24.0).to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_tape_fine_changed(move |value| {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_fine");
+            tracks_tape[track_idx]
+                .tape_fine
+                .store(value.clamp(-100.0, 100.0).to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_tape_sos_changed(move |value| {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_sos");
             tracks_tape[track_idx]
                 .tape_sos
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9561,9 +15647,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_pitch_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_pitch");
             tracks_mosaic[track_idx]
                 .mosaic_pitch
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9572,9 +15660,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_rate_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_rate");
             tracks_mosaic[track_idx]
                 .mosaic_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9583,9 +15673,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_size_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_size");
             tracks_mosaic[track_idx]
                 .mosaic_size
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9594,9 +15686,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_contour_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_contour");
             tracks_mosaic[track_idx]
                 .mosaic_contour
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9605,9 +15699,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_warp_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_warp");
             tracks_mosaic[track_idx]
                 .mosaic_warp
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9616,9 +15712,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_spray_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_spray");
             tracks_mosaic[track_idx]
                 .mosaic_spray
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9627,9 +15725,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_pattern_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_pattern");
             tracks_mosaic[track_idx]
                 .mosaic_pattern
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9638,9 +15738,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_wet_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_wet");
             tracks_mosaic[track_idx]
                 .mosaic_wet
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9649,9 +15751,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_spatial_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_spatial");
             tracks_mosaic[track_idx]
                 .mosaic_spatial
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9660,9 +15764,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_detune_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_detune");
             tracks_mosaic[track_idx]
                 .mosaic_detune
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9671,9 +15777,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_rand_rate_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_rand_rate");
             tracks_mosaic[track_idx]
                 .mosaic_rand_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9682,9 +15790,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_rand_size_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_rand_size");
             tracks_mosaic[track_idx]
                 .mosaic_rand_size
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9693,9 +15803,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_mosaic_sos_changed(move |value| {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_sos");
             tracks_mosaic[track_idx]
                 .mosaic_sos
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9705,9 +15817,11 @@ fn initialize_ui(
 
     let tracks_mosaic = Arc::clone(tracks);
     let params_mosaic = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_mosaic_enabled(move || {
         let track_idx = params_mosaic.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "mosaic_enabled");
             let enabled = tracks_mosaic[track_idx].mosaic_enabled.load(Ordering::Relaxed);
             tracks_mosaic[track_idx]
                 .mosaic_enabled
@@ -9717,9 +15831,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_cutoff_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_cutoff");
             tracks_ring[track_idx]
                 .ring_cutoff
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9728,9 +15844,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_resonance_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_resonance");
             tracks_ring[track_idx]
                 .ring_resonance
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9739,9 +15857,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_decay_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_decay");
             tracks_ring[track_idx]
                 .ring_decay
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9750,9 +15870,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_decay_mode_selected(move |index| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_decay_mode");
             let mode = index.clamp(0, 1) as u32;
             tracks_ring[track_idx]
                 .ring_decay_mode
@@ -9762,9 +15884,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_pitch_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_pitch");
             tracks_ring[track_idx]
                 .ring_pitch
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9773,9 +15897,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_tone_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_tone");
             tracks_ring[track_idx]
                 .ring_tone
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9784,9 +15910,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_tilt_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_tilt");
             tracks_ring[track_idx]
                 .ring_tilt
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9795,9 +15923,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_slope_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_slope");
             tracks_ring[track_idx]
                 .ring_slope
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9806,9 +15936,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_wet_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_wet");
             tracks_ring[track_idx]
                 .ring_wet
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9817,9 +15949,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_detune_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_detune");
             tracks_ring[track_idx]
                 .ring_detune
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9828,9 +15962,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_waves_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_waves");
             tracks_ring[track_idx]
                 .ring_waves
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9839,9 +15975,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_waves_rate_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_waves_rate");
             tracks_ring[track_idx]
                 .ring_waves_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9850,9 +15988,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_noise_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_noise");
             tracks_ring[track_idx]
                 .ring_noise
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9861,9 +16001,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_ring_noise_rate_changed(move |value| {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_noise_rate");
             tracks_ring[track_idx]
                 .ring_noise_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -9872,9 +16014,11 @@ fn initialize_ui(
 
     let tracks_ring = Arc::clone(tracks);
     let params_ring = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_ring_enabled(move || {
         let track_idx = params_ring.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "ring_enabled");
             let enabled = tracks_ring[track_idx].ring_enabled.load(Ordering::Relaxed);
             tracks_ring[track_idx]
                 .ring_enabled
@@ -9884,9 +16028,11 @@ fn initialize_ui(
 
     let tracks_g8 = Arc::clone(tracks);
     let params_g8 = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_g8_enabled(move || {
         let track_idx = params_g8.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "g8_enabled");
             let enabled = tracks_g8[track_idx].g8_enabled.load(Ordering::Relaxed);
             tracks_g8[track_idx]
                 .g8_enabled
@@ -9896,9 +16042,11 @@ fn initialize_ui(
 
     let tracks_g8 = Arc::clone(tracks);
     let params_g8 = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_g8_rate_selected(move |index| {
         let track_idx = params_g8.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "g8_rate_index");
             let rate_index = index.clamp(0, 4) as u32;
             tracks_g8[track_idx]
                 .g8_rate_index
@@ -9908,11 +16056,13 @@ fn initialize_ui(
 
     let tracks_g8 = Arc::clone(tracks);
     let params_g8 = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_g8_step_changed(move |index, value| {
         let track_idx = params_g8.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let step_idx = index.clamp(0, 31) as usize;
             let clamped = value.clamp(0.0, 1.0);
+            midi_cc_touch.touch(track_idx, &format!("g8_step_{}", step_idx));
             tracks_g8[track_idx].g8_steps[step_idx]
                 .store(clamped.to_bits(), Ordering::Relaxed);
         }
@@ -9920,9 +16070,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_tape_reverse(move || {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_reverse");
             let reversed = tracks_tape[track_idx].tape_reverse.load(Ordering::Relaxed);
             tracks_tape[track_idx]
                 .tape_reverse
@@ -9932,9 +16084,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_tape_freeze(move || {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_freeze");
             let frozen = tracks_tape[track_idx].tape_freeze.load(Ordering::Relaxed);
             tracks_tape[track_idx]
                 .tape_freeze
@@ -9944,9 +16098,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_tape_keylock(move || {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_keylock");
             let keylock = tracks_tape[track_idx].tape_keylock.load(Ordering::Relaxed);
             let enabled = !keylock;
             tracks_tape[track_idx]
@@ -9963,25 +16119,122 @@ fn initialize_ui(
                 let play_pos = f32::from_bits(
                     tracks_tape[track_idx].play_pos.load(Ordering::Relaxed),
                 );
-                tracks_tape[track_idx]
-                    .keylock_phase
-                    .store(0.0f32.to_bits(), Ordering::Relaxed);
-                tracks_tape[track_idx]
-                    .keylock_grain_a
-                    .store(play_pos.to_bits(), Ordering::Relaxed);
-                tracks_tape[track_idx].keylock_grain_b.store(
-                    (play_pos + direction as f32 * KEYLOCK_GRAIN_HOP as f32).to_bits(),
-                    Ordering::Relaxed,
-                );
+                reset_tape_keylock(&tracks_tape[track_idx], play_pos, direction);
+            }
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_tape_keylock_mode_selected(move |index| {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_keylock_mode");
+            let track = &tracks_tape[track_idx];
+            track
+                .tape_keylock_mode
+                .store(index.clamp(0, 2) as u32, Ordering::Relaxed);
+            let mut direction = track.loop_dir.load(Ordering::Relaxed);
+            if direction == 0 {
+                direction = 1;
+            }
+            if track.tape_reverse.load(Ordering::Relaxed) {
+                direction *= -1;
+            }
+            let play_pos = f32::from_bits(track.play_pos.load(Ordering::Relaxed));
+            reset_tape_keylock(track, play_pos, direction);
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_tape_slice_mode_selected(move |index| {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_slice_mode");
+            let track = &tracks_tape[track_idx];
+            track
+                .tape_slice_mode
+                .store(index.clamp(0, 2) as u32, Ordering::Relaxed);
+            track.tape_slice_playing.store(false, Ordering::Relaxed);
+            track.tape_slice_step.store(-1, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    ui.on_tape_slice_shuffle(move || {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_tape[track_idx];
+            let count = (track.tape_slice_count.load(Ordering::Relaxed) as usize)
+                .min(TAPE_SLICE_MAX);
+            if count == 0 {
+                return;
+            }
+            let mut order: Vec<u32> = (0..count as u32).collect();
+            fastrand::shuffle(&mut order);
+            for (i, slot) in track.tape_slice_order.iter().enumerate() {
+                slot.store(order[i % count], Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    ui.on_tape_slice_reverse(move || {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_tape[track_idx];
+            let mut order: Vec<u32> = track
+                .tape_slice_order
+                .iter()
+                .map(|slot| slot.load(Ordering::Relaxed))
+                .collect();
+            order.reverse();
+            for (slot, slice) in track.tape_slice_order.iter().zip(order) {
+                slot.store(slice, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    ui.on_tape_slice_reset(move || {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            for (i, slot) in tracks_tape[track_idx].tape_slice_order.iter().enumerate() {
+                slot.store(i as u32, Ordering::Relaxed);
             }
         }
     });
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    ui.on_tape_slice_step_changed(move |step, slice| {
+        let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS && (0..TAPE_SLICE_STEPS as i32).contains(&step) {
+            let track = &tracks_tape[track_idx];
+            let count = (track.tape_slice_count.load(Ordering::Relaxed) as usize)
+                .clamp(1, TAPE_SLICE_MAX);
+            let entry = match slice {
+                -1 => TAPE_SLICE_REST,
+                -2 => TAPE_SLICE_HOLD,
+                slice => slice.clamp(0, count as i32 - 1) as u32,
+            };
+            track.tape_slice_order[step as usize].store(entry, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_tape = Arc::clone(tracks);
+    let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_tape_monitor(move || {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_monitor");
             let monitor = tracks_tape[track_idx].tape_monitor.load(Ordering::Relaxed);
             tracks_tape[track_idx]
                 .tape_monitor
@@ -9991,9 +16244,11 @@ fn initialize_ui(
 
     let tracks_tape = Arc::clone(tracks);
     let params_tape = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_tape_overdub(move || {
         let track_idx = params_tape.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "tape_overdub");
             let overdub = tracks_tape[track_idx].tape_overdub.load(Ordering::Relaxed);
             tracks_tape[track_idx]
                 .tape_overdub
@@ -10004,9 +16259,11 @@ fn initialize_ui(
 
     let tracks_loop = Arc::clone(tracks);
     let params_loop = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_loop_mode_selected(move |index| {
         let track_idx = params_loop.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "loop_mode");
         let mode = index.clamp(0, 5) as u32;
             tracks_loop[track_idx]
                 .loop_mode
@@ -10120,6 +16377,11 @@ fn initialize_ui(
         if ui.get_input_device_index() >= inputs.len() as i32 {
             ui.set_input_device_index(0);
         }
+        if clock_standalone {
+            // Keep the open ports; only the lists are refreshed.
+            ui.set_midi_clock_inputs(midi_clock_port_model("Internal", &available_midi_inputs()));
+            ui.set_midi_clock_outputs(midi_clock_port_model("Off", &available_midi_outputs()));
+        }
     });
 
     let ui_apply = ui_weak.clone();
@@ -10182,34 +16444,47 @@ fn initialize_ui(
     for i in 0..4 {
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let animate_library_a = Arc::clone(&animate_library);
+        let animate_library_b = Arc::clone(&animate_library);
+        let animate_library_c = Arc::clone(&animate_library);
+        let animate_library_d = Arc::clone(&animate_library);
         let slot_idx = i;
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_type_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_type_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_types[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    animate_library_a.request_track(&tracks_animate[track_idx]);
                 }
             }),
             1 => ui.on_animate_slot_b_type_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_type_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_types[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    animate_library_b.request_track(&tracks_animate[track_idx]);
                 }
             }),
             2 => ui.on_animate_slot_c_type_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_type_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_types[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    animate_library_c.request_track(&tracks_animate[track_idx]);
                 }
             }),
             3 => ui.on_animate_slot_d_type_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_type_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_types[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    animate_library_d.request_track(&tracks_animate[track_idx]);
                 }
             }),
             _ => (),
@@ -10221,38 +16496,43 @@ fn initialize_ui(
         let animate_library_b = Arc::clone(&animate_library);
         let animate_library_c = Arc::clone(&animate_library);
         let animate_library_d = Arc::clone(&animate_library);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wavetable_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_wavetable_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_wavetables[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
-                animate_library_a.ensure_wavetable_loaded(index as usize);
+                animate_library_a.request_load(AnimateLoadRequest::Wavetable(index as usize));
             }),
             1 => ui.on_animate_slot_b_wavetable_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_wavetable_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_wavetables[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
-                animate_library_b.ensure_wavetable_loaded(index as usize);
+                animate_library_b.request_load(AnimateLoadRequest::Wavetable(index as usize));
             }),
             2 => ui.on_animate_slot_c_wavetable_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_wavetable_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_wavetables[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
-                animate_library_c.ensure_wavetable_loaded(index as usize);
+                animate_library_c.request_load(AnimateLoadRequest::Wavetable(index as usize));
             }),
             3 => ui.on_animate_slot_d_wavetable_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_wavetable_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_wavetables[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
-                animate_library_d.ensure_wavetable_loaded(index as usize);
+                animate_library_d.request_load(AnimateLoadRequest::Wavetable(index as usize));
             }),
             _ => (),
         }
@@ -10263,48 +16543,59 @@ fn initialize_ui(
         let animate_library_b = Arc::clone(&animate_library);
         let animate_library_c = Arc::clone(&animate_library);
         let animate_library_d = Arc::clone(&animate_library);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_sample_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_sample_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_samples[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    // A multisample slot needs the rest of the set as well.
+                    animate_library_a.request_track(&tracks_animate[track_idx]);
                 }
-                animate_library_a.ensure_sample_loaded(index as usize);
             }),
             1 => ui.on_animate_slot_b_sample_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_sample_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_samples[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    // A multisample slot needs the rest of the set as well.
+                    animate_library_b.request_track(&tracks_animate[track_idx]);
                 }
-                animate_library_b.ensure_sample_loaded(index as usize);
             }),
             2 => ui.on_animate_slot_c_sample_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_sample_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_samples[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    // A multisample slot needs the rest of the set as well.
+                    animate_library_c.request_track(&tracks_animate[track_idx]);
                 }
-                animate_library_c.ensure_sample_loaded(index as usize);
             }),
             3 => ui.on_animate_slot_d_sample_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_sample_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_samples[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
+                    // A multisample slot needs the rest of the set as well.
+                    animate_library_d.request_track(&tracks_animate[track_idx]);
                 }
-                animate_library_d.ensure_sample_loaded(index as usize);
             }),
             _ => (),
         }
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_coarse_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_coarse_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_coarse[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10312,6 +16603,7 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_coarse_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_coarse_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_coarse[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10319,6 +16611,7 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_coarse_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_coarse_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_coarse[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10326,6 +16619,7 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_coarse_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_coarse_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_coarse[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10335,10 +16629,12 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_fine_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_fine_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_fine[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10346,6 +16642,7 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_fine_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_fine_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_fine[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10353,6 +16650,7 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_fine_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_fine_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_fine[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10360,6 +16658,7 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_fine_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_fine_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_fine[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10369,10 +16668,12 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_level_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_level_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_level[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10380,6 +16681,7 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_level_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_level_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_level[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10387,6 +16689,7 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_level_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_level_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_level[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10394,6 +16697,7 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_level_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_level_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_level[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10403,10 +16707,12 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_pan_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_pan_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_pan[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10414,6 +16720,7 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_pan_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_pan_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_pan[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10421,6 +16728,7 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_pan_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_pan_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_pan[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10428,6 +16736,7 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_pan_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_pan_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_pan[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10437,10 +16746,66 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
+        match i {
+            0 => ui.on_animate_slot_a_wt_position_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_position_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_wt_position[slot_idx]
+                        .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+                }
+            }),
+            1 => ui.on_animate_slot_b_wt_position_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_position_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_wt_position[slot_idx]
+                        .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+                }
+            }),
+            2 => ui.on_animate_slot_c_wt_position_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_position_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_wt_position[slot_idx]
+                        .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+                }
+            }),
+            3 => ui.on_animate_slot_d_wt_position_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_position_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_wt_position[slot_idx]
+                        .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+                }
+            }),
+            _ => (),
+        }
+
+        let tracks_animate = Arc::clone(tracks);
+        let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wt_lfo_amount_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_amount_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_amount[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10448,6 +16813,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_wt_lfo_amount_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_amount_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_amount[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10455,6 +16824,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_wt_lfo_amount_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_amount_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_amount[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10462,6 +16835,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_wt_lfo_amount_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_amount_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_amount[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10471,10 +16848,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wt_lfo_shape_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_shape_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_shape[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10482,6 +16864,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_wt_lfo_shape_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_shape_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_shape[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10489,6 +16875,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_wt_lfo_shape_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_shape_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_shape[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10496,6 +16886,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_wt_lfo_shape_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_shape_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_shape[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10505,10 +16899,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wt_lfo_rate_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_rate_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_rate[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10516,6 +16915,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_wt_lfo_rate_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_rate_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_rate[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10523,6 +16926,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_wt_lfo_rate_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_rate_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_rate[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10530,6 +16937,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_wt_lfo_rate_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_rate_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_rate[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10539,10 +16950,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wt_lfo_sync_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_sync_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_sync[slot_idx]
                         .store(value, Ordering::Relaxed);
                 }
@@ -10550,6 +16966,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_wt_lfo_sync_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_sync_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_sync[slot_idx]
                         .store(value, Ordering::Relaxed);
                 }
@@ -10557,6 +16977,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_wt_lfo_sync_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_sync_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_sync[slot_idx]
                         .store(value, Ordering::Relaxed);
                 }
@@ -10564,6 +16988,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_wt_lfo_sync_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_sync_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_sync[slot_idx]
                         .store(value, Ordering::Relaxed);
                 }
@@ -10573,10 +17001,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_wt_lfo_division_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_division_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_division[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10584,6 +17017,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_wt_lfo_division_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_division_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_division[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10591,6 +17028,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_wt_lfo_division_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_division_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_division[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10598,6 +17039,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_wt_lfo_division_changed(move |index| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_wt_lfo_division_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_wt_lfo_division[slot_idx]
                         .store(index as u32, Ordering::Relaxed);
                 }
@@ -10607,10 +17052,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_sample_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_sample_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_sample_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10618,6 +17068,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_sample_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_sample_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_sample_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10625,6 +17079,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_sample_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_sample_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_sample_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10632,6 +17090,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_sample_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_sample_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_sample_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10641,10 +17103,15 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_loop_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_loop_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_loop_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10652,6 +17119,10 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_loop_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_loop_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_loop_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10659,6 +17130,10 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_loop_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_loop_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_loop_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10666,6 +17141,10 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_loop_start_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_loop_start_{}", slot_idx),
+                    );
                     tracks_animate[track_idx].animate_slot_loop_start[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10675,10 +17154,12 @@ fn initialize_ui(
 
         let tracks_animate = Arc::clone(tracks);
         let params_animate = Arc::clone(params);
+        let midi_cc_touch = Arc::clone(midi_cc);
         match i {
             0 => ui.on_animate_slot_a_loop_end_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_loop_end_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_loop_end[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10686,6 +17167,7 @@ fn initialize_ui(
             1 => ui.on_animate_slot_b_loop_end_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_loop_end_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_loop_end[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10693,6 +17175,7 @@ fn initialize_ui(
             2 => ui.on_animate_slot_c_loop_end_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_loop_end_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_loop_end[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
@@ -10700,19 +17183,81 @@ fn initialize_ui(
             3 => ui.on_animate_slot_d_loop_end_changed(move |value| {
                 let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
                 if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(track_idx, &format!("animate_slot_loop_end_{}", slot_idx));
                     tracks_animate[track_idx].animate_slot_loop_end[slot_idx]
                         .store(value.to_bits(), Ordering::Relaxed);
                 }
             }),
             _ => (),
         }
+
+        let tracks_animate = Arc::clone(tracks);
+        let params_animate = Arc::clone(params);
+        let animate_library_a = Arc::clone(&animate_library);
+        let animate_library_b = Arc::clone(&animate_library);
+        let animate_library_c = Arc::clone(&animate_library);
+        let animate_library_d = Arc::clone(&animate_library);
+        let midi_cc_touch = Arc::clone(midi_cc);
+        match i {
+            0 => ui.on_animate_slot_a_multisample_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_multisample_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_multisample[slot_idx]
+                        .store(value, Ordering::Relaxed);
+                    animate_library_a.request_track(&tracks_animate[track_idx]);
+                }
+            }),
+            1 => ui.on_animate_slot_b_multisample_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_multisample_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_multisample[slot_idx]
+                        .store(value, Ordering::Relaxed);
+                    animate_library_b.request_track(&tracks_animate[track_idx]);
+                }
+            }),
+            2 => ui.on_animate_slot_c_multisample_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_multisample_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_multisample[slot_idx]
+                        .store(value, Ordering::Relaxed);
+                    animate_library_c.request_track(&tracks_animate[track_idx]);
+                }
+            }),
+            3 => ui.on_animate_slot_d_multisample_changed(move |value| {
+                let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+                if track_idx < NUM_TRACKS {
+                    midi_cc_touch.touch(
+                        track_idx,
+                        &format!("animate_slot_multisample_{}", slot_idx),
+                    );
+                    tracks_animate[track_idx].animate_slot_multisample[slot_idx]
+                        .store(value, Ordering::Relaxed);
+                    animate_library_d.request_track(&tracks_animate[track_idx]);
+                }
+            }),
+            _ => (),
+        }
     }
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_slot_a_filter_type_changed(move |index| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_type_0");
             tracks_animate[track_idx]
                 .animate_slot_filter_type[0]
                 .store(index as u32, Ordering::Relaxed);
@@ -10721,45 +17266,347 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
-    ui.on_animate_slot_a_filter_cutoff_changed(move |value| {
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_a_filter_cutoff_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_cutoff_0");
+            tracks_animate[track_idx]
+                .animate_slot_filter_cutoff[0]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_a_filter_resonance_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_resonance_0");
+            tracks_animate[track_idx]
+                .animate_slot_filter_resonance[0]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_b_filter_type_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_type_1");
+            tracks_animate[track_idx]
+                .animate_slot_filter_type[1]
+                .store(index as u32, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_b_filter_cutoff_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_cutoff_1");
+            tracks_animate[track_idx]
+                .animate_slot_filter_cutoff[1]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_b_filter_resonance_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_resonance_1");
+            tracks_animate[track_idx]
+                .animate_slot_filter_resonance[1]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_c_filter_type_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_type_2");
+            tracks_animate[track_idx]
+                .animate_slot_filter_type[2]
+                .store(index as u32, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_c_filter_cutoff_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_cutoff_2");
+            tracks_animate[track_idx]
+                .animate_slot_filter_cutoff[2]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_c_filter_resonance_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_resonance_2");
+            tracks_animate[track_idx]
+                .animate_slot_filter_resonance[2]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_d_filter_type_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_type_3");
+            tracks_animate[track_idx]
+                .animate_slot_filter_type[3]
+                .store(index as u32, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_d_filter_cutoff_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_cutoff_3");
+            tracks_animate[track_idx]
+                .animate_slot_filter_cutoff[3]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_d_filter_resonance_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_resonance_3");
+            tracks_animate[track_idx]
+                .animate_slot_filter_resonance[3]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_a_filter_drive_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_drive_0");
+            tracks_animate[track_idx]
+                .animate_slot_filter_drive[0]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_b_filter_drive_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_drive_1");
+            tracks_animate[track_idx]
+                .animate_slot_filter_drive[1]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_c_filter_drive_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_drive_2");
+            tracks_animate[track_idx]
+                .animate_slot_filter_drive[2]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_d_filter_drive_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_slot_filter_drive_3");
+            tracks_animate[track_idx]
+                .animate_slot_filter_drive[3]
+                .store(value.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_slot_env_param_changed(move |slot, param, value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let slot = slot as usize;
+            let param = param as usize;
+            if slot < 4 && param < ANIMATE_SLOT_ENV_PARAMS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("animate_slot_{}_{}", ANIMATE_SLOT_ENV_KEYS[param], slot),
+                );
+                let (min, max) = animate_slot_env_range(param);
+                animate_slot_env_param(&tracks_animate[track_idx], param)[slot]
+                    .store(value.clamp(min, max).to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_changed(move |x, y| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_x");
+            tracks_animate[track_idx]
+                .animate_vector_x
+                .store(x.to_bits(), Ordering::Relaxed);
+            tracks_animate[track_idx]
+                .animate_vector_y
+                .store(y.to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_path_enabled_changed(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_path_enabled");
+            tracks_animate[track_idx]
+                .animate_vector_path_enabled
+                .store(enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_vector_path_recording_changed(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            if enabled {
+                // A new take replaces the path; the audio thread adds points from here.
+                track.animate_vector_path_len.store(0, Ordering::Relaxed);
+                track.animate_vector_path_rec_elapsed.store(0, Ordering::Relaxed);
+                track.animate_vector_path_loop_start.store(0, Ordering::Relaxed);
+                track.animate_vector_path_loop_end.store(0, Ordering::Relaxed);
+                track.animate_vector_path_recording.store(true, Ordering::Relaxed);
+            } else {
+                track.animate_vector_path_recording.store(false, Ordering::Relaxed);
+                let len = track.animate_vector_path_len.load(Ordering::Relaxed);
+                if len > 1 {
+                    track.animate_vector_path_loop_end.store(len - 1, Ordering::Relaxed);
+                    track.animate_vector_path_enabled.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_path_sync_changed(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_path_sync");
+            tracks_animate[track_idx]
+                .animate_vector_path_sync
+                .store(enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_path_loop_changed(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_path_loop");
+            tracks_animate[track_idx]
+                .animate_vector_path_loop
+                .store(enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_path_loop_start_changed(move |point| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_path_loop_start");
+            let point = (point.max(0) as u32).min(ANIMATE_VECTOR_PATH_POINTS as u32 - 1);
             tracks_animate[track_idx]
-                .animate_slot_filter_cutoff[0]
-                .store(value.to_bits(), Ordering::Relaxed);
+                .animate_vector_path_loop_start
+                .store(point, Ordering::Relaxed);
         }
     });
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
-    ui.on_animate_slot_a_filter_resonance_changed(move |value| {
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_vector_path_loop_end_changed(move |point| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_vector_path_loop_end");
+            let point = (point.max(0) as u32).min(ANIMATE_VECTOR_PATH_POINTS as u32 - 1);
             tracks_animate[track_idx]
-                .animate_slot_filter_resonance[0]
-                .store(value.to_bits(), Ordering::Relaxed);
+                .animate_vector_path_loop_end
+                .store(point, Ordering::Relaxed);
         }
     });
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
-    ui.on_animate_vector_changed(move |x, y| {
+    ui.on_animate_vector_path_cleared(move || {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            tracks_animate[track_idx]
-                .animate_vector_x
-                .store(x.to_bits(), Ordering::Relaxed);
-            tracks_animate[track_idx]
-                .animate_vector_y
-                .store(y.to_bits(), Ordering::Relaxed);
+            let track = &tracks_animate[track_idx];
+            track.animate_vector_path_recording.store(false, Ordering::Relaxed);
+            track.animate_vector_path_enabled.store(false, Ordering::Relaxed);
+            track.animate_vector_path_len.store(0, Ordering::Relaxed);
+            track.animate_vector_path_loop_start.store(0, Ordering::Relaxed);
+            track.animate_vector_path_loop_end.store(0, Ordering::Relaxed);
         }
     });
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_x_waveform_changed(move |index| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_x_waveform");
             tracks_animate[track_idx]
                 .animate_lfo_x_waveform
                 .store(index as u32, Ordering::Relaxed);
@@ -10768,18 +17615,22 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_x_sync_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_x_sync");
             tracks_animate[track_idx].animate_lfo_x_sync.store(value, Ordering::Relaxed);
         }
     });
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_x_division_changed(move |index| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_x_division");
             tracks_animate[track_idx]
                 .animate_lfo_x_division
                 .store(index as u32, Ordering::Relaxed);
@@ -10788,9 +17639,11 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_x_rate_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_x_rate");
             tracks_animate[track_idx]
                 .animate_lfo_x_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10799,9 +17652,11 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_x_amount_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_x_amount");
             tracks_animate[track_idx]
                 .animate_lfo_x_amount
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10810,9 +17665,11 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_y_waveform_changed(move |index| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_y_waveform");
             tracks_animate[track_idx]
                 .animate_lfo_y_waveform
                 .store(index as u32, Ordering::Relaxed);
@@ -10821,18 +17678,22 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_y_sync_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_y_sync");
             tracks_animate[track_idx].animate_lfo_y_sync.store(value, Ordering::Relaxed);
         }
     });
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_y_division_changed(move |index| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_y_division");
             tracks_animate[track_idx]
                 .animate_lfo_y_division
                 .store(index as u32, Ordering::Relaxed);
@@ -10841,9 +17702,11 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_y_rate_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_y_rate");
             tracks_animate[track_idx]
                 .animate_lfo_y_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10852,15 +17715,99 @@ fn initialize_ui(
 
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_animate_lfo_y_amount_changed(move |value| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_lfo_y_amount");
             tracks_animate[track_idx]
                 .animate_lfo_y_amount
                 .store(value.to_bits(), Ordering::Relaxed);
         }
     });
 
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_voice_mode_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_voice_mode");
+            let clamped = index.clamp(0, (ANIMATE_VOICE_MODES.len() - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_voice_mode
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_steal_mode_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_steal_mode");
+            let clamped = index.clamp(0, (ANIMATE_STEAL_MODES.len() - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_steal_mode
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_polyphony_changed(move |voices| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_polyphony");
+            let clamped = voices.clamp(1, ANIMATE_VOICES as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_polyphony
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_unison_voices_changed(move |voices| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_unison_voices");
+            let clamped = voices.clamp(2, ANIMATE_UNISON_MAX as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_unison_voices
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_unison_detune_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_unison_detune");
+            tracks_animate[track_idx]
+                .animate_unison_detune
+                .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_glide_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_glide");
+            tracks_animate[track_idx]
+                .animate_glide
+                .store(value.clamp(0.0, ANIMATE_GLIDE_MAX).to_bits(), Ordering::Relaxed);
+        }
+    });
+
     let tracks_animate = Arc::clone(tracks);
     let params_animate = Arc::clone(params);
     ui.global::<RDSKeybedBus>().on_note_triggered(move |note| {
@@ -10880,19 +17827,195 @@ fn initialize_ui(
     ui.on_animate_sequencer_grid_toggled(move |row, step| {
         let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            let index = (row * 16 + step) as usize;
-            if index < 160 {
-                let current = tracks_animate[track_idx].animate_sequencer_grid[index].load(Ordering::Relaxed);
-                tracks_animate[track_idx].animate_sequencer_grid[index].store(!current, Ordering::Relaxed);
+            let row = row.max(0) as usize;
+            let step = step.max(0) as usize;
+            if row < ANIMATE_SEQ_VOICES && step < ANIMATE_SEQ_STEPS {
+                let cell = &tracks_animate[track_idx].animate_sequencer_grid[row][step];
+                let current = cell.load(Ordering::Relaxed);
+                cell.store(!current, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_page_changed(move |page| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let clamped = page.clamp(0, (ANIMATE_SEQ_PAGES - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_page
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_edit_lane_changed(move |lane| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let clamped = lane.clamp(0, (ANIMATE_SEQ_VOICES - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_edit_lane
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_edit_step_changed(move |step| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let clamped = step.clamp(0, (ANIMATE_SEQ_STEPS - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_edit_step
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_scale_assigned(move |scale| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_scale");
+            let clamped = scale.clamp(0, (ANIMATE_SCALES.len() - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_scale
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_animate_root_note_changed(move |note| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "animate_root_note");
+            tracks_animate[track_idx]
+                .animate_root_note
+                .store(note.clamp(0, 96) as u32, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_step_note_override_changed(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            let lane = (track.animate_edit_lane.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_VOICES - 1);
+            let step = (track.animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+            if enabled && !track.animate_step_note_override[lane][step].load(Ordering::Relaxed) {
+                // Start from the note the scale gives this lane.
+                let note = animate_resolve_step_note(track, lane, step);
+                track.animate_step_note[lane][step].store(note, Ordering::Relaxed);
+            }
+            track.animate_step_note_override[lane][step].store(enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_lock_param_changed(move |param| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let clamped = param.clamp(0, (ANIMATE_LOCK_PARAMS - 1) as i32) as u32;
+            tracks_animate[track_idx]
+                .animate_lock_param
+                .store(clamped, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_step_lock_toggled(move |enabled| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            let step = (track.animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+            let param = (track.animate_lock_param.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_LOCK_PARAMS - 1);
+            if enabled && !track.animate_step_lock_enabled[step][param].load(Ordering::Relaxed) {
+                // New locks start from the current knob value.
+                let value = animate_lock_base(track)[param];
+                track.animate_step_lock_values[step][param].store(value.to_bits(), Ordering::Relaxed);
+            }
+            track.animate_step_lock_enabled[step][param].store(enabled, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_step_lock_value_changed(move |value| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            let step = (track.animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+            let param = (track.animate_lock_param.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_LOCK_PARAMS - 1);
+            let (min, max) = animate_lock_range(param);
+            track.animate_step_lock_values[step][param]
+                .store(value.clamp(min, max).to_bits(), Ordering::Relaxed);
+            track.animate_step_lock_enabled[step][param].store(true, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_step_locks_cleared(move || {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            let step = (track.animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+            for lock in track.animate_step_lock_enabled[step].iter() {
+                lock.store(false, Ordering::Relaxed);
             }
         }
     });
 
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    ui.on_animate_step_note_changed(move |note| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_animate[track_idx];
+            let lane = (track.animate_edit_lane.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_VOICES - 1);
+            let step = (track.animate_edit_step.load(Ordering::Relaxed) as usize)
+                .min(ANIMATE_SEQ_STEPS - 1);
+            track.animate_step_note[lane][step].store(note.clamp(0, 127) as u32, Ordering::Relaxed);
+            track.animate_step_note_override[lane][step].store(true, Ordering::Relaxed);
+        }
+    });
+
+    let tracks_animate = Arc::clone(tracks);
+    let params_animate = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_track_midi_channel_changed(move |index| {
+        let track_idx = params_animate.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "midi_channel");
+            tracks_animate[track_idx]
+                .midi_channel
+                .store(index.clamp(0, 16) as u32, Ordering::Relaxed);
+        }
+    });
+
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_pitch_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_pitch");
             tracks_kick[track_idx]
                 .kick_pitch
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10901,9 +18024,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_decay_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_decay");
             tracks_kick[track_idx]
                 .kick_decay
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10912,9 +18037,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_attack_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_attack");
             tracks_kick[track_idx]
                 .kick_attack
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10923,9 +18050,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_pitch_env_amount_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_pitch_env_amount");
             tracks_kick[track_idx]
                 .kick_pitch_env_amount
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10934,9 +18063,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_drive_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_drive");
             tracks_kick[track_idx]
                 .kick_drive
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10945,9 +18076,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_level_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_level");
             tracks_kick[track_idx]
                 .kick_level
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10956,9 +18089,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_filter_type_changed(move |index| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_filter_type");
             tracks_kick[track_idx]
                 .kick_filter_type
                 .store(index as u32, Ordering::Relaxed);
@@ -10967,9 +18102,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_filter_cutoff_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_filter_cutoff");
             tracks_kick[track_idx]
                 .kick_filter_cutoff
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10978,9 +18115,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_filter_resonance_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_filter_resonance");
             tracks_kick[track_idx]
                 .kick_filter_resonance
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -10989,9 +18128,11 @@ fn initialize_ui(
 
     let tracks_kick = Arc::clone(tracks);
     let params_kick = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_kick_filter_pre_drive_changed(move |value| {
         let track_idx = params_kick.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "kick_filter_pre_drive");
             tracks_kick[track_idx]
                 .kick_filter_pre_drive
                 .store(value, Ordering::Relaxed);
@@ -11013,9 +18154,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_tone_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_tone");
             tracks_snare[track_idx]
                 .snare_tone
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11024,9 +18167,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_decay_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_decay");
             tracks_snare[track_idx]
                 .snare_decay
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11035,9 +18180,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_snappy_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_snappy");
             tracks_snare[track_idx]
                 .snare_snappy
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11046,9 +18193,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_attack_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_attack");
             tracks_snare[track_idx]
                 .snare_attack
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11057,9 +18206,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_drive_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_drive");
             tracks_snare[track_idx]
                 .snare_drive
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11068,9 +18219,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_level_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_level");
             tracks_snare[track_idx]
                 .snare_level
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11079,9 +18232,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_filter_type_changed(move |index| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_filter_type");
             tracks_snare[track_idx]
                 .snare_filter_type
                 .store(index as u32, Ordering::Relaxed);
@@ -11090,9 +18245,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_filter_cutoff_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_filter_cutoff");
             tracks_snare[track_idx]
                 .snare_filter_cutoff
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11101,9 +18258,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_filter_resonance_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_filter_resonance");
             tracks_snare[track_idx]
                 .snare_filter_resonance
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11112,9 +18271,11 @@ fn initialize_ui(
 
     let tracks_snare = Arc::clone(tracks);
     let params_snare = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_snare_filter_pre_drive_changed(move |value| {
         let track_idx = params_snare.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "snare_filter_pre_drive");
             tracks_snare[track_idx]
                 .snare_filter_pre_drive
                 .store(value, Ordering::Relaxed);
@@ -11134,6 +18295,44 @@ fn initialize_ui(
         }
     });
 
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
+    ui.on_syndrm_voice_param_changed(move |voice, param, value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let voice = voice as usize;
+            let param = param as usize;
+            if voice < SYNDRM_VOICES && param < SYNDRM_VOICE_PARAMS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!(
+                        "syndrm_{}_{}",
+                        SYNDRM_VOICE_KEYS[voice],
+                        SYNDRM_VOICE_PARAM_KEYS[param],
+                    ),
+                );
+                tracks_syndrm[track_idx].syndrm_voice_params[voice][param]
+                    .store(value.to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_voice_grid_toggled(move |voice, step| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let voice = voice as usize;
+            let index = step as usize;
+            if voice < SYNDRM_VOICES && index < SYNDRM_STEPS {
+                let grid = &tracks_syndrm[track_idx].syndrm_voice_grid[voice];
+                let current = grid[index].load(Ordering::Relaxed);
+                grid[index].store(!current, Ordering::Relaxed);
+            }
+        }
+    });
+
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
     ui.on_syndrm_page_changed(move |page| {
@@ -11197,6 +18396,10 @@ fn initialize_ui(
                 } else if edit_lane == 1 {
                     tracks_syndrm[track_idx].snare_step_override_enabled[edit_step]
                         .store(value, Ordering::Relaxed);
+                } else if (edit_lane as usize) < SYNDRM_LANES {
+                    tracks_syndrm[track_idx].syndrm_voice_step_override_enabled
+                        [edit_lane as usize - 2][edit_step]
+                        .store(value, Ordering::Relaxed);
                 }
             }
         }
@@ -11204,12 +18407,94 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_step_velocity_changed(move |value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_syndrm[track_idx];
+            let edit_step = track.syndrm_edit_step.load(Ordering::Relaxed) as usize;
+            let edit_lane = track.syndrm_edit_lane.load(Ordering::Relaxed) as usize;
+            if edit_step < SYNDRM_STEPS && edit_lane < SYNDRM_LANES {
+                track.syndrm_step_velocity[edit_lane][edit_step]
+                    .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_step_probability_changed(move |value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_syndrm[track_idx];
+            let edit_step = track.syndrm_edit_step.load(Ordering::Relaxed) as usize;
+            let edit_lane = track.syndrm_edit_lane.load(Ordering::Relaxed) as usize;
+            if edit_step < SYNDRM_STEPS && edit_lane < SYNDRM_LANES {
+                track.syndrm_step_probability[edit_lane][edit_step]
+                    .store(value.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_step_micro_changed(move |value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_syndrm[track_idx];
+            let edit_step = track.syndrm_edit_step.load(Ordering::Relaxed) as usize;
+            let edit_lane = track.syndrm_edit_lane.load(Ordering::Relaxed) as usize;
+            if edit_step < SYNDRM_STEPS && edit_lane < SYNDRM_LANES {
+                track.syndrm_step_micro[edit_lane][edit_step]
+                    .store(value.clamp(-0.5, 0.5).to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_step_ratchet_changed(move |value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let track = &tracks_syndrm[track_idx];
+            let edit_step = track.syndrm_edit_step.load(Ordering::Relaxed) as usize;
+            let edit_lane = track.syndrm_edit_lane.load(Ordering::Relaxed) as usize;
+            if edit_step < SYNDRM_STEPS && edit_lane < SYNDRM_LANES {
+                let ratchet = (value.max(1) as u32).min(SYNDRM_MAX_RATCHET);
+                track.syndrm_step_ratchet[edit_lane][edit_step].store(ratchet, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    ui.on_syndrm_step_voice_param_changed(move |param, value| {
+        let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
+        if track_idx < NUM_TRACKS {
+            let edit_step =
+                tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
+            let edit_lane =
+                tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed) as usize;
+            let param = param as usize;
+            if edit_step < SYNDRM_STEPS
+                && (2..SYNDRM_LANES).contains(&edit_lane)
+                && param < SYNDRM_VOICE_PARAMS
+            {
+                tracks_syndrm[track_idx].syndrm_voice_step_params[edit_lane - 2][edit_step][param]
+                    .store(value.to_bits(), Ordering::Relaxed);
+            }
+        }
+    });
+
+    let tracks_syndrm = Arc::clone(tracks);
+    let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_pitch_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_kick_step_pitch_{}", edit_step));
                 tracks_syndrm[track_idx].kick_step_pitch[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11218,12 +18503,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_decay_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_kick_step_decay_{}", edit_step));
                 tracks_syndrm[track_idx].kick_step_decay[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11232,12 +18519,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_attack_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_kick_step_attack_{}", edit_step));
                 tracks_syndrm[track_idx].kick_step_attack[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11246,12 +18535,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_drive_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_kick_step_drive_{}", edit_step));
                 tracks_syndrm[track_idx].kick_step_drive[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11260,12 +18551,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_level_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_kick_step_level_{}", edit_step));
                 tracks_syndrm[track_idx].kick_step_level[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11274,12 +18567,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_filter_type_changed(move |index| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_kick_step_filter_type_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].kick_step_filter_type[edit_step]
                     .store(index as u32, Ordering::Relaxed);
             }
@@ -11288,12 +18586,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_filter_cutoff_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_kick_step_filter_cutoff_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].kick_step_filter_cutoff[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11302,12 +18605,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_kick_filter_resonance_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_kick_step_filter_resonance_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].kick_step_filter_resonance[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11316,12 +18624,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_tone_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_tone_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_tone[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11330,12 +18640,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_decay_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_decay_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_decay[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11344,12 +18656,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_snappy_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_snappy_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_snappy[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11358,12 +18672,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_attack_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_attack_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_attack[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11372,12 +18688,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_drive_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_drive_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_drive[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11386,12 +18704,14 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_level_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(track_idx, &format!("syndrm_snare_step_level_{}", edit_step));
                 tracks_syndrm[track_idx].snare_step_level[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11400,12 +18720,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_filter_type_changed(move |index| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_snare_step_filter_type_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].snare_step_filter_type[edit_step]
                     .store(index as u32, Ordering::Relaxed);
             }
@@ -11414,12 +18739,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_filter_cutoff_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_snare_step_filter_cutoff_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].snare_step_filter_cutoff[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11428,12 +18758,17 @@ fn initialize_ui(
 
     let tracks_syndrm = Arc::clone(tracks);
     let params_syndrm = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_syndrm_step_snare_filter_resonance_changed(move |value| {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let edit_step =
                 tracks_syndrm[track_idx].syndrm_edit_step.load(Ordering::Relaxed) as usize;
             if edit_step < SYNDRM_STEPS {
+                midi_cc_touch.touch(
+                    track_idx,
+                    &format!("syndrm_snare_step_filter_resonance_{}", edit_step),
+                );
                 tracks_syndrm[track_idx].snare_step_filter_resonance[edit_step]
                     .store(value.to_bits(), Ordering::Relaxed);
             }
@@ -11447,7 +18782,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, true, false);
         }
@@ -11459,7 +18794,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, true, false);
         }
     });
@@ -11471,7 +18806,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, true, false);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, true, false);
         }
     });
 
@@ -11480,7 +18815,7 @@ fn initialize_ui(
     ui.on_syndrm_randomize_steps_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, true, false);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, true, false);
         }
     });
 
@@ -11491,7 +18826,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, false, true);
         }
@@ -11503,7 +18838,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, false, true);
         }
     });
@@ -11515,7 +18850,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, false, true);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, false, true);
         }
     });
 
@@ -11524,7 +18859,7 @@ fn initialize_ui(
     ui.on_syndrm_randomize_params_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, false, true);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, false, true);
         }
     });
 
@@ -11535,7 +18870,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, true, true);
         }
@@ -11547,7 +18882,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_randomize_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, true, true);
         }
     });
@@ -11559,7 +18894,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, true, true);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, true, true);
         }
     });
 
@@ -11568,7 +18903,7 @@ fn initialize_ui(
     ui.on_syndrm_randomize_both_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_randomize_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, true, true);
+            syndrm_randomize_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, true, true);
         }
     });
 
@@ -11579,7 +18914,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, true, false);
         }
@@ -11591,7 +18926,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, true, false);
         }
     });
@@ -11603,7 +18938,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, true, false);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, true, false);
         }
     });
 
@@ -11612,7 +18947,7 @@ fn initialize_ui(
     ui.on_syndrm_clear_steps_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, true, false);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, true, false);
         }
     });
 
@@ -11623,7 +18958,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, false, true);
         }
@@ -11635,7 +18970,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, false, true);
         }
     });
@@ -11647,7 +18982,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, false, true);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, false, true);
         }
     });
 
@@ -11656,7 +18991,7 @@ fn initialize_ui(
     ui.on_syndrm_clear_params_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, false, true);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, false, true);
         }
     });
 
@@ -11667,7 +19002,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             let start = page * SYNDRM_PAGE_SIZE;
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, start, SYNDRM_PAGE_SIZE, true, true);
         }
@@ -11679,7 +19014,7 @@ fn initialize_ui(
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
             let lane = tracks_syndrm[track_idx].syndrm_edit_lane.load(Ordering::Relaxed);
-            let lanes = if lane == 0 { 0b01 } else { 0b10 };
+            let lanes = syndrm_lane_mask(lane);
             syndrm_clear_apply(&tracks_syndrm[track_idx], lanes, 0, SYNDRM_STEPS, true, true);
         }
     });
@@ -11691,7 +19026,7 @@ fn initialize_ui(
         if track_idx < NUM_TRACKS {
             let page = tracks_syndrm[track_idx].syndrm_page.load(Ordering::Relaxed) as usize;
             let start = page * SYNDRM_PAGE_SIZE;
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, start, SYNDRM_PAGE_SIZE, true, true);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, start, SYNDRM_PAGE_SIZE, true, true);
         }
     });
 
@@ -11700,15 +19035,17 @@ fn initialize_ui(
     ui.on_syndrm_clear_both_all_all(move || {
         let track_idx = params_syndrm.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
-            syndrm_clear_apply(&tracks_syndrm[track_idx], 0b11, 0, SYNDRM_STEPS, true, true);
+            syndrm_clear_apply(&tracks_syndrm[track_idx], SYNDRM_ALL_LANES, 0, SYNDRM_STEPS, true, true);
         }
     });
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_base_freq_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_base_freq");
             tracks_void[track_idx]
                 .void_base_freq
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11717,9 +19054,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_chaos_depth_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_chaos_depth");
             tracks_void[track_idx]
                 .void_chaos_depth
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11728,9 +19067,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_entropy_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_entropy");
             tracks_void[track_idx]
                 .void_entropy
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11739,9 +19080,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_feedback_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_feedback");
             tracks_void[track_idx]
                 .void_feedback
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11750,9 +19093,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_diffusion_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_diffusion");
             tracks_void[track_idx]
                 .void_diffusion
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11761,9 +19106,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_mod_rate_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_mod_rate");
             tracks_void[track_idx]
                 .void_mod_rate
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11772,9 +19119,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_void_level_changed(move |value| {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_level");
             tracks_void[track_idx]
                 .void_level
                 .store(value.to_bits(), Ordering::Relaxed);
@@ -11783,9 +19132,11 @@ fn initialize_ui(
 
     let tracks_void = Arc::clone(tracks);
     let params_void = Arc::clone(params);
+    let midi_cc_touch = Arc::clone(midi_cc);
     ui.on_toggle_void(move || {
         let track_idx = params_void.selected_track.value().saturating_sub(1) as usize;
         if track_idx < NUM_TRACKS {
+            midi_cc_touch.touch(track_idx, "void_enabled");
             let current = tracks_void[track_idx].void_enabled.load(Ordering::Relaxed);
             tracks_void[track_idx].void_enabled.store(!current, Ordering::Relaxed);
         }
@@ -11808,6 +19159,10 @@ enum ProjectDialogAction {
         title: String,
         description: String,
     },
+    Render {
+        path: PathBuf,
+        options: RenderOptions,
+    },
 }
 
 #[derive(Clone)]
@@ -12106,3 +19461,47 @@ fn restart_with_audio_settings(
     cmd.spawn().map_err(|err| err.to_string())?;
     std::process::exit(0);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Energy outside the harmonics of a note rendered with exactly `CYCLES` periods in
+    /// `N` samples, relative to the harmonic energy. `render` maps phase to a sample.
+    fn alias_ratio(render: impl Fn(f32) -> f32) -> f32 {
+        const N: usize = 8192;
+        const CYCLES: usize = 683;
+        let mut re: Vec<f32> = (0..N)
+            .map(|n| render(((n * CYCLES) % N) as f32 / N as f32))
+            .collect();
+        let mut im = vec![0.0f32; N];
+        fft_in_place(&mut re, &mut im, false);
+        let (mut harmonic, mut alias) = (0.0f32, 0.0f32);
+        for bin in 1..N / 2 {
+            let energy = re[bin] * re[bin] + im[bin] * im[bin];
+            if bin % CYCLES == 0 {
+                harmonic += energy;
+            } else {
+                alias += energy;
+            }
+        }
+        alias / harmonic
+    }
+
+    #[test]
+    fn wavetable_mips_suppress_aliasing() {
+        let saw: Vec<f32> = (0..ANIMATE_WAVETABLE_FRAME)
+            .map(|i| 1.0 - 2.0 * i as f32 / ANIMATE_WAVETABLE_FRAME as f32)
+            .collect();
+        let table = Wavetable::new(saw, None).unwrap();
+        let sr = 48_000.0;
+        // About 4 kHz, landing on a whole number of cycles in the FFT window.
+        let freq = 683.0 * sr / 8192.0;
+
+        let raw = alias_ratio(|phase| table.levels[0].read(0, phase));
+        let mipped = alias_ratio(|phase| table.read(0.0, phase, freq, sr));
+        assert!(raw > 0.05, "level 0 should alias at 4 kHz, got {raw}");
+        assert!(mipped < 1e-4, "mip levels alias too much: {mipped}");
+        assert!(mipped < raw * 1e-3, "mips {mipped} vs level 0 {raw}");
+    }
+}